@@ -1,54 +1,435 @@
 use anyhow::Result;
-use chrono::NaiveDateTime;
+use chrono::{Datelike, Duration, IsoWeek, NaiveDateTime};
+use cli_app::AcknowledgeReminderUseCaseInvoker;
+use cli_app::AddAgendaItemUseCaseInvoker;
+use cli_app::AddChoreUseCaseInvoker;
+use cli_app::AddReminderUseCaseInvoker;
 use cli_app::AddTodoUseCaseInvoker;
+use cli_app::CompleteTodoUseCaseInvoker;
+use cli_app::ConvertPlannableUseCaseInvoker;
+use cli_app::EditTodoUseCaseInvoker;
+use cli_app::ExpirePlannablesUseCaseInvoker;
+use cli_app::ExpiryReportUseCaseInvoker;
+use cli_app::GetChoreUseCaseInvoker;
+use cli_app::GetShortIdsUseCaseInvoker;
 use cli_app::GetTodoUseCaseInvoker;
-use entities::todo_events::TodoCreatedEvent;
-use event_store::plannable_todo_event_store::TodoEventStore;
+use cli_app::NextHoursViewUseCaseInvoker;
+use cli_app::RaiseImpedimentUseCaseInvoker;
+use cli_app::RaiseIssueUseCaseInvoker;
+use cli_app::RemoveTodoUseCaseInvoker;
+use cli_app::ReopenTodoUseCaseInvoker;
+use cli_app::RescheduleAgendaItemUseCaseInvoker;
+use cli_app::RescheduleChoreUseCaseInvoker;
+use cli_app::ResolveIdUseCaseInvoker;
+use cli_app::SnoozeReminderUseCaseInvoker;
+use cli_app::TodayViewUseCaseInvoker;
+use cli_app::WeekViewUseCaseInvoker;
+use entities::agenda_item::AgendaItem;
+use entities::agenda_item_events::{AgendaItemEnd, AgendaItemEvent, AgendaRecurrence};
+use entities::chore::Chore;
+use entities::chore_events::ChoreEvent;
+use entities::errors::{
+    AgendaItemError, ChoreError, ConversionError, EventStoreError, IdError, PlannableError,
+    ReminderError, TodoError, ViewError,
+};
+use entities::impediment_events::ImpedimentEvent;
+use entities::issue_events::{IssueEvent, Severity};
+use entities::plannable::{PlannableKind, PlannableRef};
+use entities::recurrence::Recurrence;
+use entities::reminder::Reminder;
+use entities::reminder_events::ReminderEvent;
+use entities::task_events::TaskEvent;
+use entities::todo::Todo;
+use entities::todo_events::TodoEvent;
+use event_store::plannable_event_store::{EventStore, PlannableEvent};
+use event_store::sqlite_event_store::{
+    AgendaItemEventStore, ChoreEventStore, ImpedimentEventStore, IssueEventStore,
+    ReminderEventStore, SqliteEventStore, TaskEventStore, TodoEventStore,
+};
+use std::process::ExitCode;
+use usecases::acknowledge_reminder_usecase::{AcknowledgeReminderUsecase, ChangeReminderEvents};
+use usecases::add_agenda_item_usecase::{AddAgendaItemUsecase, StoreAgendaItemEvents};
+use usecases::add_chore_usecase::{AddChoreUsecase, StoreChoreEvents};
+use usecases::add_reminder_usecase::{AddReminderUsecase, StoreReminderEvents};
 use usecases::add_todo_usecase::AddTodoUsecase;
 use usecases::add_todo_usecase::StoreTodoEvents;
+use usecases::change_todo::ChangeTodoEvents;
+use usecases::clock::{Clock, SystemClock};
+use usecases::complete_todo_usecase::CompleteTodoUsecase;
+use usecases::convert_plannable_usecase::{
+    ConversionEvent, ConvertPlannableEvents, ConvertPlannableUsecase,
+};
+use usecases::edit_todo_usecase::EditTodoUsecase;
+use usecases::expire_plannables_usecase::{ExpirePlannableEvents, ExpirePlannablesUsecase};
+use usecases::get_chore_usecase::{GetChoreEvents, GetChoreUsecase};
+use usecases::get_expiry_report_usecase::{ExpiryReport, GetExpiryReportUsecase};
+use usecases::get_short_ids_usecase::GetShortIdsUsecase;
 use usecases::get_todo_usecase::GetTodoEvents;
+use usecases::id_generator::RandomIdGenerator;
+use usecases::next_hours_view_usecase::{NextHoursViewUsecase, TimelineEntry};
+use usecases::raise_impediment_usecase::{RaiseImpedimentUsecase, StoreImpedimentEvents};
+use usecases::raise_issue_usecase::{RaiseIssueUsecase, StoreIssueEvents};
+use usecases::remove_todo_usecase::RemoveTodoUsecase;
+use usecases::reopen_todo_usecase::ReopenTodoUsecase;
+use usecases::reschedule_agenda_item_usecase::{
+    ChangeAgendaItemEvents, RescheduleAgendaItemUsecase,
+};
+use usecases::reschedule_chore_usecase::{ChangeChoreEvents, RescheduleChoreUsecase};
+use usecases::resolve_id_usecase::ResolveIdUsecase;
+use usecases::short_ids::{FindPlannableIds, ShortIds};
+use usecases::snooze_reminder_usecase::SnoozeReminderUsecase;
+use usecases::today_view_usecase::TodayViewUsecase;
+use usecases::view::{GetViewEvents, ViewItem};
+use usecases::week_view_usecase::{WeekDay, WeekViewUsecase};
+use uuid::Uuid;
 
 use cli_app::CliApp;
 use usecases::get_todo_usecase::GetTodoUsecase;
 
 struct AppEventStore {
     real_event_store: TodoEventStore,
+    chore_event_store: ChoreEventStore,
+    task_event_store: TaskEventStore,
+    agenda_item_event_store: AgendaItemEventStore,
+    reminder_event_store: ReminderEventStore,
+    issue_event_store: IssueEventStore,
+    impediment_event_store: ImpedimentEventStore,
 }
 
 impl AppEventStore {
-    fn new(database_url: &str) -> Self {
-        let eventstore = TodoEventStore::new(database_url).unwrap();
-        Self {
+    // Every kind goes through the one connection of the todo store
+    fn new(database_url: &str) -> Result<Self, EventStoreError> {
+        let eventstore = TodoEventStore::new(database_url)?;
+        Ok(Self {
+            chore_event_store: eventstore.share(),
+            task_event_store: eventstore.share(),
+            agenda_item_event_store: eventstore.share(),
+            reminder_event_store: eventstore.share(),
+            issue_event_store: eventstore.share(),
+            impediment_event_store: eventstore.share(),
             real_event_store: eventstore,
-        }
+        })
     }
 }
 impl StoreTodoEvents for AppEventStore {
-    fn save(&mut self, todo_events: Vec<TodoCreatedEvent>) -> Result<(), std::io::Error> {
-        self.real_event_store.save(todo_events)
+    fn append(
+        &mut self,
+        todo_id: Uuid,
+        expected_version: i32,
+        todo_events: Vec<TodoEvent>,
+    ) -> Result<i32, EventStoreError> {
+        self.real_event_store
+            .append(todo_id, expected_version, todo_events)
     }
 }
 
 impl GetTodoEvents for AppEventStore {
-    fn get_all(&mut self) -> Result<Vec<TodoCreatedEvent>, std::io::Error> {
+    fn get_all(&mut self) -> Result<Vec<TodoEvent>, EventStoreError> {
+        self.real_event_store.get_all()
+    }
+}
+
+impl ChangeTodoEvents for AppEventStore {
+    fn read(&mut self, todo_id: Uuid) -> Result<Vec<TodoEvent>, EventStoreError> {
+        self.real_event_store.read(todo_id)
+    }
+    fn append(
+        &mut self,
+        todo_id: Uuid,
+        expected_version: i32,
+        todo_events: Vec<TodoEvent>,
+    ) -> Result<i32, EventStoreError> {
+        self.real_event_store
+            .append(todo_id, expected_version, todo_events)
+    }
+}
+impl StoreChoreEvents for AppEventStore {
+    fn append(
+        &mut self,
+        chore_id: Uuid,
+        expected_version: i32,
+        chore_events: Vec<ChoreEvent>,
+    ) -> Result<i32, EventStoreError> {
+        self.chore_event_store
+            .append(chore_id, expected_version, chore_events)
+    }
+}
+
+impl ChangeChoreEvents for AppEventStore {
+    fn read(&mut self, chore_id: Uuid) -> Result<Vec<ChoreEvent>, EventStoreError> {
+        self.chore_event_store.read(chore_id)
+    }
+    fn append(
+        &mut self,
+        chore_id: Uuid,
+        expected_version: i32,
+        chore_events: Vec<ChoreEvent>,
+    ) -> Result<i32, EventStoreError> {
+        self.chore_event_store
+            .append(chore_id, expected_version, chore_events)
+    }
+}
+
+impl GetChoreEvents for AppEventStore {
+    fn get_all(&mut self) -> Result<Vec<ChoreEvent>, EventStoreError> {
+        self.chore_event_store.get_all()
+    }
+}
+
+impl StoreAgendaItemEvents for AppEventStore {
+    fn append(
+        &mut self,
+        agenda_item_id: Uuid,
+        expected_version: i32,
+        agenda_item_events: Vec<AgendaItemEvent>,
+    ) -> Result<i32, EventStoreError> {
+        self.agenda_item_event_store
+            .append(agenda_item_id, expected_version, agenda_item_events)
+    }
+}
+
+impl ChangeAgendaItemEvents for AppEventStore {
+    fn read(&mut self, agenda_item_id: Uuid) -> Result<Vec<AgendaItemEvent>, EventStoreError> {
+        self.agenda_item_event_store.read(agenda_item_id)
+    }
+    fn append(
+        &mut self,
+        agenda_item_id: Uuid,
+        expected_version: i32,
+        agenda_item_events: Vec<AgendaItemEvent>,
+    ) -> Result<i32, EventStoreError> {
+        self.agenda_item_event_store
+            .append(agenda_item_id, expected_version, agenda_item_events)
+    }
+}
+
+impl StoreReminderEvents for AppEventStore {
+    fn append(
+        &mut self,
+        reminder_id: Uuid,
+        expected_version: i32,
+        reminder_events: Vec<ReminderEvent>,
+    ) -> Result<i32, EventStoreError> {
+        self.reminder_event_store
+            .append(reminder_id, expected_version, reminder_events)
+    }
+}
+
+impl ChangeReminderEvents for AppEventStore {
+    fn read(&mut self, reminder_id: Uuid) -> Result<Vec<ReminderEvent>, EventStoreError> {
+        self.reminder_event_store.read(reminder_id)
+    }
+    fn append(
+        &mut self,
+        reminder_id: Uuid,
+        expected_version: i32,
+        reminder_events: Vec<ReminderEvent>,
+    ) -> Result<i32, EventStoreError> {
+        self.reminder_event_store
+            .append(reminder_id, expected_version, reminder_events)
+    }
+}
+
+impl StoreIssueEvents for AppEventStore {
+    fn append(
+        &mut self,
+        issue_id: Uuid,
+        expected_version: i32,
+        issue_events: Vec<IssueEvent>,
+    ) -> Result<i32, EventStoreError> {
+        self.issue_event_store
+            .append(issue_id, expected_version, issue_events)
+    }
+}
+
+impl StoreImpedimentEvents for AppEventStore {
+    fn append(
+        &mut self,
+        impediment_id: Uuid,
+        expected_version: i32,
+        impediment_events: Vec<ImpedimentEvent>,
+    ) -> Result<i32, EventStoreError> {
+        self.impediment_event_store
+            .append(impediment_id, expected_version, impediment_events)
+    }
+}
+
+impl GetViewEvents for AppEventStore {
+    fn get_all_todos(&mut self) -> Result<Vec<TodoEvent>, EventStoreError> {
         self.real_event_store.get_all()
     }
+    fn get_all_tasks(&mut self) -> Result<Vec<TaskEvent>, EventStoreError> {
+        self.task_event_store.get_all()
+    }
+    fn get_all_chores(&mut self) -> Result<Vec<ChoreEvent>, EventStoreError> {
+        self.chore_event_store.get_all()
+    }
+    fn get_all_agenda_items(&mut self) -> Result<Vec<AgendaItemEvent>, EventStoreError> {
+        self.agenda_item_event_store.get_all()
+    }
+    fn get_all_reminders(&mut self) -> Result<Vec<ReminderEvent>, EventStoreError> {
+        self.reminder_event_store.get_all()
+    }
+    fn get_all_impediments(&mut self) -> Result<Vec<ImpedimentEvent>, EventStoreError> {
+        self.impediment_event_store.get_all()
+    }
+}
+impl ExpirePlannableEvents for AppEventStore {
+    fn get_all_tasks(&mut self) -> Result<Vec<TaskEvent>, EventStoreError> {
+        self.task_event_store.get_all()
+    }
+    fn get_all_chores(&mut self) -> Result<Vec<ChoreEvent>, EventStoreError> {
+        self.chore_event_store.get_all()
+    }
+    fn get_all_impediments(&mut self) -> Result<Vec<ImpedimentEvent>, EventStoreError> {
+        self.impediment_event_store.get_all()
+    }
+    fn append_task(
+        &mut self,
+        task_id: Uuid,
+        expected_version: i32,
+        task_events: Vec<TaskEvent>,
+    ) -> Result<i32, EventStoreError> {
+        self.task_event_store
+            .append(task_id, expected_version, task_events)
+    }
+    fn append_chore(
+        &mut self,
+        chore_id: Uuid,
+        expected_version: i32,
+        chore_events: Vec<ChoreEvent>,
+    ) -> Result<i32, EventStoreError> {
+        self.chore_event_store
+            .append(chore_id, expected_version, chore_events)
+    }
+    fn append_impediment(
+        &mut self,
+        impediment_id: Uuid,
+        expected_version: i32,
+        impediment_events: Vec<ImpedimentEvent>,
+    ) -> Result<i32, EventStoreError> {
+        self.impediment_event_store
+            .append(impediment_id, expected_version, impediment_events)
+    }
+}
+impl ConvertPlannableEvents for AppEventStore {
+    fn read_todo(&mut self, todo_id: Uuid) -> Result<Vec<TodoEvent>, EventStoreError> {
+        self.real_event_store.read(todo_id)
+    }
+    fn read_task(&mut self, task_id: Uuid) -> Result<Vec<TaskEvent>, EventStoreError> {
+        self.task_event_store.read(task_id)
+    }
+    fn read_chore(&mut self, chore_id: Uuid) -> Result<Vec<ChoreEvent>, EventStoreError> {
+        self.chore_event_store.read(chore_id)
+    }
+    fn append_conversion(
+        &mut self,
+        closed: ConversionEvent,
+        opened: ConversionEvent,
+    ) -> Result<(), EventStoreError> {
+        match closed {
+            ConversionEvent::Todo(event) => append_conversion(
+                &mut self.real_event_store,
+                event.todo_id(),
+                event.sequence(),
+                event,
+                opened,
+            ),
+            ConversionEvent::Task(event) => append_conversion(
+                &mut self.task_event_store,
+                event.task_id(),
+                event.sequence(),
+                event,
+                opened,
+            ),
+            ConversionEvent::Chore(event) => append_conversion(
+                &mut self.chore_event_store,
+                event.chore_id(),
+                event.sequence(),
+                event,
+                opened,
+            ),
+        }
+    }
+}
+
+// Appends the event that closes the source and the one that opens the target in one transaction
+// of the source store
+fn append_conversion<E: PlannableEvent>(
+    source_event_store: &mut SqliteEventStore<E>,
+    source_id: Uuid,
+    source_version: i32,
+    closed: E,
+    opened: ConversionEvent,
+) -> Result<(), EventStoreError> {
+    match opened {
+        ConversionEvent::Todo(event) => source_event_store.append_with(
+            source_id,
+            source_version,
+            vec![closed],
+            event.todo_id(),
+            event.sequence(),
+            vec![event],
+        ),
+        ConversionEvent::Task(event) => source_event_store.append_with(
+            source_id,
+            source_version,
+            vec![closed],
+            event.task_id(),
+            event.sequence(),
+            vec![event],
+        ),
+        ConversionEvent::Chore(event) => source_event_store.append_with(
+            source_id,
+            source_version,
+            vec![closed],
+            event.chore_id(),
+            event.sequence(),
+            vec![event],
+        ),
+    }
+    .map(|_versions| ())
+}
+
+impl FindPlannableIds for AppEventStore {
+    fn find_ids(
+        &mut self,
+        kind: PlannableKind,
+        id_prefix: &str,
+    ) -> Result<Vec<Uuid>, EventStoreError> {
+        self.real_event_store.find_plannable_ids(kind, id_prefix)
+    }
 }
 
-fn main() -> Result<()> {
-    App::new().run()
+fn main() -> ExitCode {
+    let result = App::new()
+        .map_err(anyhow::Error::from)
+        .and_then(|mut app| app.run());
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::from(cli_app::exit_code(&error))
+        }
+    }
 }
 
 struct App {
     app_event_store: AppEventStore,
+    clock: SystemClock,
+    id_generator: RandomIdGenerator,
 }
 
 impl App {
-    pub fn new() -> Self {
+    pub fn new() -> Result<Self, EventStoreError> {
         let database_url = "/tmp/test_plannable_events.db";
-        let app_event_store = AppEventStore::new(database_url);
+        let app_event_store = AppEventStore::new(database_url)?;
 
-        Self { app_event_store }
+        Ok(Self {
+            app_event_store,
+            clock: SystemClock,
+            id_generator: RandomIdGenerator,
+        })
     }
 
     pub fn run(&mut self) -> Result<()> {
@@ -57,16 +438,252 @@ impl App {
 }
 
 impl AddTodoUseCaseInvoker for App {
-    fn invoke_add_todo_usecase(&mut self, title: String, end_date: Option<NaiveDateTime>) {
-        AddTodoUsecase::new(&mut self.app_event_store)
-            .execute(title, end_date)
-            .unwrap();
+    fn invoke_add_todo_usecase(
+        &mut self,
+        title: String,
+        end_date: Option<NaiveDateTime>,
+    ) -> Result<(), PlannableError> {
+        AddTodoUsecase::new(
+            &mut self.app_event_store,
+            &self.clock,
+            &mut self.id_generator,
+        )
+        .execute(title, end_date)
     }
 }
 impl GetTodoUseCaseInvoker for App {
-    fn invoke_get_todo_usecase(&mut self) -> Vec<TodoCreatedEvent> {
-        GetTodoUsecase::new(&mut self.app_event_store)
-            .execute()
-            .unwrap()
+    fn invoke_get_todo_usecase(&mut self) -> Result<Vec<Todo>, EventStoreError> {
+        GetTodoUsecase::new(&mut self.app_event_store).execute()
+    }
+}
+impl CompleteTodoUseCaseInvoker for App {
+    fn invoke_complete_todo_usecase(&mut self, todo_id: Uuid) -> Result<Todo, TodoError> {
+        CompleteTodoUsecase::new(&mut self.app_event_store, &mut self.id_generator).execute(todo_id)
+    }
+}
+impl ReopenTodoUseCaseInvoker for App {
+    fn invoke_reopen_todo_usecase(&mut self, todo_id: Uuid) -> Result<Todo, TodoError> {
+        ReopenTodoUsecase::new(&mut self.app_event_store, &mut self.id_generator).execute(todo_id)
+    }
+}
+impl RemoveTodoUseCaseInvoker for App {
+    fn invoke_remove_todo_usecase(&mut self, todo_id: Uuid) -> Result<Todo, TodoError> {
+        RemoveTodoUsecase::new(&mut self.app_event_store, &mut self.id_generator).execute(todo_id)
+    }
+}
+impl EditTodoUseCaseInvoker for App {
+    fn invoke_edit_todo_usecase(
+        &mut self,
+        todo_id: Uuid,
+        title: Option<String>,
+        end_date: Option<NaiveDateTime>,
+    ) -> Result<Todo, TodoError> {
+        EditTodoUsecase::new(
+            &mut self.app_event_store,
+            &self.clock,
+            &mut self.id_generator,
+        )
+        .execute(todo_id, title, end_date)
+    }
+}
+impl AddChoreUseCaseInvoker for App {
+    fn invoke_add_chore_usecase(
+        &mut self,
+        title: String,
+        due_date: Option<NaiveDateTime>,
+        recurrence: Option<Recurrence>,
+    ) -> Result<(), PlannableError> {
+        AddChoreUsecase::new(
+            &mut self.app_event_store,
+            &self.clock,
+            &mut self.id_generator,
+        )
+        .execute(title, due_date, recurrence)
+    }
+}
+impl GetChoreUseCaseInvoker for App {
+    fn invoke_get_chore_usecase(&mut self) -> Result<Vec<Chore>, EventStoreError> {
+        GetChoreUsecase::new(&mut self.app_event_store).execute()
+    }
+}
+impl RescheduleChoreUseCaseInvoker for App {
+    fn invoke_reschedule_chore_usecase(
+        &mut self,
+        chore_id: Uuid,
+        due_date: NaiveDateTime,
+    ) -> Result<Chore, ChoreError> {
+        RescheduleChoreUsecase::new(
+            &mut self.app_event_store,
+            &self.clock,
+            &mut self.id_generator,
+        )
+        .execute(chore_id, due_date)
+    }
+}
+impl AddAgendaItemUseCaseInvoker for App {
+    fn invoke_add_agenda_item_usecase(
+        &mut self,
+        title: String,
+        start: NaiveDateTime,
+        end: Option<AgendaItemEnd>,
+        location: Option<String>,
+        recurrence: Option<AgendaRecurrence>,
+    ) -> Result<(), PlannableError> {
+        AddAgendaItemUsecase::new(
+            &mut self.app_event_store,
+            &self.clock,
+            &mut self.id_generator,
+        )
+        .execute(title, start, end, location, recurrence)
+        .map(|_agenda_item_id| ())
+    }
+}
+impl RescheduleAgendaItemUseCaseInvoker for App {
+    fn invoke_reschedule_agenda_item_usecase(
+        &mut self,
+        agenda_item_id: Uuid,
+        start: NaiveDateTime,
+        end: Option<AgendaItemEnd>,
+    ) -> Result<AgendaItem, AgendaItemError> {
+        RescheduleAgendaItemUsecase::new(
+            &mut self.app_event_store,
+            &self.clock,
+            &mut self.id_generator,
+        )
+        .execute(agenda_item_id, start, end)
+    }
+}
+impl AddReminderUseCaseInvoker for App {
+    fn invoke_add_reminder_usecase(
+        &mut self,
+        title: String,
+        trigger_at: NaiveDateTime,
+        recurrence: Option<Recurrence>,
+    ) -> Result<(), PlannableError> {
+        AddReminderUsecase::new(
+            &mut self.app_event_store,
+            &self.clock,
+            &mut self.id_generator,
+        )
+        .execute(title, trigger_at, recurrence)
+        .map(|_reminder_id| ())
+    }
+}
+impl AcknowledgeReminderUseCaseInvoker for App {
+    fn invoke_acknowledge_reminder_usecase(
+        &mut self,
+        reminder_id: Uuid,
+    ) -> Result<Reminder, ReminderError> {
+        AcknowledgeReminderUsecase::new(
+            &mut self.app_event_store,
+            &self.clock,
+            &mut self.id_generator,
+        )
+        .execute(reminder_id)
+    }
+}
+impl SnoozeReminderUseCaseInvoker for App {
+    fn invoke_snooze_reminder_usecase(
+        &mut self,
+        reminder_id: Uuid,
+        until: NaiveDateTime,
+    ) -> Result<Reminder, ReminderError> {
+        SnoozeReminderUsecase::new(
+            &mut self.app_event_store,
+            &self.clock,
+            &mut self.id_generator,
+        )
+        .execute(reminder_id, until)
+    }
+}
+impl RaiseIssueUseCaseInvoker for App {
+    fn invoke_raise_issue_usecase(
+        &mut self,
+        title: String,
+        severity: Severity,
+    ) -> Result<(), PlannableError> {
+        RaiseIssueUsecase::new(&mut self.app_event_store, &mut self.id_generator)
+            .execute(title, severity)
+            .map(|_issue_id| ())
+    }
+}
+impl RaiseImpedimentUseCaseInvoker for App {
+    fn invoke_raise_impediment_usecase(
+        &mut self,
+        title: String,
+        expires_at: NaiveDateTime,
+        blocks: Vec<PlannableRef>,
+    ) -> Result<(), PlannableError> {
+        RaiseImpedimentUsecase::new(
+            &mut self.app_event_store,
+            &self.clock,
+            &mut self.id_generator,
+        )
+        .execute(title, expires_at, blocks)
+        .map(|_impediment_id| ())
+    }
+}
+impl NextHoursViewUseCaseInvoker for App {
+    fn invoke_next_hours_view_usecase(
+        &mut self,
+        window: Duration,
+    ) -> Result<Vec<TimelineEntry>, ViewError> {
+        NextHoursViewUsecase::new(&mut self.app_event_store, &self.clock).execute(window)
+    }
+}
+impl WeekViewUseCaseInvoker for App {
+    fn invoke_week_view_usecase(
+        &mut self,
+        week: Option<IsoWeek>,
+    ) -> Result<Vec<WeekDay>, EventStoreError> {
+        let week = week.unwrap_or_else(|| self.clock.now().iso_week());
+        WeekViewUsecase::new(&mut self.app_event_store).execute(week)
+    }
+}
+impl TodayViewUseCaseInvoker for App {
+    fn invoke_today_view_usecase(&mut self) -> Result<Vec<ViewItem>, EventStoreError> {
+        TodayViewUsecase::new(&mut self.app_event_store, &self.clock).execute()
+    }
+}
+impl ExpiryReportUseCaseInvoker for App {
+    fn invoke_expiry_report_usecase(
+        &mut self,
+        horizon: Duration,
+    ) -> Result<ExpiryReport, EventStoreError> {
+        GetExpiryReportUsecase::new(&mut self.app_event_store, &self.clock).execute(horizon)
+    }
+}
+impl ExpirePlannablesUseCaseInvoker for App {
+    fn invoke_expire_plannables_usecase(&mut self) -> Result<Vec<ViewItem>, EventStoreError> {
+        ExpirePlannablesUsecase::new(
+            &mut self.app_event_store,
+            &self.clock,
+            &mut self.id_generator,
+        )
+        .execute()
+    }
+}
+impl ConvertPlannableUseCaseInvoker for App {
+    fn invoke_convert_plannable_usecase(
+        &mut self,
+        source: PlannableRef,
+        target: PlannableKind,
+    ) -> Result<PlannableRef, ConversionError> {
+        ConvertPlannableUsecase::new(&mut self.app_event_store, &mut self.id_generator)
+            .execute(source, target)
+    }
+}
+impl ResolveIdUseCaseInvoker for App {
+    fn invoke_resolve_id_usecase(
+        &mut self,
+        kind: PlannableKind,
+        id_prefix: &str,
+    ) -> Result<Uuid, IdError> {
+        ResolveIdUsecase::new(&mut self.app_event_store).execute(kind, id_prefix)
+    }
+}
+impl GetShortIdsUseCaseInvoker for App {
+    fn invoke_get_short_ids_usecase(&mut self) -> Result<ShortIds, EventStoreError> {
+        GetShortIdsUsecase::new(&mut self.app_event_store).execute()
     }
 }
@@ -0,0 +1,231 @@
+use crate::agenda_item_events::{AgendaItemEnd, AgendaItemEvent, AgendaItemId, AgendaRecurrence};
+use chrono::{Duration, NaiveDateTime};
+
+// Current state of an agenda item, rebuilt by folding its events
+#[derive(Debug, PartialEq, Clone)]
+pub struct AgendaItem {
+    pub agenda_item_id: AgendaItemId,
+    pub title: String,
+    // Start of the first occurrence
+    pub start: NaiveDateTime,
+    pub end: Option<AgendaItemEnd>,
+    pub location: Option<String>,
+    pub recurrence: Option<AgendaRecurrence>,
+    pub cancelled: bool,
+    // Number of events applied, the sequence of the next event to append
+    pub version: i32,
+}
+
+// One concrete time slot of an agenda item
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Occurrence {
+    pub start: NaiveDateTime,
+    pub end: Option<NaiveDateTime>,
+}
+
+impl AgendaItem {
+    // Folds the events of a single agenda item in sequence order. Returns None when the stream
+    // does not start with a Created event.
+    pub fn from_events(events: &[AgendaItemEvent]) -> Option<Self> {
+        let mut ordered: Vec<&AgendaItemEvent> = events.iter().collect();
+        ordered.sort_by_key(|event| event.sequence());
+        let mut ordered = ordered.into_iter();
+
+        let mut agenda_item = match ordered.next()? {
+            AgendaItemEvent::Created(created) => Self {
+                agenda_item_id: created.agenda_item_id,
+                title: created.title.clone(),
+                start: created.start,
+                end: created.end,
+                location: created.location.clone(),
+                recurrence: created.recurrence.clone(),
+                cancelled: false,
+                version: 1,
+            },
+            _ => return None,
+        };
+        ordered.for_each(|event| agenda_item.apply(event));
+        Some(agenda_item)
+    }
+
+    pub fn apply(&mut self, event: &AgendaItemEvent) {
+        match event {
+            AgendaItemEvent::Created(_) => {}
+            AgendaItemEvent::Rescheduled(rescheduled) => {
+                self.start = rescheduled.start;
+                self.end = rescheduled.end;
+            }
+            AgendaItemEvent::Relocated(relocated) => self.location = relocated.location.clone(),
+            AgendaItemEvent::OccurrenceExcluded(excluded) => {
+                if let Some(recurrence) = &mut self.recurrence {
+                    recurrence.excluded_dates.push(excluded.date);
+                }
+            }
+            AgendaItemEvent::Cancelled(_) => self.cancelled = true,
+        }
+        self.version += 1;
+    }
+
+    // How long every occurrence takes, None when the agenda item has no end
+    pub fn duration(&self) -> Option<Duration> {
+        match self.end? {
+            AgendaItemEnd::At(end) => Some(end - self.start),
+            AgendaItemEnd::After(duration) => Some(duration),
+        }
+    }
+
+    // The occurrences that overlap the window from..to, ordered by start. An occurrence without an
+    // end only overlaps when it starts inside the window. A cancelled agenda item has none.
+    pub fn occurrences(&self, from: NaiveDateTime, to: NaiveDateTime) -> Vec<Occurrence> {
+        if self.cancelled {
+            return Vec::new();
+        }
+        let duration = self.duration();
+        let occurrence = |start: NaiveDateTime| Occurrence {
+            start,
+            end: duration.map(|duration| start + duration),
+        };
+        let overlaps = |occurrence: &Occurrence| {
+            occurrence.start < to && occurrence.end.unwrap_or(occurrence.start) >= from
+        };
+
+        let recurrence = match &self.recurrence {
+            Some(recurrence) => recurrence,
+            None => {
+                return Some(occurrence(self.start))
+                    .filter(overlaps)
+                    .into_iter()
+                    .collect()
+            }
+        };
+        (0..)
+            .map_while(|n| recurrence.recurrence.nth_after(self.start, n))
+            .take_while(|start| *start < to)
+            .take_while(|start| recurrence.until.is_none_or(|until| *start <= until))
+            .filter(|start| !recurrence.excluded_dates.contains(&start.date()))
+            .map(occurrence)
+            .filter(overlaps)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AgendaItem, Occurrence};
+    use crate::agenda_item_events::{
+        AgendaItemCreatedEvent, AgendaItemEnd, AgendaItemEvent, AgendaItemOccurrenceExcludedEvent,
+        AgendaRecurrence,
+    };
+    use crate::recurrence::Recurrence;
+    use chrono::{Duration, NaiveDate, NaiveDateTime};
+    use uuid::Uuid;
+
+    fn date(month: u32, day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, month, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn given_weeklyitemuntilaugust_when_occurrences_then_expandinsidewindow() {
+        //GIVEN weekly yoga classes on Mondays until August without the one on 2023-07-17
+        let agenda_item_id = Uuid::new_v4();
+        let events = vec![
+            AgendaItemEvent::Created(AgendaItemCreatedEvent {
+                event_id: Uuid::new_v4(),
+                agenda_item_id,
+                sequence: 0,
+                title: String::from("Yoga class"),
+                start: date(7, 3, 18),
+                end: Some(AgendaItemEnd::After(Duration::hours(1))),
+                location: Some(String::from("Gym")),
+                recurrence: Some(AgendaRecurrence {
+                    recurrence: Recurrence::Weeks(1),
+                    until: Some(date(8, 1, 0)),
+                    excluded_dates: Vec::new(),
+                }),
+            }),
+            AgendaItemEvent::OccurrenceExcluded(AgendaItemOccurrenceExcludedEvent {
+                event_id: Uuid::new_v4(),
+                agenda_item_id,
+                sequence: 1,
+                date: NaiveDate::from_ymd_opt(2023, 7, 17).unwrap(),
+            }),
+        ];
+        let agenda_item = AgendaItem::from_events(&events).unwrap();
+
+        //WHEN expanding a window that starts during the first class and runs past August
+        let occurrences =
+            agenda_item.occurrences(date(7, 3, 18) + Duration::minutes(30), date(9, 1, 0));
+
+        //THEN every class that is not excluded and starts before August
+        let expected: Vec<Occurrence> = [3, 10, 24, 31]
+            .iter()
+            .map(|day| Occurrence {
+                start: date(7, *day, 18),
+                end: Some(date(7, *day, 19)),
+            })
+            .collect();
+        assert_eq!(occurrences, expected);
+    }
+
+    #[test]
+    fn given_oneoffitem_when_outsidewindow_then_nooccurrences() {
+        let agenda_item =
+            AgendaItem::from_events(&[AgendaItemEvent::Created(AgendaItemCreatedEvent {
+                event_id: Uuid::new_v4(),
+                agenda_item_id: Uuid::new_v4(),
+                sequence: 0,
+                title: String::from("Dentist appointment"),
+                start: date(9, 25, 10),
+                end: Some(AgendaItemEnd::At(date(9, 25, 11))),
+                location: None,
+                recurrence: None,
+            })])
+            .unwrap();
+        assert_eq!(
+            agenda_item.occurrences(date(9, 26, 0), date(9, 27, 0)),
+            vec![]
+        );
+        assert_eq!(
+            agenda_item.occurrences(date(9, 25, 0), date(9, 26, 0)),
+            vec![Occurrence {
+                start: date(9, 25, 10),
+                end: Some(date(9, 25, 11)),
+            }]
+        );
+    }
+
+    #[test]
+    fn given_storedzerorecurrence_when_occurrences_then_onlythefirst() {
+        //GIVEN a row written before zero counts were rejected
+        let events = vec![AgendaItemEvent::Created(AgendaItemCreatedEvent {
+            event_id: Uuid::new_v4(),
+            agenda_item_id: Uuid::new_v4(),
+            sequence: 0,
+            title: String::from("Yoga class"),
+            start: date(7, 3, 18),
+            end: None,
+            location: None,
+            recurrence: Some(AgendaRecurrence {
+                recurrence: Recurrence::Days(0),
+                until: None,
+                excluded_dates: Vec::new(),
+            }),
+        })];
+        let agenda_item = AgendaItem::from_events(&events).unwrap();
+
+        //WHEN expanding a window without an until to stop at
+        let occurrences = agenda_item.occurrences(date(7, 1, 0), date(9, 1, 0));
+
+        //THEN it ends after the first occurrence instead of repeating it forever
+        assert_eq!(
+            occurrences,
+            vec![Occurrence {
+                start: date(7, 3, 18),
+                end: None,
+            }]
+        );
+    }
+}
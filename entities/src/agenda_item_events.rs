@@ -0,0 +1,108 @@
+use crate::recurrence::Recurrence;
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+use uuid::Uuid;
+
+pub type AgendaItemId = Uuid;
+type AgendaItemEventId = Uuid;
+
+// When an agenda item is over, either at a fixed time or a duration after its start
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AgendaItemEnd {
+    At(NaiveDateTime),
+    After(Duration),
+}
+
+// Repeats an agenda item until a date, leaving out the occurrences that start on excluded dates
+#[derive(Debug, PartialEq, Clone)]
+pub struct AgendaRecurrence {
+    pub recurrence: Recurrence,
+    pub until: Option<NaiveDateTime>,
+    pub excluded_dates: Vec<NaiveDate>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct AgendaItemCreatedEvent {
+    pub title: String,
+    pub agenda_item_id: AgendaItemId,
+    pub event_id: AgendaItemEventId,
+    pub sequence: i32,
+    pub start: NaiveDateTime,
+    pub end: Option<AgendaItemEnd>,
+    pub location: Option<String>,
+    pub recurrence: Option<AgendaRecurrence>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct AgendaItemRescheduledEvent {
+    pub agenda_item_id: AgendaItemId,
+    pub event_id: AgendaItemEventId,
+    pub sequence: i32,
+    pub start: NaiveDateTime,
+    pub end: Option<AgendaItemEnd>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct AgendaItemRelocatedEvent {
+    pub agenda_item_id: AgendaItemId,
+    pub event_id: AgendaItemEventId,
+    pub sequence: i32,
+    pub location: Option<String>,
+}
+
+// Leaves out the occurrence starting on date, the other occurrences stay
+#[derive(Debug, PartialEq, Clone)]
+pub struct AgendaItemOccurrenceExcludedEvent {
+    pub agenda_item_id: AgendaItemId,
+    pub event_id: AgendaItemEventId,
+    pub sequence: i32,
+    pub date: NaiveDate,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct AgendaItemCancelledEvent {
+    pub agenda_item_id: AgendaItemId,
+    pub event_id: AgendaItemEventId,
+    pub sequence: i32,
+}
+
+// Everything that can happen to an agenda item during its lifetime, in the order it is stored
+#[derive(Debug, PartialEq, Clone)]
+pub enum AgendaItemEvent {
+    Created(AgendaItemCreatedEvent),
+    Rescheduled(AgendaItemRescheduledEvent),
+    Relocated(AgendaItemRelocatedEvent),
+    OccurrenceExcluded(AgendaItemOccurrenceExcludedEvent),
+    Cancelled(AgendaItemCancelledEvent),
+}
+
+impl AgendaItemEvent {
+    pub fn agenda_item_id(&self) -> AgendaItemId {
+        match self {
+            AgendaItemEvent::Created(event) => event.agenda_item_id,
+            AgendaItemEvent::Rescheduled(event) => event.agenda_item_id,
+            AgendaItemEvent::Relocated(event) => event.agenda_item_id,
+            AgendaItemEvent::OccurrenceExcluded(event) => event.agenda_item_id,
+            AgendaItemEvent::Cancelled(event) => event.agenda_item_id,
+        }
+    }
+
+    pub fn event_id(&self) -> AgendaItemEventId {
+        match self {
+            AgendaItemEvent::Created(event) => event.event_id,
+            AgendaItemEvent::Rescheduled(event) => event.event_id,
+            AgendaItemEvent::Relocated(event) => event.event_id,
+            AgendaItemEvent::OccurrenceExcluded(event) => event.event_id,
+            AgendaItemEvent::Cancelled(event) => event.event_id,
+        }
+    }
+
+    pub fn sequence(&self) -> i32 {
+        match self {
+            AgendaItemEvent::Created(event) => event.sequence,
+            AgendaItemEvent::Rescheduled(event) => event.sequence,
+            AgendaItemEvent::Relocated(event) => event.sequence,
+            AgendaItemEvent::OccurrenceExcluded(event) => event.sequence,
+            AgendaItemEvent::Cancelled(event) => event.sequence,
+        }
+    }
+}
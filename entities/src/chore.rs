@@ -0,0 +1,189 @@
+use crate::chore_events::{ChoreEvent, ChoreId};
+use crate::plannable::PlannableRef;
+use crate::recurrence::Recurrence;
+use chrono::NaiveDateTime;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ChoreStatus {
+    Open,
+    Done,
+    Skipped,
+    Expired,
+}
+
+// Current state of a chore, rebuilt by folding its events
+#[derive(Debug, PartialEq, Clone)]
+pub struct Chore {
+    pub chore_id: ChoreId,
+    pub title: String,
+    // Due date of the current occurrence
+    pub due_date: Option<NaiveDateTime>,
+    pub recurrence: Option<Recurrence>,
+    // Due date the occurrences are counted from, the first one or the one of the last reschedule.
+    // Counting from it keeps a monthly chore on the 31st in the months that have one.
+    pub schedule_start: Option<NaiveDateTime>,
+    // Number of occurrences finished since schedule_start, done, skipped or expired
+    pub occurrence: u32,
+    pub status: ChoreStatus,
+    // Number of occurrences that were done
+    pub times_done: u32,
+    pub converted_from: Option<PlannableRef>,
+    // Set once the chore was converted into another plannable, it is closed from then on
+    pub converted_into: Option<PlannableRef>,
+    // Number of events applied, the sequence of the next event to append
+    pub version: i32,
+}
+
+impl Chore {
+    // Folds the events of a single chore in sequence order. Returns None when the stream does not
+    // start with a Created event.
+    pub fn from_events(events: &[ChoreEvent]) -> Option<Self> {
+        let mut ordered: Vec<&ChoreEvent> = events.iter().collect();
+        ordered.sort_by_key(|event| event.sequence());
+        let mut ordered = ordered.into_iter();
+
+        let mut chore = match ordered.next()? {
+            ChoreEvent::Created(created) => Self {
+                chore_id: created.chore_id,
+                title: created.title.clone(),
+                due_date: created.due_date,
+                recurrence: created.recurrence,
+                schedule_start: created.due_date,
+                occurrence: 0,
+                status: ChoreStatus::Open,
+                times_done: 0,
+                converted_from: created.converted_from,
+                converted_into: None,
+                version: 1,
+            },
+            _ => return None,
+        };
+        ordered.for_each(|event| chore.apply(event));
+        Some(chore)
+    }
+
+    pub fn apply(&mut self, event: &ChoreEvent) {
+        match event {
+            ChoreEvent::Created(_) => {}
+            ChoreEvent::Rescheduled(rescheduled) => {
+                self.due_date = rescheduled.due_date;
+                self.schedule_start = rescheduled.due_date;
+                self.occurrence = 0;
+            }
+            ChoreEvent::Done(_) => {
+                self.times_done += 1;
+                self.finish_occurrence(ChoreStatus::Done);
+            }
+            ChoreEvent::Skipped(_) => self.finish_occurrence(ChoreStatus::Skipped),
+            ChoreEvent::Expired(_) => self.finish_occurrence(ChoreStatus::Expired),
+            ChoreEvent::Converted(converted) => self.converted_into = Some(converted.into),
+        }
+        self.version += 1;
+    }
+
+    // Chores SHOULD expire, an open chore lapses once its due date has passed. For a recurring chore
+    // that is the current occurrence only, expiring it moves the chore on to the next one.
+    pub fn should_expire(&self, now: NaiveDateTime) -> bool {
+        self.status == ChoreStatus::Open
+            && self.converted_into.is_none()
+            && self.due_date.is_some_and(|due_date| due_date <= now)
+    }
+
+    // A recurring chore stays open with the due date of its next occurrence, any other chore ends
+    // with the given status
+    fn finish_occurrence(&mut self, status: ChoreStatus) {
+        match self.recurrence {
+            Some(recurrence) => {
+                self.occurrence += 1;
+                self.due_date = self
+                    .schedule_start
+                    .and_then(|start| recurrence.nth_after(start, self.occurrence))
+            }
+            None => self.status = status,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Chore, ChoreStatus};
+    use crate::chore_events::{
+        ChoreCreatedEvent, ChoreDoneEvent, ChoreEvent, ChoreExpiredEvent, ChoreSkippedEvent,
+    };
+    use crate::recurrence::Recurrence;
+    use chrono::{NaiveDate, NaiveDateTime};
+    use uuid::Uuid;
+
+    fn date(month: u32, day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, month, day)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap()
+    }
+
+    fn created(chore_id: Uuid, recurrence: Option<Recurrence>) -> ChoreEvent {
+        ChoreEvent::Created(ChoreCreatedEvent {
+            event_id: Uuid::new_v4(),
+            chore_id,
+            sequence: 0,
+            title: String::from("Wash the car"),
+            due_date: Some(date(1, 31)),
+            recurrence,
+            converted_from: None,
+        })
+    }
+
+    #[test]
+    fn given_recurringchore_when_doneskippedandexpired_then_moveontonextoccurrence() {
+        let chore_id = Uuid::new_v4();
+        let events = vec![
+            created(chore_id, Some(Recurrence::Months(1))),
+            ChoreEvent::Done(ChoreDoneEvent {
+                event_id: Uuid::new_v4(),
+                chore_id,
+                sequence: 1,
+            }),
+            ChoreEvent::Skipped(ChoreSkippedEvent {
+                event_id: Uuid::new_v4(),
+                chore_id,
+                sequence: 2,
+            }),
+            ChoreEvent::Expired(ChoreExpiredEvent {
+                event_id: Uuid::new_v4(),
+                chore_id,
+                sequence: 3,
+            }),
+        ];
+        let chore = Chore::from_events(&events).unwrap();
+        assert_eq!(chore.status, ChoreStatus::Open);
+        assert_eq!(chore.due_date, Some(date(4, 30)));
+        assert_eq!(chore.times_done, 1);
+        assert_eq!(chore.version, 4);
+    }
+
+    #[test]
+    fn given_oneoffchore_when_done_then_done() {
+        let chore_id = Uuid::new_v4();
+        let events = vec![
+            created(chore_id, None),
+            ChoreEvent::Done(ChoreDoneEvent {
+                event_id: Uuid::new_v4(),
+                chore_id,
+                sequence: 1,
+            }),
+        ];
+        let chore = Chore::from_events(&events).unwrap();
+        assert_eq!(chore.status, ChoreStatus::Done);
+        assert_eq!(chore.due_date, Some(date(1, 31)));
+    }
+
+    #[test]
+    fn given_streamwithoutcreated_when_fold_then_none() {
+        let events = vec![ChoreEvent::Done(ChoreDoneEvent {
+            event_id: Uuid::new_v4(),
+            chore_id: Uuid::new_v4(),
+            sequence: 0,
+        })];
+        assert_eq!(Chore::from_events(&events), None);
+    }
+}
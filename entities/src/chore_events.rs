@@ -0,0 +1,105 @@
+use crate::plannable::PlannableRef;
+use crate::recurrence::Recurrence;
+use chrono::NaiveDateTime;
+use uuid::Uuid;
+
+pub type ChoreId = Uuid;
+type ChoreEventId = Uuid;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ChoreCreatedEvent {
+    pub title: String,
+    pub chore_id: ChoreId,
+    pub event_id: ChoreEventId,
+    pub sequence: i32,
+    pub due_date: Option<NaiveDateTime>,
+    pub recurrence: Option<Recurrence>,
+    // The plannable this chore was converted from
+    pub converted_from: Option<PlannableRef>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ChoreRescheduledEvent {
+    pub chore_id: ChoreId,
+    pub event_id: ChoreEventId,
+    pub sequence: i32,
+    pub due_date: Option<NaiveDateTime>,
+}
+
+// The current occurrence is done, a recurring chore moves on to the next one
+#[derive(Debug, PartialEq, Clone)]
+pub struct ChoreDoneEvent {
+    pub chore_id: ChoreId,
+    pub event_id: ChoreEventId,
+    pub sequence: i32,
+}
+
+// The current occurrence is left undone, a recurring chore moves on to the next one
+#[derive(Debug, PartialEq, Clone)]
+pub struct ChoreSkippedEvent {
+    pub chore_id: ChoreId,
+    pub event_id: ChoreEventId,
+    pub sequence: i32,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ChoreExpiredEvent {
+    pub chore_id: ChoreId,
+    pub event_id: ChoreEventId,
+    pub sequence: i32,
+}
+
+// Closes the chore, its work continues as the plannable it was converted into
+#[derive(Debug, PartialEq, Clone)]
+pub struct ChoreConvertedEvent {
+    pub chore_id: ChoreId,
+    pub event_id: ChoreEventId,
+    pub sequence: i32,
+    pub into: PlannableRef,
+}
+
+// Everything that can happen to a chore during its lifetime, in the order it is stored
+#[derive(Debug, PartialEq, Clone)]
+pub enum ChoreEvent {
+    Created(ChoreCreatedEvent),
+    Rescheduled(ChoreRescheduledEvent),
+    Done(ChoreDoneEvent),
+    Skipped(ChoreSkippedEvent),
+    Expired(ChoreExpiredEvent),
+    Converted(ChoreConvertedEvent),
+}
+
+impl ChoreEvent {
+    pub fn chore_id(&self) -> ChoreId {
+        match self {
+            ChoreEvent::Created(event) => event.chore_id,
+            ChoreEvent::Rescheduled(event) => event.chore_id,
+            ChoreEvent::Done(event) => event.chore_id,
+            ChoreEvent::Skipped(event) => event.chore_id,
+            ChoreEvent::Expired(event) => event.chore_id,
+            ChoreEvent::Converted(event) => event.chore_id,
+        }
+    }
+
+    pub fn event_id(&self) -> ChoreEventId {
+        match self {
+            ChoreEvent::Created(event) => event.event_id,
+            ChoreEvent::Rescheduled(event) => event.event_id,
+            ChoreEvent::Done(event) => event.event_id,
+            ChoreEvent::Skipped(event) => event.event_id,
+            ChoreEvent::Expired(event) => event.event_id,
+            ChoreEvent::Converted(event) => event.event_id,
+        }
+    }
+
+    pub fn sequence(&self) -> i32 {
+        match self {
+            ChoreEvent::Created(event) => event.sequence,
+            ChoreEvent::Rescheduled(event) => event.sequence,
+            ChoreEvent::Done(event) => event.sequence,
+            ChoreEvent::Skipped(event) => event.sequence,
+            ChoreEvent::Expired(event) => event.sequence,
+            ChoreEvent::Converted(event) => event.sequence,
+        }
+    }
+}
@@ -0,0 +1,573 @@
+use crate::plannable::{PlannableKind, PlannableRef};
+use crate::story::StoryStatus;
+use std::error::Error;
+use std::fmt;
+use uuid::Uuid;
+
+// Everything that can go wrong while storing or reading plannable events
+#[derive(Debug, PartialEq, Clone)]
+pub enum EventStoreError {
+    // The database could not be opened
+    Connection(String),
+    // The database has no plannable_events table (yet)
+    MissingSchema(String),
+    // The schema could not be brought up to date
+    Migration(String),
+    // A database constraint such as the unique (plannable_id, sequence) was violated
+    ConstraintViolation(String),
+    // An event handed to append belongs to another stream than the one appended to
+    ForeignEvent {
+        kind: String,
+        plannable_id: String,
+    },
+    // The stream was appended to by someone else since it was read
+    ConcurrencyConflict {
+        plannable_id: String,
+        expected_version: i32,
+        actual_version: i32,
+    },
+    // A stored row could not be decoded
+    CorruptBody {
+        event_id: String,
+        reason: String,
+    },
+    // A stored row has an event type this version does not know about
+    UnknownEventType {
+        event_id: String,
+        event_type: String,
+    },
+    // Any other database failure
+    Database(String),
+}
+
+impl fmt::Display for EventStoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventStoreError::Connection(reason) => {
+                write!(f, "Could not open the event store: {}", reason)
+            }
+            EventStoreError::MissingSchema(reason) => {
+                write!(f, "The event store has no schema: {}", reason)
+            }
+            EventStoreError::Migration(reason) => {
+                write!(f, "Could not migrate the event store: {}", reason)
+            }
+            EventStoreError::ConstraintViolation(reason) => {
+                write!(f, "Constraint violated: {}", reason)
+            }
+            EventStoreError::ForeignEvent { kind, plannable_id } => {
+                write!(f, "Event does not belong to {} {}", kind, plannable_id)
+            }
+            EventStoreError::ConcurrencyConflict {
+                plannable_id,
+                expected_version,
+                actual_version,
+            } => write!(
+                f,
+                "Concurrency conflict on {}: expected version {} but found {}",
+                plannable_id, expected_version, actual_version
+            ),
+            EventStoreError::CorruptBody { event_id, reason } => {
+                write!(f, "Event {} is corrupt: {}", event_id, reason)
+            }
+            EventStoreError::UnknownEventType {
+                event_id,
+                event_type,
+            } => write!(f, "Event {} has unknown type {}", event_id, event_type),
+            EventStoreError::Database(reason) => write!(f, "Database error: {}", reason),
+        }
+    }
+}
+
+impl Error for EventStoreError {}
+
+// A rule from the README comparison table that an item breaks
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Violation {
+    // A text that has to say something is blank
+    Empty {
+        field: &'static str,
+    },
+    // The kind MUST have a fixed timeline but the date is missing
+    MissingDate {
+        field: &'static str,
+    },
+    // A recurrence needs a date to count from
+    RecurrenceWithoutDate,
+    // A recurrence of every 0 days, weeks, months or years never moves on
+    ZeroRecurrence,
+    // The date leaves the plannable entirely in the past
+    InThePast {
+        field: &'static str,
+    },
+    // A date that has to come after another one does not, e.g. an end before the start
+    NotAfter {
+        field: &'static str,
+        other: &'static str,
+    },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::Empty { field } => write!(f, "the {} is empty", field),
+            Violation::MissingDate { field } => write!(f, "the {} is required", field),
+            Violation::RecurrenceWithoutDate => {
+                write!(f, "a recurrence needs a date to start from")
+            }
+            Violation::ZeroRecurrence => write!(f, "a recurrence has to repeat at least every 1"),
+            Violation::InThePast { field } => write!(f, "the {} is in the past", field),
+            Violation::NotAfter { field, other } => {
+                write!(f, "the {} has to come after the {}", field, other)
+            }
+        }
+    }
+}
+
+// All the rules an item of one kind breaks, so they can be shown at once
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ValidationError {
+    pub kind: PlannableKind,
+    pub violations: Vec<Violation>,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let violations: Vec<String> = self.violations.iter().map(|v| v.to_string()).collect();
+        write!(f, "Invalid {}: {}", self.kind, violations.join(", "))
+    }
+}
+
+impl Error for ValidationError {}
+
+// Everything that can go wrong while creating or changing a plannable
+#[derive(Debug, PartialEq, Clone)]
+pub enum PlannableError {
+    Invalid(ValidationError),
+    EventStore(EventStoreError),
+}
+
+impl fmt::Display for PlannableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlannableError::Invalid(error) => write!(f, "{}", error),
+            PlannableError::EventStore(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for PlannableError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PlannableError::Invalid(error) => Some(error),
+            PlannableError::EventStore(error) => Some(error),
+        }
+    }
+}
+
+impl From<ValidationError> for PlannableError {
+    fn from(error: ValidationError) -> Self {
+        PlannableError::Invalid(error)
+    }
+}
+
+impl From<EventStoreError> for PlannableError {
+    fn from(error: EventStoreError) -> Self {
+        PlannableError::EventStore(error)
+    }
+}
+
+// Everything that can go wrong while moving a story through its workflow
+#[derive(Debug, PartialEq, Clone)]
+pub enum StoryError {
+    // No story was ever created with this id
+    NotFound(String),
+    // The story is not in the status the transition starts from
+    IllegalTransition {
+        story_id: String,
+        from: StoryStatus,
+        to: StoryStatus,
+    },
+    Invalid(ValidationError),
+    EventStore(EventStoreError),
+}
+
+impl fmt::Display for StoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StoryError::NotFound(story_id) => write!(f, "Story {} does not exist", story_id),
+            StoryError::IllegalTransition { story_id, from, to } => {
+                write!(f, "Story {} can not move from {} to {}", story_id, from, to)
+            }
+            StoryError::Invalid(error) => write!(f, "{}", error),
+            StoryError::EventStore(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for StoryError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StoryError::Invalid(error) => Some(error),
+            StoryError::EventStore(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<EventStoreError> for StoryError {
+    fn from(error: EventStoreError) -> Self {
+        StoryError::EventStore(error)
+    }
+}
+
+impl From<ValidationError> for StoryError {
+    fn from(error: ValidationError) -> Self {
+        StoryError::Invalid(error)
+    }
+}
+
+// Everything that can go wrong while completing, reopening, removing or editing a todo
+#[derive(Debug, PartialEq, Clone)]
+pub enum TodoError {
+    // No todo was ever created with this id, or it was removed
+    NotFound(String),
+    AlreadyCompleted(String),
+    // Only a completed todo can be reopened
+    NotCompleted(String),
+    // The todo is closed by a conversion, its wish continues in the other plannable
+    Converted { todo_id: String, into: PlannableRef },
+    Invalid(ValidationError),
+    EventStore(EventStoreError),
+}
+
+impl fmt::Display for TodoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TodoError::NotFound(todo_id) => write!(f, "Todo {} does not exist", todo_id),
+            TodoError::AlreadyCompleted(todo_id) => {
+                write!(f, "Todo {} is already completed", todo_id)
+            }
+            TodoError::NotCompleted(todo_id) => write!(f, "Todo {} is not completed", todo_id),
+            TodoError::Converted { todo_id, into } => {
+                write!(f, "Todo {} was converted into a {}", todo_id, into)
+            }
+            TodoError::Invalid(error) => write!(f, "{}", error),
+            TodoError::EventStore(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for TodoError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            TodoError::Invalid(error) => Some(error),
+            TodoError::EventStore(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<EventStoreError> for TodoError {
+    fn from(error: EventStoreError) -> Self {
+        TodoError::EventStore(error)
+    }
+}
+
+impl From<ValidationError> for TodoError {
+    fn from(error: ValidationError) -> Self {
+        TodoError::Invalid(error)
+    }
+}
+
+// Everything that can go wrong while resolving an id, or the start of one, typed by the user
+#[derive(Debug, PartialEq, Clone)]
+pub enum IdError {
+    // Ids only consist of hex digits and dashes
+    Malformed(String),
+    NotFound {
+        kind: PlannableKind,
+        prefix: String,
+    },
+    // More than one id starts with the prefix, the candidates are in ascending order
+    Ambiguous {
+        kind: PlannableKind,
+        prefix: String,
+        candidates: Vec<Uuid>,
+    },
+    EventStore(EventStoreError),
+}
+
+impl fmt::Display for IdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdError::Malformed(prefix) => write!(f, "'{}' is not an id", prefix),
+            IdError::NotFound { kind, prefix } => {
+                write!(f, "No {} id starts with {}", kind, prefix)
+            }
+            IdError::Ambiguous {
+                kind,
+                prefix,
+                candidates,
+            } => {
+                let candidates: Vec<String> = candidates.iter().map(|id| id.to_string()).collect();
+                write!(
+                    f,
+                    "The {} id {} is ambiguous, candidates are {}",
+                    kind,
+                    prefix,
+                    candidates.join(", ")
+                )
+            }
+            IdError::EventStore(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for IdError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            IdError::EventStore(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<EventStoreError> for IdError {
+    fn from(error: EventStoreError) -> Self {
+        IdError::EventStore(error)
+    }
+}
+
+// Everything that can go wrong while converting a plannable into another kind
+#[derive(Debug, PartialEq, Clone)]
+pub enum ConversionError {
+    NotFound(PlannableRef),
+    // The source is already closed by an earlier conversion
+    AlreadyConverted {
+        source: PlannableRef,
+        into: PlannableRef,
+    },
+    Unsupported {
+        from: PlannableKind,
+        to: PlannableKind,
+    },
+    // The source does not meet the rules of the target kind
+    TargetRuleViolated(ValidationError),
+    EventStore(EventStoreError),
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConversionError::NotFound(source) => write!(f, "The {} does not exist", source),
+            ConversionError::AlreadyConverted { source, into } => {
+                write!(f, "The {} was already converted into a {}", source, into)
+            }
+            ConversionError::Unsupported { from, to } => {
+                write!(f, "A {} can not be converted into a {}", from, to)
+            }
+            ConversionError::TargetRuleViolated(error) => {
+                write!(f, "Can not convert: {}", error)
+            }
+            ConversionError::EventStore(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for ConversionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ConversionError::TargetRuleViolated(error) => Some(error),
+            ConversionError::EventStore(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<EventStoreError> for ConversionError {
+    fn from(error: EventStoreError) -> Self {
+        ConversionError::EventStore(error)
+    }
+}
+
+// Everything that can go wrong while putting a view together
+#[derive(Debug, PartialEq, Clone)]
+pub enum ViewError {
+    // The window ends after the last date and time that can be represented
+    WindowOutOfRange,
+    EventStore(EventStoreError),
+}
+
+impl fmt::Display for ViewError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ViewError::WindowOutOfRange => {
+                write!(f, "The window ends after the last date that can be shown")
+            }
+            ViewError::EventStore(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for ViewError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ViewError::EventStore(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<EventStoreError> for ViewError {
+    fn from(error: EventStoreError) -> Self {
+        ViewError::EventStore(error)
+    }
+}
+
+// Everything that can go wrong while changing an existing chore
+#[derive(Debug, PartialEq, Clone)]
+pub enum ChoreError {
+    // No chore was ever created with this id
+    NotFound(String),
+    // A one-off chore that is done, skipped or expired has no occurrence left to change
+    NotOpen(String),
+    // The chore is closed by a conversion, its wish continues in the other plannable
+    Converted {
+        chore_id: String,
+        into: PlannableRef,
+    },
+    Invalid(ValidationError),
+    EventStore(EventStoreError),
+}
+
+impl fmt::Display for ChoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChoreError::NotFound(chore_id) => write!(f, "Chore {} does not exist", chore_id),
+            ChoreError::NotOpen(chore_id) => write!(f, "Chore {} is no longer open", chore_id),
+            ChoreError::Converted { chore_id, into } => {
+                write!(f, "Chore {} was converted into a {}", chore_id, into)
+            }
+            ChoreError::Invalid(error) => write!(f, "{}", error),
+            ChoreError::EventStore(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for ChoreError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ChoreError::Invalid(error) => Some(error),
+            ChoreError::EventStore(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<EventStoreError> for ChoreError {
+    fn from(error: EventStoreError) -> Self {
+        ChoreError::EventStore(error)
+    }
+}
+
+impl From<ValidationError> for ChoreError {
+    fn from(error: ValidationError) -> Self {
+        ChoreError::Invalid(error)
+    }
+}
+
+// Everything that can go wrong while changing an existing agenda item
+#[derive(Debug, PartialEq, Clone)]
+pub enum AgendaItemError {
+    // No agenda item was ever created with this id
+    NotFound(String),
+    Cancelled(String),
+    Invalid(ValidationError),
+    EventStore(EventStoreError),
+}
+
+impl fmt::Display for AgendaItemError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AgendaItemError::NotFound(agenda_item_id) => {
+                write!(f, "Agenda item {} does not exist", agenda_item_id)
+            }
+            AgendaItemError::Cancelled(agenda_item_id) => {
+                write!(f, "Agenda item {} is cancelled", agenda_item_id)
+            }
+            AgendaItemError::Invalid(error) => write!(f, "{}", error),
+            AgendaItemError::EventStore(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for AgendaItemError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AgendaItemError::Invalid(error) => Some(error),
+            AgendaItemError::EventStore(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<EventStoreError> for AgendaItemError {
+    fn from(error: EventStoreError) -> Self {
+        AgendaItemError::EventStore(error)
+    }
+}
+
+impl From<ValidationError> for AgendaItemError {
+    fn from(error: ValidationError) -> Self {
+        AgendaItemError::Invalid(error)
+    }
+}
+
+// Everything that can go wrong while acknowledging or snoozing a reminder
+#[derive(Debug, PartialEq, Clone)]
+pub enum ReminderError {
+    // No reminder was ever created with this id
+    NotFound(String),
+    // A one-off reminder that was acknowledged has no notification left
+    Acknowledged(String),
+    Invalid(ValidationError),
+    EventStore(EventStoreError),
+}
+
+impl fmt::Display for ReminderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReminderError::NotFound(reminder_id) => {
+                write!(f, "Reminder {} does not exist", reminder_id)
+            }
+            ReminderError::Acknowledged(reminder_id) => {
+                write!(f, "Reminder {} is already acknowledged", reminder_id)
+            }
+            ReminderError::Invalid(error) => write!(f, "{}", error),
+            ReminderError::EventStore(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for ReminderError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ReminderError::Invalid(error) => Some(error),
+            ReminderError::EventStore(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<EventStoreError> for ReminderError {
+    fn from(error: EventStoreError) -> Self {
+        ReminderError::EventStore(error)
+    }
+}
+
+impl From<ValidationError> for ReminderError {
+    fn from(error: ValidationError) -> Self {
+        ReminderError::Invalid(error)
+    }
+}
@@ -0,0 +1,111 @@
+use crate::impediment_events::{ImpedimentEvent, ImpedimentId};
+use crate::plannable::PlannableRef;
+use chrono::NaiveDateTime;
+
+// Current state of an impediment, rebuilt by folding its events
+#[derive(Debug, PartialEq, Clone)]
+pub struct Impediment {
+    pub impediment_id: ImpedimentId,
+    pub title: String,
+    pub expires_at: NaiveDateTime,
+    pub blocks: Vec<PlannableRef>,
+    pub resolved: bool,
+    pub expired: bool,
+    // Number of events applied, the sequence of the next event to append
+    pub version: i32,
+}
+
+impl Impediment {
+    // Folds the events of a single impediment in sequence order. Returns None when the stream does
+    // not start with a Raised event.
+    pub fn from_events(events: &[ImpedimentEvent]) -> Option<Self> {
+        let mut ordered: Vec<&ImpedimentEvent> = events.iter().collect();
+        ordered.sort_by_key(|event| event.sequence());
+        let mut ordered = ordered.into_iter();
+
+        let mut impediment = match ordered.next()? {
+            ImpedimentEvent::Raised(raised) => Self {
+                impediment_id: raised.impediment_id,
+                title: raised.title.clone(),
+                expires_at: raised.expires_at,
+                blocks: raised.blocks.clone(),
+                resolved: false,
+                expired: false,
+                version: 1,
+            },
+            _ => return None,
+        };
+        ordered.for_each(|event| impediment.apply(event));
+        Some(impediment)
+    }
+
+    pub fn apply(&mut self, event: &ImpedimentEvent) {
+        match event {
+            ImpedimentEvent::Raised(_) => {}
+            ImpedimentEvent::Resolved(_) => self.resolved = true,
+            ImpedimentEvent::Expired(_) => self.expired = true,
+        }
+        self.version += 1;
+    }
+
+    // An impediment only blocks until it is resolved or expires
+    pub fn is_active(&self, now: NaiveDateTime) -> bool {
+        !self.resolved && !self.expired && now < self.expires_at
+    }
+
+    // Impediments MUST expire, an unresolved one lapses at its expiry date
+    pub fn should_expire(&self, now: NaiveDateTime) -> bool {
+        !self.resolved && !self.expired && self.expires_at <= now
+    }
+
+    pub fn is_blocking(&self, plannable: &PlannableRef, now: NaiveDateTime) -> bool {
+        self.is_active(now) && self.blocks.contains(plannable)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Impediment;
+    use crate::impediment_events::{
+        ImpedimentEvent, ImpedimentRaisedEvent, ImpedimentResolvedEvent,
+    };
+    use crate::plannable::PlannableRef;
+    use chrono::{NaiveDate, NaiveDateTime};
+    use uuid::Uuid;
+
+    fn date(day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, 9, day)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn given_impediment_when_expiredorresolved_then_nolongerblocking() {
+        let impediment_id = Uuid::new_v4();
+        let todo = PlannableRef::Todo(Uuid::new_v4());
+        let raised = ImpedimentEvent::Raised(ImpedimentRaisedEvent {
+            event_id: Uuid::new_v4(),
+            impediment_id,
+            sequence: 0,
+            title: String::from("Bank card stopped working"),
+            expires_at: date(10),
+            blocks: vec![todo],
+        });
+        let impediment = Impediment::from_events(std::slice::from_ref(&raised)).unwrap();
+        assert!(impediment.is_blocking(&todo, date(9)));
+        assert!(!impediment.is_blocking(&PlannableRef::Todo(Uuid::new_v4()), date(9)));
+        assert!(!impediment.is_blocking(&todo, date(10)));
+
+        let resolved = Impediment::from_events(&[
+            raised,
+            ImpedimentEvent::Resolved(ImpedimentResolvedEvent {
+                event_id: Uuid::new_v4(),
+                impediment_id,
+                sequence: 1,
+            }),
+        ])
+        .unwrap();
+        assert!(!resolved.is_blocking(&todo, date(9)));
+    }
+}
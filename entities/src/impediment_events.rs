@@ -0,0 +1,66 @@
+use crate::plannable::PlannableRef;
+use chrono::NaiveDateTime;
+use uuid::Uuid;
+
+pub type ImpedimentId = Uuid;
+type ImpedimentEventId = Uuid;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ImpedimentRaisedEvent {
+    pub title: String,
+    pub impediment_id: ImpedimentId,
+    pub event_id: ImpedimentEventId,
+    pub sequence: i32,
+    // Impediments are immediate, after this time it no longer blocks anything
+    pub expires_at: NaiveDateTime,
+    pub blocks: Vec<PlannableRef>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ImpedimentResolvedEvent {
+    pub impediment_id: ImpedimentId,
+    pub event_id: ImpedimentEventId,
+    pub sequence: i32,
+}
+
+// The impediment lapsed at its expiry date without being resolved
+#[derive(Debug, PartialEq, Clone)]
+pub struct ImpedimentExpiredEvent {
+    pub impediment_id: ImpedimentId,
+    pub event_id: ImpedimentEventId,
+    pub sequence: i32,
+}
+
+// Everything that can happen to an impediment during its lifetime, in the order it is stored
+#[derive(Debug, PartialEq, Clone)]
+pub enum ImpedimentEvent {
+    Raised(ImpedimentRaisedEvent),
+    Resolved(ImpedimentResolvedEvent),
+    Expired(ImpedimentExpiredEvent),
+}
+
+impl ImpedimentEvent {
+    pub fn impediment_id(&self) -> ImpedimentId {
+        match self {
+            ImpedimentEvent::Raised(event) => event.impediment_id,
+            ImpedimentEvent::Resolved(event) => event.impediment_id,
+            ImpedimentEvent::Expired(event) => event.impediment_id,
+        }
+    }
+
+    pub fn event_id(&self) -> ImpedimentEventId {
+        match self {
+            ImpedimentEvent::Raised(event) => event.event_id,
+            ImpedimentEvent::Resolved(event) => event.event_id,
+            ImpedimentEvent::Expired(event) => event.event_id,
+        }
+    }
+
+    pub fn sequence(&self) -> i32 {
+        match self {
+            ImpedimentEvent::Raised(event) => event.sequence,
+            ImpedimentEvent::Resolved(event) => event.sequence,
+            ImpedimentEvent::Expired(event) => event.sequence,
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use crate::issue_events::{IssueEvent, IssueId, Severity};
+
+// Current state of an issue, rebuilt by folding its events
+#[derive(Debug, PartialEq, Clone)]
+pub struct Issue {
+    pub issue_id: IssueId,
+    pub title: String,
+    pub severity: Severity,
+    pub comments: Vec<String>,
+    // Note of the last resolution, kept when the issue is reopened
+    pub resolution: Option<String>,
+    pub resolved: bool,
+    // Number of events applied, the sequence of the next event to append
+    pub version: i32,
+}
+
+impl Issue {
+    // Folds the events of a single issue in sequence order. Returns None when the stream does not
+    // start with a Raised event.
+    pub fn from_events(events: &[IssueEvent]) -> Option<Self> {
+        let mut ordered: Vec<&IssueEvent> = events.iter().collect();
+        ordered.sort_by_key(|event| event.sequence());
+        let mut ordered = ordered.into_iter();
+
+        let mut issue = match ordered.next()? {
+            IssueEvent::Raised(raised) => Self {
+                issue_id: raised.issue_id,
+                title: raised.title.clone(),
+                severity: raised.severity,
+                comments: Vec::new(),
+                resolution: None,
+                resolved: false,
+                version: 1,
+            },
+            _ => return None,
+        };
+        ordered.for_each(|event| issue.apply(event));
+        Some(issue)
+    }
+
+    pub fn apply(&mut self, event: &IssueEvent) {
+        match event {
+            IssueEvent::Raised(_) => {}
+            IssueEvent::Commented(commented) => self.comments.push(commented.comment.clone()),
+            IssueEvent::Resolved(resolved) => {
+                self.resolution = Some(resolved.resolution.clone());
+                self.resolved = true;
+            }
+            IssueEvent::Reopened(_) => self.resolved = false,
+        }
+        self.version += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Issue;
+    use crate::issue_events::{
+        IssueCommentedEvent, IssueEvent, IssueRaisedEvent, IssueReopenedEvent, IssueResolvedEvent,
+        Severity,
+    };
+    use uuid::Uuid;
+
+    #[test]
+    fn given_resolvedandreopenedissue_when_fold_then_openwithlastresolution() {
+        let issue_id = Uuid::new_v4();
+        let events = vec![
+            IssueEvent::Raised(IssueRaisedEvent {
+                event_id: Uuid::new_v4(),
+                issue_id,
+                sequence: 0,
+                title: String::from("Dishwasher not performing up to the mark"),
+                severity: Severity::Medium,
+            }),
+            IssueEvent::Commented(IssueCommentedEvent {
+                event_id: Uuid::new_v4(),
+                issue_id,
+                sequence: 1,
+                comment: String::from("Filter is clogged"),
+            }),
+            IssueEvent::Resolved(IssueResolvedEvent {
+                event_id: Uuid::new_v4(),
+                issue_id,
+                sequence: 2,
+                resolution: String::from("Cleaned the filter"),
+            }),
+            IssueEvent::Reopened(IssueReopenedEvent {
+                event_id: Uuid::new_v4(),
+                issue_id,
+                sequence: 3,
+            }),
+        ];
+        let issue = Issue::from_events(&events).unwrap();
+        assert!(!issue.resolved);
+        assert_eq!(issue.resolution, Some(String::from("Cleaned the filter")));
+        assert_eq!(issue.comments, vec![String::from("Filter is clogged")]);
+        assert_eq!(issue.version, 4);
+    }
+}
@@ -0,0 +1,83 @@
+use uuid::Uuid;
+
+pub type IssueId = Uuid;
+type IssueEventId = Uuid;
+
+// Ordered from least to most severe
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Severity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct IssueRaisedEvent {
+    pub title: String,
+    pub issue_id: IssueId,
+    pub event_id: IssueEventId,
+    pub sequence: i32,
+    pub severity: Severity,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct IssueCommentedEvent {
+    pub issue_id: IssueId,
+    pub event_id: IssueEventId,
+    pub sequence: i32,
+    pub comment: String,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct IssueResolvedEvent {
+    pub issue_id: IssueId,
+    pub event_id: IssueEventId,
+    pub sequence: i32,
+    pub resolution: String,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct IssueReopenedEvent {
+    pub issue_id: IssueId,
+    pub event_id: IssueEventId,
+    pub sequence: i32,
+}
+
+// Everything that can happen to an issue during its lifetime, in the order it is stored
+#[derive(Debug, PartialEq, Clone)]
+pub enum IssueEvent {
+    Raised(IssueRaisedEvent),
+    Commented(IssueCommentedEvent),
+    Resolved(IssueResolvedEvent),
+    Reopened(IssueReopenedEvent),
+}
+
+impl IssueEvent {
+    pub fn issue_id(&self) -> IssueId {
+        match self {
+            IssueEvent::Raised(event) => event.issue_id,
+            IssueEvent::Commented(event) => event.issue_id,
+            IssueEvent::Resolved(event) => event.issue_id,
+            IssueEvent::Reopened(event) => event.issue_id,
+        }
+    }
+
+    pub fn event_id(&self) -> IssueEventId {
+        match self {
+            IssueEvent::Raised(event) => event.event_id,
+            IssueEvent::Commented(event) => event.event_id,
+            IssueEvent::Resolved(event) => event.event_id,
+            IssueEvent::Reopened(event) => event.event_id,
+        }
+    }
+
+    pub fn sequence(&self) -> i32 {
+        match self {
+            IssueEvent::Raised(event) => event.sequence,
+            IssueEvent::Commented(event) => event.sequence,
+            IssueEvent::Resolved(event) => event.sequence,
+            IssueEvent::Reopened(event) => event.sequence,
+        }
+    }
+}
@@ -1,2 +1,19 @@
+pub mod agenda_item;
+pub mod agenda_item_events;
+pub mod chore;
+pub mod chore_events;
+pub mod errors;
+pub mod impediment;
+pub mod impediment_events;
+pub mod issue;
+pub mod issue_events;
+pub mod plannable;
+pub mod recurrence;
+pub mod reminder;
+pub mod reminder_events;
+pub mod story;
+pub mod story_events;
+pub mod task;
 pub mod task_events;
+pub mod todo;
 pub mod todo_events;
@@ -0,0 +1,118 @@
+use crate::chore_events::ChoreId;
+use crate::story_events::StoryId;
+use crate::task_events::TaskId;
+use crate::todo_events::TodoId;
+use std::fmt;
+use uuid::Uuid;
+
+// The kinds of plannables from the README
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum PlannableKind {
+    Todo,
+    Task,
+    Chore,
+    AgendaItem,
+    Reminder,
+    Story,
+    Issue,
+    Impediment,
+}
+
+impl fmt::Display for PlannableKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            PlannableKind::Todo => "todo",
+            PlannableKind::Task => "task",
+            PlannableKind::Chore => "chore",
+            PlannableKind::AgendaItem => "agenda item",
+            PlannableKind::Reminder => "reminder",
+            PlannableKind::Story => "story",
+            PlannableKind::Issue => "issue",
+            PlannableKind::Impediment => "impediment",
+        };
+        write!(f, "{}", kind)
+    }
+}
+
+impl PlannableKind {
+    pub const ALL: [PlannableKind; 8] = [
+        PlannableKind::Todo,
+        PlannableKind::Task,
+        PlannableKind::Chore,
+        PlannableKind::AgendaItem,
+        PlannableKind::Reminder,
+        PlannableKind::Story,
+        PlannableKind::Issue,
+        PlannableKind::Impediment,
+    ];
+
+    pub fn urgency(&self) -> Urgency {
+        match self {
+            PlannableKind::Task | PlannableKind::Impediment => Urgency::Immediate,
+            PlannableKind::Chore => Urgency::High,
+            PlannableKind::Story | PlannableKind::Issue => Urgency::Individual,
+            PlannableKind::Todo => Urgency::NotUrgent,
+            PlannableKind::AgendaItem | PlannableKind::Reminder => Urgency::NotApplicable,
+        }
+    }
+}
+
+// The urgency column of the README comparison table, most urgent first
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Urgency {
+    Immediate,
+    High,
+    // Up to whoever picks it up
+    Individual,
+    NotUrgent,
+    // Bound to a time instead, like agenda items and reminders
+    NotApplicable,
+}
+
+impl fmt::Display for Urgency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let urgency = match self {
+            Urgency::Immediate => "immediate",
+            Urgency::High => "high",
+            Urgency::Individual => "individual",
+            Urgency::NotUrgent => "not urgent",
+            Urgency::NotApplicable => "not applicable",
+        };
+        write!(f, "{}", urgency)
+    }
+}
+
+// Points at a plannable of another kind, e.g. the work an impediment blocks
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PlannableRef {
+    Todo(TodoId),
+    Task(TaskId),
+    Chore(ChoreId),
+    Story(StoryId),
+}
+
+impl PlannableRef {
+    pub fn kind(&self) -> PlannableKind {
+        match self {
+            PlannableRef::Todo(_) => PlannableKind::Todo,
+            PlannableRef::Task(_) => PlannableKind::Task,
+            PlannableRef::Chore(_) => PlannableKind::Chore,
+            PlannableRef::Story(_) => PlannableKind::Story,
+        }
+    }
+
+    pub fn id(&self) -> Uuid {
+        match self {
+            PlannableRef::Todo(id)
+            | PlannableRef::Task(id)
+            | PlannableRef::Chore(id)
+            | PlannableRef::Story(id) => *id,
+        }
+    }
+}
+
+impl fmt::Display for PlannableRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.kind(), self.id())
+    }
+}
@@ -0,0 +1,48 @@
+use chrono::{Duration, Months, NaiveDateTime};
+
+// How often a plannable comes back, every count days, weeks, months or years
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Recurrence {
+    Days(u32),
+    Weeks(u32),
+    Months(u32),
+    Years(u32),
+}
+
+impl Recurrence {
+    // The nth occurrence counted from start, where start itself is the 0th. Counting from start
+    // keeps monthly and yearly occurrences on the same day where the month has it. None when out of range.
+    // A zero count never comes back, validation rejects it but older rows may still hold one, so
+    // there is no occurrence after start then. Otherwise expanding it would never end.
+    pub fn nth_after(&self, start: NaiveDateTime, n: u32) -> Option<NaiveDateTime> {
+        if n == 0 {
+            return Some(start);
+        }
+        if self.count() == 0 {
+            return None;
+        }
+        match self {
+            Recurrence::Days(days) => {
+                start.checked_add_signed(Duration::days(i64::from(*days) * i64::from(n)))
+            }
+            Recurrence::Weeks(weeks) => {
+                start.checked_add_signed(Duration::weeks(i64::from(*weeks) * i64::from(n)))
+            }
+            Recurrence::Months(months) => {
+                start.checked_add_months(Months::new(months.checked_mul(n)?))
+            }
+            Recurrence::Years(years) => {
+                start.checked_add_months(Months::new(years.checked_mul(12)?.checked_mul(n)?))
+            }
+        }
+    }
+
+    fn count(&self) -> u32 {
+        match self {
+            Recurrence::Days(count)
+            | Recurrence::Weeks(count)
+            | Recurrence::Months(count)
+            | Recurrence::Years(count) => *count,
+        }
+    }
+}
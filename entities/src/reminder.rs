@@ -0,0 +1,203 @@
+use crate::recurrence::Recurrence;
+use crate::reminder_events::{ReminderEvent, ReminderId};
+use chrono::NaiveDateTime;
+
+// Current state of a reminder, rebuilt by folding its events
+#[derive(Debug, PartialEq, Clone)]
+pub struct Reminder {
+    pub reminder_id: ReminderId,
+    pub title: String,
+    // Trigger time of the current notification
+    pub trigger_at: NaiveDateTime,
+    // Trigger time of the first notification, later ones are counted from it so a yearly reminder
+    // on Feb 29 is back on Feb 29 in leap years
+    pub first_trigger_at: NaiveDateTime,
+    pub recurrence: Option<Recurrence>,
+    pub snoozed_until: Option<NaiveDateTime>,
+    // Which notification counted from the first one is the current one, the first is the 0th
+    pub occurrence: u32,
+    // Set once there is no notification left, a recurring reminder always has a next one
+    pub acknowledged: bool,
+    // Number of events applied, the sequence of the next event to append
+    pub version: i32,
+}
+
+impl Reminder {
+    // Folds the events of a single reminder in sequence order. Returns None when the stream does
+    // not start with a Created event.
+    pub fn from_events(events: &[ReminderEvent]) -> Option<Self> {
+        let mut ordered: Vec<&ReminderEvent> = events.iter().collect();
+        ordered.sort_by_key(|event| event.sequence());
+        let mut ordered = ordered.into_iter();
+
+        let mut reminder = match ordered.next()? {
+            ReminderEvent::Created(created) => Self {
+                reminder_id: created.reminder_id,
+                title: created.title.clone(),
+                trigger_at: created.trigger_at,
+                first_trigger_at: created.trigger_at,
+                recurrence: created.recurrence,
+                snoozed_until: None,
+                occurrence: 0,
+                acknowledged: false,
+                version: 1,
+            },
+            _ => return None,
+        };
+        ordered.for_each(|event| reminder.apply(event));
+        Some(reminder)
+    }
+
+    pub fn apply(&mut self, event: &ReminderEvent) {
+        match event {
+            ReminderEvent::Created(_) => {}
+            ReminderEvent::Acknowledged(acknowledged) => {
+                self.snoozed_until = None;
+                let next = self.recurrence.and_then(|recurrence| {
+                    self.next_occurrence(recurrence, acknowledged.acknowledged_at)
+                });
+                match next {
+                    Some(next) => self.trigger_at = next,
+                    None => self.acknowledged = true,
+                }
+            }
+            ReminderEvent::Snoozed(snoozed) => self.snoozed_until = Some(snoozed.until),
+        }
+        self.version += 1;
+    }
+
+    // Moves on to the first occurrence after acknowledged_at, so the occurrences that were missed
+    // while nobody looked are not notified one by one. None when there is none left in range.
+    fn next_occurrence(
+        &mut self,
+        recurrence: Recurrence,
+        acknowledged_at: NaiveDateTime,
+    ) -> Option<NaiveDateTime> {
+        let mut occurrence = self.occurrence.checked_add(1)?;
+        loop {
+            let trigger_at = recurrence.nth_after(self.first_trigger_at, occurrence)?;
+            if trigger_at > acknowledged_at {
+                self.occurrence = occurrence;
+                return Some(trigger_at);
+            }
+            occurrence = occurrence.checked_add(1)?;
+        }
+    }
+
+    // When the reminder notifies next, None once it is acknowledged for good
+    pub fn notify_at(&self) -> Option<NaiveDateTime> {
+        if self.acknowledged {
+            return None;
+        }
+        Some(self.snoozed_until.unwrap_or(self.trigger_at))
+    }
+
+    pub fn is_due(&self, now: NaiveDateTime) -> bool {
+        self.notify_at().is_some_and(|notify_at| notify_at <= now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Reminder;
+    use crate::recurrence::Recurrence;
+    use crate::reminder_events::{
+        ReminderAcknowledgedEvent, ReminderCreatedEvent, ReminderEvent, ReminderSnoozedEvent,
+    };
+    use chrono::{NaiveDate, NaiveDateTime};
+    use uuid::Uuid;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(8, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn given_yearlyreminder_when_acknowledged_then_duenextyear() {
+        let reminder_id = Uuid::new_v4();
+        let acknowledged = |sequence: i32, acknowledged_at: NaiveDateTime| {
+            ReminderEvent::Acknowledged(ReminderAcknowledgedEvent {
+                event_id: Uuid::new_v4(),
+                reminder_id,
+                sequence,
+                acknowledged_at,
+            })
+        };
+        let mut events = vec![
+            ReminderEvent::Created(ReminderCreatedEvent {
+                event_id: Uuid::new_v4(),
+                reminder_id,
+                sequence: 0,
+                title: String::from("Birthday list"),
+                trigger_at: date(2024, 2, 29),
+                recurrence: Some(Recurrence::Years(1)),
+            }),
+            acknowledged(1, date(2024, 2, 29)),
+        ];
+        let reminder = Reminder::from_events(&events).unwrap();
+        assert_eq!(reminder.trigger_at, date(2025, 2, 28));
+        assert!(!reminder.is_due(date(2025, 2, 27)));
+        assert!(reminder.is_due(date(2025, 2, 28)));
+
+        //The years without Feb 29 do not move the next leap year to Feb 28
+        events.extend([
+            acknowledged(2, date(2025, 2, 28)),
+            acknowledged(3, date(2026, 2, 28)),
+            acknowledged(4, date(2027, 2, 28)),
+        ]);
+        let reminder = Reminder::from_events(&events).unwrap();
+        assert_eq!(reminder.trigger_at, date(2028, 2, 29));
+    }
+
+    #[test]
+    fn given_weeklyreminderlagging_when_acknowledged_then_firstoccurrenceafteracknowledgement() {
+        let reminder_id = Uuid::new_v4();
+        let events = vec![
+            ReminderEvent::Created(ReminderCreatedEvent {
+                event_id: Uuid::new_v4(),
+                reminder_id,
+                sequence: 0,
+                title: String::from("Put the bins out"),
+                trigger_at: date(2023, 9, 1),
+                recurrence: Some(Recurrence::Weeks(1)),
+            }),
+            //Sep 8 and Sep 15 were missed as well
+            ReminderEvent::Acknowledged(ReminderAcknowledgedEvent {
+                event_id: Uuid::new_v4(),
+                reminder_id,
+                sequence: 1,
+                acknowledged_at: date(2023, 9, 20),
+            }),
+        ];
+        let reminder = Reminder::from_events(&events).unwrap();
+        assert_eq!(reminder.trigger_at, date(2023, 9, 22));
+        assert_eq!(reminder.occurrence, 3);
+        assert!(!reminder.is_due(date(2023, 9, 21)));
+    }
+
+    #[test]
+    fn given_snoozedreminder_when_beforesnoozeend_then_notdue() {
+        let reminder_id = Uuid::new_v4();
+        let events = vec![
+            ReminderEvent::Created(ReminderCreatedEvent {
+                event_id: Uuid::new_v4(),
+                reminder_id,
+                sequence: 0,
+                title: String::from("Remind change of music class to participants"),
+                trigger_at: date(2023, 9, 1),
+                recurrence: None,
+            }),
+            ReminderEvent::Snoozed(ReminderSnoozedEvent {
+                event_id: Uuid::new_v4(),
+                reminder_id,
+                sequence: 1,
+                until: date(2023, 9, 3),
+            }),
+        ];
+        let reminder = Reminder::from_events(&events).unwrap();
+        assert!(!reminder.is_due(date(2023, 9, 2)));
+        assert!(reminder.is_due(date(2023, 9, 3)));
+    }
+}
@@ -0,0 +1,69 @@
+use crate::recurrence::Recurrence;
+use chrono::NaiveDateTime;
+use uuid::Uuid;
+
+pub type ReminderId = Uuid;
+type ReminderEventId = Uuid;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ReminderCreatedEvent {
+    pub title: String,
+    pub reminder_id: ReminderId,
+    pub event_id: ReminderEventId,
+    pub sequence: i32,
+    pub trigger_at: NaiveDateTime,
+    pub recurrence: Option<Recurrence>,
+}
+
+// The current notification was seen, a recurring reminder moves on to its first trigger time
+// after acknowledged_at
+#[derive(Debug, PartialEq, Clone)]
+pub struct ReminderAcknowledgedEvent {
+    pub reminder_id: ReminderId,
+    pub event_id: ReminderEventId,
+    pub sequence: i32,
+    pub acknowledged_at: NaiveDateTime,
+}
+
+// Puts off the current notification until the given time
+#[derive(Debug, PartialEq, Clone)]
+pub struct ReminderSnoozedEvent {
+    pub reminder_id: ReminderId,
+    pub event_id: ReminderEventId,
+    pub sequence: i32,
+    pub until: NaiveDateTime,
+}
+
+// Everything that can happen to a reminder during its lifetime, in the order it is stored
+#[derive(Debug, PartialEq, Clone)]
+pub enum ReminderEvent {
+    Created(ReminderCreatedEvent),
+    Acknowledged(ReminderAcknowledgedEvent),
+    Snoozed(ReminderSnoozedEvent),
+}
+
+impl ReminderEvent {
+    pub fn reminder_id(&self) -> ReminderId {
+        match self {
+            ReminderEvent::Created(event) => event.reminder_id,
+            ReminderEvent::Acknowledged(event) => event.reminder_id,
+            ReminderEvent::Snoozed(event) => event.reminder_id,
+        }
+    }
+
+    pub fn event_id(&self) -> ReminderEventId {
+        match self {
+            ReminderEvent::Created(event) => event.event_id,
+            ReminderEvent::Acknowledged(event) => event.event_id,
+            ReminderEvent::Snoozed(event) => event.event_id,
+        }
+    }
+
+    pub fn sequence(&self) -> i32 {
+        match self {
+            ReminderEvent::Created(event) => event.sequence,
+            ReminderEvent::Acknowledged(event) => event.sequence,
+            ReminderEvent::Snoozed(event) => event.sequence,
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use crate::errors::StoryError;
+use crate::story_events::{
+    StoryEvent, StoryFinishedEvent, StoryId, StoryMarkedReadyEvent, StoryRefinedEvent,
+    StoryStartedEvent,
+};
+use std::fmt;
+use uuid::Uuid;
+
+// Where a story is in its refinement workflow, a story only ever moves one status forward
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum StoryStatus {
+    Draft,
+    Refined,
+    Ready,
+    InProgress,
+    Done,
+}
+
+impl fmt::Display for StoryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            StoryStatus::Draft => "draft",
+            StoryStatus::Refined => "refined",
+            StoryStatus::Ready => "ready",
+            StoryStatus::InProgress => "in progress",
+            StoryStatus::Done => "done",
+        };
+        write!(f, "{}", status)
+    }
+}
+
+// A requested move to the next status, with the details the move records
+#[derive(Debug, PartialEq, Clone)]
+pub enum StoryTransition {
+    Refine {
+        description: String,
+        acceptance_notes: String,
+        estimate: Option<u32>,
+    },
+    MarkReady,
+    Start,
+    Finish,
+}
+
+impl StoryTransition {
+    pub fn from_status(&self) -> StoryStatus {
+        match self {
+            StoryTransition::Refine { .. } => StoryStatus::Draft,
+            StoryTransition::MarkReady => StoryStatus::Refined,
+            StoryTransition::Start => StoryStatus::Ready,
+            StoryTransition::Finish => StoryStatus::InProgress,
+        }
+    }
+
+    pub fn to_status(&self) -> StoryStatus {
+        match self {
+            StoryTransition::Refine { .. } => StoryStatus::Refined,
+            StoryTransition::MarkReady => StoryStatus::Ready,
+            StoryTransition::Start => StoryStatus::InProgress,
+            StoryTransition::Finish => StoryStatus::Done,
+        }
+    }
+}
+
+// Current state of a story, rebuilt by folding its events
+#[derive(Debug, PartialEq, Clone)]
+pub struct Story {
+    pub story_id: StoryId,
+    pub title: String,
+    pub description: String,
+    pub acceptance_notes: String,
+    // In story points
+    pub estimate: Option<u32>,
+    pub status: StoryStatus,
+    // Number of events applied, the sequence of the next event to append
+    pub version: i32,
+}
+
+impl Story {
+    // Folds the events of a single story in sequence order. Returns None when the stream does not
+    // start with a Created event.
+    pub fn from_events(events: &[StoryEvent]) -> Option<Self> {
+        let mut ordered: Vec<&StoryEvent> = events.iter().collect();
+        ordered.sort_by_key(|event| event.sequence());
+        let mut ordered = ordered.into_iter();
+
+        let mut story = match ordered.next()? {
+            StoryEvent::Created(created) => Self {
+                story_id: created.story_id,
+                title: created.title.clone(),
+                description: created.description.clone(),
+                acceptance_notes: String::new(),
+                estimate: None,
+                status: StoryStatus::Draft,
+                version: 1,
+            },
+            _ => return None,
+        };
+        ordered.for_each(|event| story.apply(event));
+        Some(story)
+    }
+
+    pub fn apply(&mut self, event: &StoryEvent) {
+        match event {
+            StoryEvent::Created(_) => {}
+            StoryEvent::Refined(refined) => {
+                self.description = refined.description.clone();
+                self.acceptance_notes = refined.acceptance_notes.clone();
+                self.estimate = refined.estimate;
+                self.status = StoryStatus::Refined;
+            }
+            StoryEvent::MarkedReady(_) => self.status = StoryStatus::Ready,
+            StoryEvent::Started(_) => self.status = StoryStatus::InProgress,
+            StoryEvent::Finished(_) => self.status = StoryStatus::Done,
+        }
+        self.version += 1;
+    }
+
+    // The event that records the transition, to be appended at the current version. Fails with
+    // IllegalTransition when the story is not in the status the transition starts from.
+    pub fn transition(
+        &self,
+        transition: StoryTransition,
+        event_id: Uuid,
+    ) -> Result<StoryEvent, StoryError> {
+        if self.status != transition.from_status() {
+            return Err(StoryError::IllegalTransition {
+                story_id: self.story_id.to_string(),
+                from: self.status,
+                to: transition.to_status(),
+            });
+        }
+        let story_id = self.story_id;
+        let sequence = self.version;
+        Ok(match transition {
+            StoryTransition::Refine {
+                description,
+                acceptance_notes,
+                estimate,
+            } => StoryEvent::Refined(StoryRefinedEvent {
+                story_id,
+                event_id,
+                sequence,
+                description,
+                acceptance_notes,
+                estimate,
+            }),
+            StoryTransition::MarkReady => StoryEvent::MarkedReady(StoryMarkedReadyEvent {
+                story_id,
+                event_id,
+                sequence,
+            }),
+            StoryTransition::Start => StoryEvent::Started(StoryStartedEvent {
+                story_id,
+                event_id,
+                sequence,
+            }),
+            StoryTransition::Finish => StoryEvent::Finished(StoryFinishedEvent {
+                story_id,
+                event_id,
+                sequence,
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Story, StoryStatus, StoryTransition};
+    use crate::errors::StoryError;
+    use crate::story_events::{StoryCreatedEvent, StoryEvent};
+    use uuid::Uuid;
+
+    fn draft() -> Story {
+        Story::from_events(&[StoryEvent::Created(StoryCreatedEvent {
+            event_id: Uuid::new_v4(),
+            story_id: Uuid::new_v4(),
+            sequence: 0,
+            title: String::from("Refactor code to use clean architecture"),
+            description: String::from("Split the app into layers"),
+        })])
+        .unwrap()
+    }
+
+    #[test]
+    fn given_draft_when_walkedthroughworkflow_then_done() {
+        let mut story = draft();
+        let transitions = vec![
+            StoryTransition::Refine {
+                description: String::from("Split the app into entities, usecases and others"),
+                acceptance_notes: String::from("No usecase depends on diesel"),
+                estimate: Some(8),
+            },
+            StoryTransition::MarkReady,
+            StoryTransition::Start,
+            StoryTransition::Finish,
+        ];
+        for transition in transitions {
+            let event = story.transition(transition, Uuid::new_v4()).unwrap();
+            assert_eq!(event.sequence(), story.version);
+            story.apply(&event);
+        }
+        assert_eq!(story.status, StoryStatus::Done);
+        assert_eq!(story.estimate, Some(8));
+        assert_eq!(story.version, 5);
+    }
+
+    #[test]
+    fn given_draft_when_started_then_illegaltransition() {
+        let story = draft();
+        let result = story.transition(StoryTransition::Start, Uuid::new_v4());
+        assert_eq!(
+            result,
+            Err(StoryError::IllegalTransition {
+                story_id: story.story_id.to_string(),
+                from: StoryStatus::Draft,
+                to: StoryStatus::InProgress,
+            })
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use uuid::Uuid;
+
+pub type StoryId = Uuid;
+type StoryEventId = Uuid;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct StoryCreatedEvent {
+    pub title: String,
+    pub story_id: StoryId,
+    pub event_id: StoryEventId,
+    pub sequence: i32,
+    pub description: String,
+}
+
+// The draft was worked out, replaces description, acceptance notes and estimate
+#[derive(Debug, PartialEq, Clone)]
+pub struct StoryRefinedEvent {
+    pub story_id: StoryId,
+    pub event_id: StoryEventId,
+    pub sequence: i32,
+    pub description: String,
+    pub acceptance_notes: String,
+    // In story points
+    pub estimate: Option<u32>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct StoryMarkedReadyEvent {
+    pub story_id: StoryId,
+    pub event_id: StoryEventId,
+    pub sequence: i32,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct StoryStartedEvent {
+    pub story_id: StoryId,
+    pub event_id: StoryEventId,
+    pub sequence: i32,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct StoryFinishedEvent {
+    pub story_id: StoryId,
+    pub event_id: StoryEventId,
+    pub sequence: i32,
+}
+
+// Everything that can happen to a story during its lifetime, in the order it is stored
+#[derive(Debug, PartialEq, Clone)]
+pub enum StoryEvent {
+    Created(StoryCreatedEvent),
+    Refined(StoryRefinedEvent),
+    MarkedReady(StoryMarkedReadyEvent),
+    Started(StoryStartedEvent),
+    Finished(StoryFinishedEvent),
+}
+
+impl StoryEvent {
+    pub fn story_id(&self) -> StoryId {
+        match self {
+            StoryEvent::Created(event) => event.story_id,
+            StoryEvent::Refined(event) => event.story_id,
+            StoryEvent::MarkedReady(event) => event.story_id,
+            StoryEvent::Started(event) => event.story_id,
+            StoryEvent::Finished(event) => event.story_id,
+        }
+    }
+
+    pub fn event_id(&self) -> StoryEventId {
+        match self {
+            StoryEvent::Created(event) => event.event_id,
+            StoryEvent::Refined(event) => event.event_id,
+            StoryEvent::MarkedReady(event) => event.event_id,
+            StoryEvent::Started(event) => event.event_id,
+            StoryEvent::Finished(event) => event.event_id,
+        }
+    }
+
+    pub fn sequence(&self) -> i32 {
+        match self {
+            StoryEvent::Created(event) => event.sequence,
+            StoryEvent::Refined(event) => event.sequence,
+            StoryEvent::MarkedReady(event) => event.sequence,
+            StoryEvent::Started(event) => event.sequence,
+            StoryEvent::Finished(event) => event.sequence,
+        }
+    }
+}
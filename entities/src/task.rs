@@ -0,0 +1,57 @@
+use crate::plannable::PlannableRef;
+use crate::task_events::{TaskEvent, TaskId};
+use chrono::NaiveDateTime;
+
+// Current state of a one-of task, rebuilt by folding its events
+#[derive(Debug, PartialEq, Clone)]
+pub struct Task {
+    pub task_id: TaskId,
+    pub title: String,
+    pub end_date: Option<NaiveDateTime>,
+    pub converted_from: Option<PlannableRef>,
+    pub converted_into: Option<PlannableRef>,
+    pub expired: bool,
+    // Number of events applied, the sequence of the next event to append
+    pub version: i32,
+}
+
+impl Task {
+    // Folds the events of a single task in sequence order. Returns None when the stream does not
+    // start with a Created event.
+    pub fn from_events(events: &[TaskEvent]) -> Option<Self> {
+        let mut ordered: Vec<&TaskEvent> = events.iter().collect();
+        ordered.sort_by_key(|event| event.sequence());
+        let mut ordered = ordered.into_iter();
+
+        let mut task = match ordered.next()? {
+            TaskEvent::Created(created) => Self {
+                task_id: created.task_id,
+                title: created.title.clone(),
+                end_date: created.end_date,
+                converted_from: created.converted_from,
+                converted_into: None,
+                expired: false,
+                version: 1,
+            },
+            _ => return None,
+        };
+        ordered.for_each(|event| task.apply(event));
+        Some(task)
+    }
+
+    pub fn apply(&mut self, event: &TaskEvent) {
+        match event {
+            TaskEvent::Created(_) => {}
+            TaskEvent::Converted(converted) => self.converted_into = Some(converted.into),
+            TaskEvent::Expired(_) => self.expired = true,
+        }
+        self.version += 1;
+    }
+
+    // One-of tasks MUST expire, once their end date has passed they lapse
+    pub fn should_expire(&self, now: NaiveDateTime) -> bool {
+        !self.expired
+            && self.converted_into.is_none()
+            && self.end_date.is_some_and(|end_date| end_date <= now)
+    }
+}
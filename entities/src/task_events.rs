@@ -1,13 +1,67 @@
+use crate::plannable::PlannableRef;
 use chrono::NaiveDateTime;
 use uuid::Uuid;
 
-type TaskId = Uuid;
-type TaskCreatedEventId = Uuid;
+pub type TaskId = Uuid;
+type TaskEventId = Uuid;
 #[derive(Debug, PartialEq, Clone)]
 pub struct TaskCreatedEvent {
     pub title: String,
     pub task_id: TaskId,
-    pub event_id: TaskCreatedEventId,
+    pub event_id: TaskEventId,
     pub sequence: i32,
     pub end_date: Option<NaiveDateTime>,
+    // The plannable this task was converted from
+    pub converted_from: Option<PlannableRef>,
+}
+
+// Closes the task, its work continues as the plannable it was converted into
+#[derive(Debug, PartialEq, Clone)]
+pub struct TaskConvertedEvent {
+    pub task_id: TaskId,
+    pub event_id: TaskEventId,
+    pub sequence: i32,
+    pub into: PlannableRef,
+}
+
+// The end date passed before the task was done, it is over from then on
+#[derive(Debug, PartialEq, Clone)]
+pub struct TaskExpiredEvent {
+    pub task_id: TaskId,
+    pub event_id: TaskEventId,
+    pub sequence: i32,
+}
+
+// Everything that can happen to a task during its lifetime, in the order it is stored
+#[derive(Debug, PartialEq, Clone)]
+pub enum TaskEvent {
+    Created(TaskCreatedEvent),
+    Converted(TaskConvertedEvent),
+    Expired(TaskExpiredEvent),
+}
+
+impl TaskEvent {
+    pub fn task_id(&self) -> TaskId {
+        match self {
+            TaskEvent::Created(event) => event.task_id,
+            TaskEvent::Converted(event) => event.task_id,
+            TaskEvent::Expired(event) => event.task_id,
+        }
+    }
+
+    pub fn event_id(&self) -> TaskEventId {
+        match self {
+            TaskEvent::Created(event) => event.event_id,
+            TaskEvent::Converted(event) => event.event_id,
+            TaskEvent::Expired(event) => event.event_id,
+        }
+    }
+
+    pub fn sequence(&self) -> i32 {
+        match self {
+            TaskEvent::Created(event) => event.sequence,
+            TaskEvent::Converted(event) => event.sequence,
+            TaskEvent::Expired(event) => event.sequence,
+        }
+    }
 }
@@ -0,0 +1,116 @@
+use crate::plannable::PlannableRef;
+use crate::todo_events::{TodoEvent, TodoId};
+use chrono::NaiveDateTime;
+
+// Current state of a todo, rebuilt by folding its events
+#[derive(Debug, PartialEq, Clone)]
+pub struct Todo {
+    pub todo_id: TodoId,
+    pub title: String,
+    pub end_date: Option<NaiveDateTime>,
+    pub completed: bool,
+    pub removed: bool,
+    pub converted_from: Option<PlannableRef>,
+    // Set once the todo was converted into another plannable, it is closed from then on
+    pub converted_into: Option<PlannableRef>,
+    // Number of events applied, the sequence of the next event to append
+    pub version: i32,
+}
+
+impl Todo {
+    // Folds the events of a single todo in sequence order. Returns None when the stream does not
+    // start with a Created event.
+    pub fn from_events(events: &[TodoEvent]) -> Option<Self> {
+        let mut ordered: Vec<&TodoEvent> = events.iter().collect();
+        ordered.sort_by_key(|event| event.sequence());
+        let mut ordered = ordered.into_iter();
+
+        let mut todo = match ordered.next()? {
+            TodoEvent::Created(created) => Self {
+                todo_id: created.todo_id,
+                title: created.title.clone(),
+                end_date: created.end_date,
+                completed: false,
+                removed: false,
+                converted_from: created.converted_from,
+                converted_into: None,
+                version: 1,
+            },
+            _ => return None,
+        };
+        ordered.for_each(|event| todo.apply(event));
+        Some(todo)
+    }
+
+    pub fn apply(&mut self, event: &TodoEvent) {
+        match event {
+            TodoEvent::Created(_) => {}
+            TodoEvent::TitleChanged(changed) => self.title = changed.title.clone(),
+            TodoEvent::EndDateChanged(changed) => self.end_date = changed.end_date,
+            TodoEvent::Completed(_) => self.completed = true,
+            TodoEvent::Reopened(_) => self.completed = false,
+            TodoEvent::Removed(_) => self.removed = true,
+            TodoEvent::Converted(converted) => self.converted_into = Some(converted.into),
+        }
+        self.version += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Todo;
+    use crate::todo_events::{
+        TodoCompletedEvent, TodoCreatedEvent, TodoEvent, TodoTitleChangedEvent,
+    };
+    use uuid::Uuid;
+
+    #[test]
+    fn given_unorderedevents_when_fold_then_applyinsequenceorder() {
+        let todo_id = Uuid::new_v4();
+        let events = vec![
+            TodoEvent::Completed(TodoCompletedEvent {
+                event_id: Uuid::new_v4(),
+                todo_id,
+                sequence: 2,
+            }),
+            TodoEvent::TitleChanged(TodoTitleChangedEvent {
+                event_id: Uuid::new_v4(),
+                todo_id,
+                sequence: 1,
+                title: String::from("Read rust book"),
+            }),
+            TodoEvent::Created(TodoCreatedEvent {
+                event_id: Uuid::new_v4(),
+                todo_id,
+                sequence: 0,
+                title: String::from("Buy rust book"),
+                end_date: None,
+                converted_from: None,
+            }),
+        ];
+        let todo = Todo::from_events(&events).unwrap();
+        assert_eq!(
+            todo,
+            Todo {
+                todo_id,
+                title: String::from("Read rust book"),
+                end_date: None,
+                completed: true,
+                removed: false,
+                converted_from: None,
+                converted_into: None,
+                version: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn given_streamwithoutcreated_when_fold_then_none() {
+        let events = vec![TodoEvent::Completed(TodoCompletedEvent {
+            event_id: Uuid::new_v4(),
+            todo_id: Uuid::new_v4(),
+            sequence: 0,
+        })];
+        assert_eq!(Todo::from_events(&events), None);
+    }
+}
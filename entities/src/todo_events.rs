@@ -1,13 +1,112 @@
+use crate::plannable::PlannableRef;
 use chrono::NaiveDateTime;
 use uuid::Uuid;
 
-type TodoId = Uuid;
-type TodoCreatedEventId = Uuid;
+pub type TodoId = Uuid;
+type TodoEventId = Uuid;
 #[derive(Debug, PartialEq, Clone)]
 pub struct TodoCreatedEvent {
     pub title: String,
     pub todo_id: TodoId,
-    pub event_id: TodoCreatedEventId,
+    pub event_id: TodoEventId,
     pub sequence: i32,
     pub end_date: Option<NaiveDateTime>,
+    // The plannable this todo was converted from
+    pub converted_from: Option<PlannableRef>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct TodoTitleChangedEvent {
+    pub title: String,
+    pub todo_id: TodoId,
+    pub event_id: TodoEventId,
+    pub sequence: i32,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct TodoEndDateChangedEvent {
+    pub todo_id: TodoId,
+    pub event_id: TodoEventId,
+    pub sequence: i32,
+    pub end_date: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct TodoCompletedEvent {
+    pub todo_id: TodoId,
+    pub event_id: TodoEventId,
+    pub sequence: i32,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct TodoReopenedEvent {
+    pub todo_id: TodoId,
+    pub event_id: TodoEventId,
+    pub sequence: i32,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct TodoRemovedEvent {
+    pub todo_id: TodoId,
+    pub event_id: TodoEventId,
+    pub sequence: i32,
+}
+
+// Closes the todo, its wish continues as the plannable it was converted into
+#[derive(Debug, PartialEq, Clone)]
+pub struct TodoConvertedEvent {
+    pub todo_id: TodoId,
+    pub event_id: TodoEventId,
+    pub sequence: i32,
+    pub into: PlannableRef,
+}
+
+// Everything that can happen to a todo during its lifetime, in the order it is stored
+#[derive(Debug, PartialEq, Clone)]
+pub enum TodoEvent {
+    Created(TodoCreatedEvent),
+    TitleChanged(TodoTitleChangedEvent),
+    EndDateChanged(TodoEndDateChangedEvent),
+    Completed(TodoCompletedEvent),
+    Reopened(TodoReopenedEvent),
+    Removed(TodoRemovedEvent),
+    Converted(TodoConvertedEvent),
+}
+
+impl TodoEvent {
+    pub fn todo_id(&self) -> TodoId {
+        match self {
+            TodoEvent::Created(event) => event.todo_id,
+            TodoEvent::TitleChanged(event) => event.todo_id,
+            TodoEvent::EndDateChanged(event) => event.todo_id,
+            TodoEvent::Completed(event) => event.todo_id,
+            TodoEvent::Reopened(event) => event.todo_id,
+            TodoEvent::Removed(event) => event.todo_id,
+            TodoEvent::Converted(event) => event.todo_id,
+        }
+    }
+
+    pub fn event_id(&self) -> TodoEventId {
+        match self {
+            TodoEvent::Created(event) => event.event_id,
+            TodoEvent::TitleChanged(event) => event.event_id,
+            TodoEvent::EndDateChanged(event) => event.event_id,
+            TodoEvent::Completed(event) => event.event_id,
+            TodoEvent::Reopened(event) => event.event_id,
+            TodoEvent::Removed(event) => event.event_id,
+            TodoEvent::Converted(event) => event.event_id,
+        }
+    }
+
+    pub fn sequence(&self) -> i32 {
+        match self {
+            TodoEvent::Created(event) => event.sequence,
+            TodoEvent::TitleChanged(event) => event.sequence,
+            TodoEvent::EndDateChanged(event) => event.sequence,
+            TodoEvent::Completed(event) => event.sequence,
+            TodoEvent::Reopened(event) => event.sequence,
+            TodoEvent::Removed(event) => event.sequence,
+            TodoEvent::Converted(event) => event.sequence,
+        }
+    }
 }
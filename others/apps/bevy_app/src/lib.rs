@@ -1,14 +1,52 @@
+use entities::errors::PlannableError;
+
 pub fn add(left: usize, right: usize) -> usize {
     left + right
 }
 
+// The text lines shown when a plannable could not be stored: a heading and one line per broken rule
+pub fn error_lines(error: &PlannableError) -> Vec<String> {
+    match error {
+        PlannableError::Invalid(invalid) => std::iter::once(format!("Invalid {}", invalid.kind))
+            .chain(
+                invalid
+                    .violations
+                    .iter()
+                    .map(|violation| format!("- {}", violation)),
+            )
+            .collect(),
+        PlannableError::EventStore(error) => vec![error.to_string()],
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use entities::errors::{ValidationError, Violation};
+    use entities::plannable::PlannableKind;
 
     #[test]
     fn it_works() {
         let result = add(2, 2);
         assert_eq!(result, 4);
     }
+
+    #[test]
+    fn given_invalidchore_when_errorlines_then_oneperviolation() {
+        let error = PlannableError::Invalid(ValidationError {
+            kind: PlannableKind::Chore,
+            violations: vec![
+                Violation::Empty { field: "title" },
+                Violation::ZeroRecurrence,
+            ],
+        });
+        assert_eq!(
+            error_lines(&error),
+            vec![
+                "Invalid chore",
+                "- the title is empty",
+                "- a recurrence has to repeat at least every 1",
+            ]
+        );
+    }
 }
@@ -1,7 +1,24 @@
 use anyhow::Result;
-use chrono::NaiveDateTime;
+use chrono::{Datelike, Duration, IsoWeek, NaiveDate, NaiveDateTime, Weekday};
 use clap::{Parser, Subcommand};
-use entities::todo_events::TodoCreatedEvent;
+use entities::agenda_item::AgendaItem;
+use entities::agenda_item_events::{AgendaItemEnd, AgendaRecurrence};
+use entities::chore::{Chore, ChoreStatus};
+use entities::errors::{
+    AgendaItemError, ChoreError, ConversionError, EventStoreError, IdError, PlannableError,
+    ReminderError, TodoError, ViewError,
+};
+use entities::issue_events::Severity;
+use entities::plannable::{PlannableKind, PlannableRef};
+use entities::recurrence::Recurrence;
+use entities::reminder::Reminder;
+use entities::todo::Todo;
+use usecases::get_expiry_report_usecase::ExpiryReport;
+use usecases::next_hours_view_usecase::TimelineEntry;
+use usecases::short_ids::ShortIds;
+use usecases::view::ViewItem;
+use usecases::week_view_usecase::WeekDay;
+use uuid::Uuid;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -14,6 +31,38 @@ struct Cli {
 #[derive(Subcommand, Debug)]
 enum Command {
     Todo(TodoCli),
+    Chore(ChoreCli),
+    Agenda(AgendaCli),
+    Reminder(ReminderCli),
+    Issue(IssueCli),
+    Impediment(ImpedimentCli),
+    /// Everything that needs attention today
+    Today,
+    /// Everything coming up in a window from now, like 3h, 90m or 2d
+    Next {
+        #[arg(value_parser = parse_window)]
+        window: Duration,
+    },
+    /// The ISO week like 2023-W39 as a table of seven days, the current week by default
+    Week {
+        #[arg(value_parser = parse_week)]
+        week: Option<IsoWeek>,
+    },
+    /// Tasks, chores and impediments past their end date or ending within the horizon
+    Report {
+        #[arg(long, default_value = "1d", value_parser = parse_window)]
+        horizon: Duration,
+    },
+    /// Records the lapse of every task, chore and impediment past its end date
+    Expire,
+    /// Closes a todo, task or chore and opens one of another of these kinds with its title and date
+    Convert {
+        #[arg(value_parser = parse_convertible_kind)]
+        kind: PlannableKind,
+        id: String,
+        #[arg(value_parser = parse_convertible_kind)]
+        into: PlannableKind,
+    },
 }
 
 #[derive(Parser, Debug)]
@@ -30,19 +79,457 @@ enum TodoCommand {
         end_date: Option<NaiveDateTime>,
     },
     Get,
+    /// Marks a todo as done, the id can be any prefix that only one todo id starts with
+    Done {
+        id: String,
+    },
+    Reopen {
+        id: String,
+    },
+    Rm {
+        id: String,
+    },
+    /// Changes only what is given, at least one of title and end date
+    Edit {
+        id: String,
+        #[arg(long, required_unless_present = "end_date")]
+        title: Option<String>,
+        #[arg(long, value_parser = parse_duration)]
+        end_date: Option<NaiveDateTime>,
+    },
+}
+
+#[derive(Parser, Debug)]
+struct ChoreCli {
+    #[command(subcommand)]
+    command: ChoreCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum ChoreCommand {
+    Add {
+        title: String,
+        #[arg(value_parser = parse_duration)]
+        due_date: Option<NaiveDateTime>,
+        /// daily, weekly, monthly, yearly or a count with a unit like 3d, 2w, 6m or 1y
+        #[arg(long, value_parser = parse_recurrence, requires = "due_date")]
+        every: Option<Recurrence>,
+    },
+    Get,
+    /// Moves the current occurrence to a new due date, a recurring chore repeats from there
+    Reschedule {
+        id: String,
+        #[arg(value_parser = parse_duration)]
+        due_date: NaiveDateTime,
+    },
+}
+
+#[derive(Parser, Debug)]
+struct AgendaCli {
+    #[command(subcommand)]
+    command: AgendaCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum AgendaCommand {
+    /// An appointment from start, ending at a date with --end or after a length like 90m with --for
+    Add {
+        title: String,
+        #[arg(value_parser = parse_duration)]
+        start: NaiveDateTime,
+        #[arg(long, value_parser = parse_duration, conflicts_with = "length")]
+        end: Option<NaiveDateTime>,
+        #[arg(long = "for", value_parser = parse_window)]
+        length: Option<Duration>,
+        #[arg(long)]
+        location: Option<String>,
+        /// daily, weekly, monthly, yearly or a count with a unit like 3d, 2w, 6m or 1y
+        #[arg(long, value_parser = parse_recurrence)]
+        every: Option<Recurrence>,
+        /// The last date a recurring agenda item may start on
+        #[arg(long, value_parser = parse_duration, requires = "every")]
+        until: Option<NaiveDateTime>,
+    },
+    /// Moves the agenda item to a new start, without --end or --for it has no end any more
+    Reschedule {
+        id: String,
+        #[arg(value_parser = parse_duration)]
+        start: NaiveDateTime,
+        #[arg(long, value_parser = parse_duration, conflicts_with = "length")]
+        end: Option<NaiveDateTime>,
+        #[arg(long = "for", value_parser = parse_window)]
+        length: Option<Duration>,
+    },
+}
+
+#[derive(Parser, Debug)]
+struct ReminderCli {
+    #[command(subcommand)]
+    command: ReminderCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum ReminderCommand {
+    Add {
+        title: String,
+        #[arg(value_parser = parse_duration)]
+        trigger_at: NaiveDateTime,
+        /// daily, weekly, monthly, yearly or a count with a unit like 3d, 2w, 6m or 1y
+        #[arg(long, value_parser = parse_recurrence)]
+        every: Option<Recurrence>,
+    },
+    /// Marks the current notification as seen, a recurring reminder moves on to its next one
+    Ack { id: String },
+    /// Puts off the current notification until the given date
+    Snooze {
+        id: String,
+        #[arg(value_parser = parse_duration)]
+        until: NaiveDateTime,
+    },
+}
+
+#[derive(Parser, Debug)]
+struct IssueCli {
+    #[command(subcommand)]
+    command: IssueCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum IssueCommand {
+    Raise {
+        title: String,
+        /// low, medium, high or critical
+        #[arg(long, default_value = "medium", value_parser = parse_severity)]
+        severity: Severity,
+    },
+}
+
+#[derive(Parser, Debug)]
+struct ImpedimentCli {
+    #[command(subcommand)]
+    command: ImpedimentCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum ImpedimentCommand {
+    Raise {
+        title: String,
+        #[arg(value_parser = parse_duration)]
+        expires_at: NaiveDateTime,
+        /// A blocked todo, task, chore or story like task:3f2a, may be repeated
+        #[arg(long, value_parser = parse_blocked)]
+        blocks: Vec<(PlannableKind, String)>,
+    },
 }
 
 fn parse_duration(date: &str) -> Result<NaiveDateTime, chrono::ParseError> {
-    let parse_from_str = NaiveDateTime::parse_from_str;
-    let end_date = parse_from_str(date, "%Y-%m-%d %H:%M:%S");
-    end_date
+    NaiveDateTime::parse_from_str(date, "%Y-%m-%d %H:%M:%S")
+}
+
+fn parse_recurrence(recurrence: &str) -> Result<Recurrence, String> {
+    match recurrence {
+        "daily" => return Ok(Recurrence::Days(1)),
+        "weekly" => return Ok(Recurrence::Weeks(1)),
+        "monthly" => return Ok(Recurrence::Months(1)),
+        "yearly" => return Ok(Recurrence::Years(1)),
+        _ => {}
+    }
+    let invalid = || format!("invalid recurrence '{}'", recurrence);
+    let unit_start = recurrence.len().checked_sub(1).ok_or_else(invalid)?;
+    if !recurrence.is_char_boundary(unit_start) {
+        return Err(invalid());
+    }
+    let (count, unit) = recurrence.split_at(unit_start);
+    let count: u32 = count.parse().map_err(|_| invalid())?;
+    if count == 0 {
+        return Err(invalid());
+    }
+    match unit {
+        "d" => Ok(Recurrence::Days(count)),
+        "w" => Ok(Recurrence::Weeks(count)),
+        "m" => Ok(Recurrence::Months(count)),
+        "y" => Ok(Recurrence::Years(count)),
+        _ => Err(invalid()),
+    }
+}
+
+// Longest window accepted, so the minutes always fit a Duration
+const MAX_WINDOW_DAYS: i64 = 100 * 366;
+
+fn parse_window(window: &str) -> Result<Duration, String> {
+    let invalid = || format!("invalid window '{}'", window);
+    let unit_start = window.len().checked_sub(1).ok_or_else(invalid)?;
+    if !window.is_char_boundary(unit_start) {
+        return Err(invalid());
+    }
+    let (count, unit) = window.split_at(unit_start);
+    let count: i64 = count.parse().map_err(|_| invalid())?;
+    if count <= 0 {
+        return Err(invalid());
+    }
+    let minutes_per_unit = match unit {
+        "m" => 1,
+        "h" => 60,
+        "d" => 24 * 60,
+        _ => return Err(invalid()),
+    };
+    match count.checked_mul(minutes_per_unit) {
+        Some(minutes) if minutes <= MAX_WINDOW_DAYS * 24 * 60 => Ok(Duration::minutes(minutes)),
+        _ => Err(format!("window '{}' is longer than 100 years", window)),
+    }
+}
+
+fn parse_convertible_kind(kind: &str) -> Result<PlannableKind, String> {
+    match kind {
+        "todo" => Ok(PlannableKind::Todo),
+        "task" => Ok(PlannableKind::Task),
+        "chore" => Ok(PlannableKind::Chore),
+        _ => Err(format!(
+            "invalid kind '{}', expected todo, task or chore",
+            kind
+        )),
+    }
+}
+
+fn parse_severity(severity: &str) -> Result<Severity, String> {
+    match severity {
+        "low" => Ok(Severity::Low),
+        "medium" => Ok(Severity::Medium),
+        "high" => Ok(Severity::High),
+        "critical" => Ok(Severity::Critical),
+        _ => Err(format!(
+            "invalid severity '{}', expected low, medium, high or critical",
+            severity
+        )),
+    }
+}
+
+// Only the kinds an impediment can block, the id is resolved like any other id prefix
+fn parse_blocked(blocked: &str) -> Result<(PlannableKind, String), String> {
+    let invalid = || {
+        format!(
+            "invalid plannable '{}', expected todo, task, chore or story and an id like task:3f2a",
+            blocked
+        )
+    };
+    let (kind, id) = blocked.split_once(':').ok_or_else(invalid)?;
+    let kind = match kind {
+        "todo" => PlannableKind::Todo,
+        "task" => PlannableKind::Task,
+        "chore" => PlannableKind::Chore,
+        "story" => PlannableKind::Story,
+        _ => return Err(invalid()),
+    };
+    Ok((kind, id.to_string()))
+}
+
+fn plannable_ref(kind: PlannableKind, id: Uuid) -> PlannableRef {
+    match kind {
+        PlannableKind::Todo => PlannableRef::Todo(id),
+        PlannableKind::Task => PlannableRef::Task(id),
+        PlannableKind::Chore => PlannableRef::Chore(id),
+        PlannableKind::Story => PlannableRef::Story(id),
+        _ => unreachable!("only kinds that can be referenced are parsed"),
+    }
+}
+
+fn agenda_item_end(end: Option<NaiveDateTime>, length: Option<Duration>) -> Option<AgendaItemEnd> {
+    end.map(AgendaItemEnd::At)
+        .or(length.map(AgendaItemEnd::After))
 }
 
-pub struct CliApp<'a, I: AddTodoUseCaseInvoker + GetTodoUseCaseInvoker> {
+fn parse_week(week: &str) -> Result<IsoWeek, String> {
+    let invalid = || format!("invalid week '{}'", week);
+    let (year, number) = week.split_once("-W").ok_or_else(invalid)?;
+    let year: i32 = year.parse().map_err(|_| invalid())?;
+    let number: u32 = number.parse().map_err(|_| invalid())?;
+    NaiveDate::from_isoywd_opt(year, number, Weekday::Mon)
+        .map(|monday| monday.iso_week())
+        .ok_or_else(invalid)
+}
+
+fn format_recurrence(recurrence: &Recurrence) -> String {
+    match recurrence {
+        Recurrence::Days(days) => format!("{}d", days),
+        Recurrence::Weeks(weeks) => format!("{}w", weeks),
+        Recurrence::Months(months) => format!("{}m", months),
+        Recurrence::Years(years) => format!("{}y", years),
+    }
+}
+
+fn format_chore(chore: &Chore, short_ids: &ShortIds) -> String {
+    let status = match chore.status {
+        ChoreStatus::Open => " ",
+        ChoreStatus::Done => "x",
+        ChoreStatus::Skipped => "-",
+        ChoreStatus::Expired => "!",
+    };
+    let chore_id = short_ids.short(chore.chore_id);
+    let mut line = format!("{} [{}] {}", chore_id, status, chore.title);
+    if let Some(due_date) = chore.due_date {
+        line.push_str(&format!(" (due {})", due_date.format("%Y-%m-%d %H:%M:%S")));
+    }
+    if let Some(recurrence) = &chore.recurrence {
+        line.push_str(&format!(" every {}", format_recurrence(recurrence)));
+    }
+    line
+}
+
+fn format_agenda_item(agenda_item: &AgendaItem, short_ids: &ShortIds) -> String {
+    let agenda_item_id = short_ids.short(agenda_item.agenda_item_id);
+    let mut line = format!(
+        "{} {} {}",
+        agenda_item_id,
+        agenda_item.start.format("%Y-%m-%d %H:%M"),
+        agenda_item.title
+    );
+    if let Some(duration) = agenda_item.duration() {
+        line.push_str(&format!(" for {}", format_span(&duration)));
+    }
+    line
+}
+
+fn format_reminder(reminder: &Reminder, short_ids: &ShortIds) -> String {
+    let reminder_id = short_ids.short(reminder.reminder_id);
+    match reminder.notify_at() {
+        Some(notify_at) => format!(
+            "{} {} (next {})",
+            reminder_id,
+            reminder.title,
+            notify_at.format("%Y-%m-%d %H:%M:%S")
+        ),
+        None => format!("{} {} (acknowledged)", reminder_id, reminder.title),
+    }
+}
+
+fn format_todo(todo: &Todo, short_ids: &ShortIds) -> String {
+    let status = if todo.completed { "x" } else { " " };
+    let todo_id = short_ids.short(todo.todo_id);
+    match todo.end_date {
+        Some(end_date) => format!(
+            "{} [{}] {} (until {})",
+            todo_id,
+            status,
+            todo.title,
+            end_date.format("%Y-%m-%d %H:%M:%S")
+        ),
+        None => format!("{} [{}] {}", todo_id, status, todo.title),
+    }
+}
+
+fn format_view_item(item: &ViewItem, short_ids: &ShortIds) -> String {
+    let mut line = item.at.format("%Y-%m-%d %H:%M").to_string();
+    if let Some(until) = item.until {
+        line.push_str(&format!(" - {}", until.format("%H:%M")));
+    }
+    format!("{} {} ({})", line, item.title, short_ids.short(item.id))
+}
+
+// Largest units first like 2d 3h or 45m, seconds are dropped
+fn format_span(span: &Duration) -> String {
+    let parts: Vec<String> = [
+        (span.num_days(), "d"),
+        (span.num_hours() % 24, "h"),
+        (span.num_minutes() % 60, "m"),
+    ]
+    .iter()
+    .filter(|(count, _)| *count > 0)
+    .map(|(count, unit)| format!("{}{}", count, unit))
+    .collect();
+    match parts.is_empty() {
+        true => String::from("0m"),
+        false => parts.join(" "),
+    }
+}
+
+const WEEK_COLUMN_WIDTH: usize = 20;
+
+// Seven columns from Monday to Sunday with an item per row, titles are cut to fit the column
+fn format_week(days: &[WeekDay]) -> String {
+    let cell = |text: String| {
+        let text: String = text.chars().take(WEEK_COLUMN_WIDTH - 1).collect();
+        format!("{:<width$}", text, width = WEEK_COLUMN_WIDTH)
+    };
+    let mut lines = vec![days
+        .iter()
+        .map(|day| cell(day.date.format("%a %m-%d").to_string()))
+        .collect::<String>()];
+    let rows = days.iter().map(|day| day.items.len()).max().unwrap_or(0);
+    for row in 0..rows {
+        lines.push(
+            days.iter()
+                .map(|day| match day.items.get(row) {
+                    Some(item) => cell(format!("{} {}", item.at.format("%H:%M"), item.title)),
+                    None => cell(String::new()),
+                })
+                .collect(),
+        );
+    }
+    lines
+        .iter()
+        .map(|line| line.trim_end())
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
+pub struct CliApp<
+    'a,
+    I: AddTodoUseCaseInvoker
+        + GetTodoUseCaseInvoker
+        + CompleteTodoUseCaseInvoker
+        + ReopenTodoUseCaseInvoker
+        + RemoveTodoUseCaseInvoker
+        + EditTodoUseCaseInvoker
+        + AddChoreUseCaseInvoker
+        + GetChoreUseCaseInvoker
+        + RescheduleChoreUseCaseInvoker
+        + AddAgendaItemUseCaseInvoker
+        + RescheduleAgendaItemUseCaseInvoker
+        + AddReminderUseCaseInvoker
+        + AcknowledgeReminderUseCaseInvoker
+        + SnoozeReminderUseCaseInvoker
+        + RaiseIssueUseCaseInvoker
+        + RaiseImpedimentUseCaseInvoker
+        + TodayViewUseCaseInvoker
+        + NextHoursViewUseCaseInvoker
+        + WeekViewUseCaseInvoker
+        + ExpiryReportUseCaseInvoker
+        + ExpirePlannablesUseCaseInvoker
+        + ConvertPlannableUseCaseInvoker
+        + ResolveIdUseCaseInvoker
+        + GetShortIdsUseCaseInvoker,
+> {
     usecase_invoker: &'a mut I,
 }
 
-impl<'a, I: AddTodoUseCaseInvoker + GetTodoUseCaseInvoker> CliApp<'a, I> {
+impl<
+        'a,
+        I: AddTodoUseCaseInvoker
+            + GetTodoUseCaseInvoker
+            + CompleteTodoUseCaseInvoker
+            + ReopenTodoUseCaseInvoker
+            + RemoveTodoUseCaseInvoker
+            + EditTodoUseCaseInvoker
+            + AddChoreUseCaseInvoker
+            + GetChoreUseCaseInvoker
+            + RescheduleChoreUseCaseInvoker
+            + AddAgendaItemUseCaseInvoker
+            + RescheduleAgendaItemUseCaseInvoker
+            + AddReminderUseCaseInvoker
+            + AcknowledgeReminderUseCaseInvoker
+            + SnoozeReminderUseCaseInvoker
+            + RaiseIssueUseCaseInvoker
+            + RaiseImpedimentUseCaseInvoker
+            + TodayViewUseCaseInvoker
+            + NextHoursViewUseCaseInvoker
+            + WeekViewUseCaseInvoker
+            + ExpiryReportUseCaseInvoker
+            + ExpirePlannablesUseCaseInvoker
+            + ConvertPlannableUseCaseInvoker
+            + ResolveIdUseCaseInvoker
+            + GetShortIdsUseCaseInvoker,
+    > CliApp<'a, I>
+{
     pub fn new(usecase_invoker: &'a mut I) -> Self {
         Self { usecase_invoker }
     }
@@ -53,25 +540,525 @@ impl<'a, I: AddTodoUseCaseInvoker + GetTodoUseCaseInvoker> CliApp<'a, I> {
         match &cli.command {
             Command::Todo(TodoCli { command }) => match command {
                 TodoCommand::Add { title, end_date } => {
-                    println!("{:?}", title);
-                    println!("{:?}", end_date);
                     self.usecase_invoker
-                        .invoke_add_todo_usecase(title.to_string(), *end_date);
+                        .invoke_add_todo_usecase(title.to_string(), *end_date)?;
                 }
                 TodoCommand::Get => {
-                    let result = self.usecase_invoker.invoke_get_todo_usecase();
-                    println!("{:?}", result);
+                    let todos = self.usecase_invoker.invoke_get_todo_usecase()?;
+                    let short_ids = self.usecase_invoker.invoke_get_short_ids_usecase()?;
+                    todos
+                        .iter()
+                        .for_each(|todo| println!("{}", format_todo(todo, &short_ids)));
+                }
+                TodoCommand::Done { id } => {
+                    let todo_id = self
+                        .usecase_invoker
+                        .invoke_resolve_id_usecase(PlannableKind::Todo, id)?;
+                    let todo = self.usecase_invoker.invoke_complete_todo_usecase(todo_id)?;
+                    let short_ids = self.usecase_invoker.invoke_get_short_ids_usecase()?;
+                    println!("{}", format_todo(&todo, &short_ids));
+                }
+                TodoCommand::Reopen { id } => {
+                    let todo_id = self
+                        .usecase_invoker
+                        .invoke_resolve_id_usecase(PlannableKind::Todo, id)?;
+                    let todo = self.usecase_invoker.invoke_reopen_todo_usecase(todo_id)?;
+                    let short_ids = self.usecase_invoker.invoke_get_short_ids_usecase()?;
+                    println!("{}", format_todo(&todo, &short_ids));
+                }
+                TodoCommand::Rm { id } => {
+                    let todo_id = self
+                        .usecase_invoker
+                        .invoke_resolve_id_usecase(PlannableKind::Todo, id)?;
+                    let todo = self.usecase_invoker.invoke_remove_todo_usecase(todo_id)?;
+                    let short_ids = self.usecase_invoker.invoke_get_short_ids_usecase()?;
+                    println!("removed {}", format_todo(&todo, &short_ids));
+                }
+                TodoCommand::Edit {
+                    id,
+                    title,
+                    end_date,
+                } => {
+                    let todo_id = self
+                        .usecase_invoker
+                        .invoke_resolve_id_usecase(PlannableKind::Todo, id)?;
+                    let todo = self.usecase_invoker.invoke_edit_todo_usecase(
+                        todo_id,
+                        title.clone(),
+                        *end_date,
+                    )?;
+                    let short_ids = self.usecase_invoker.invoke_get_short_ids_usecase()?;
+                    println!("{}", format_todo(&todo, &short_ids));
+                }
+            },
+            Command::Chore(ChoreCli { command }) => match command {
+                ChoreCommand::Add {
+                    title,
+                    due_date,
+                    every,
+                } => {
+                    self.usecase_invoker.invoke_add_chore_usecase(
+                        title.to_string(),
+                        *due_date,
+                        *every,
+                    )?;
+                }
+                ChoreCommand::Get => {
+                    let chores = self.usecase_invoker.invoke_get_chore_usecase()?;
+                    let short_ids = self.usecase_invoker.invoke_get_short_ids_usecase()?;
+                    chores
+                        .iter()
+                        .for_each(|chore| println!("{}", format_chore(chore, &short_ids)));
+                }
+                ChoreCommand::Reschedule { id, due_date } => {
+                    let chore_id = self
+                        .usecase_invoker
+                        .invoke_resolve_id_usecase(PlannableKind::Chore, id)?;
+                    let chore = self
+                        .usecase_invoker
+                        .invoke_reschedule_chore_usecase(chore_id, *due_date)?;
+                    let short_ids = self.usecase_invoker.invoke_get_short_ids_usecase()?;
+                    println!("{}", format_chore(&chore, &short_ids));
+                }
+            },
+            Command::Agenda(AgendaCli { command }) => match command {
+                AgendaCommand::Add {
+                    title,
+                    start,
+                    end,
+                    length,
+                    location,
+                    every,
+                    until,
+                } => {
+                    let recurrence = every.map(|recurrence| AgendaRecurrence {
+                        recurrence,
+                        until: *until,
+                        excluded_dates: Vec::new(),
+                    });
+                    self.usecase_invoker.invoke_add_agenda_item_usecase(
+                        title.to_string(),
+                        *start,
+                        agenda_item_end(*end, *length),
+                        location.clone(),
+                        recurrence,
+                    )?;
+                }
+                AgendaCommand::Reschedule {
+                    id,
+                    start,
+                    end,
+                    length,
+                } => {
+                    let agenda_item_id = self
+                        .usecase_invoker
+                        .invoke_resolve_id_usecase(PlannableKind::AgendaItem, id)?;
+                    let agenda_item = self.usecase_invoker.invoke_reschedule_agenda_item_usecase(
+                        agenda_item_id,
+                        *start,
+                        agenda_item_end(*end, *length),
+                    )?;
+                    let short_ids = self.usecase_invoker.invoke_get_short_ids_usecase()?;
+                    println!("{}", format_agenda_item(&agenda_item, &short_ids));
+                }
+            },
+            Command::Reminder(ReminderCli { command }) => match command {
+                ReminderCommand::Add {
+                    title,
+                    trigger_at,
+                    every,
+                } => {
+                    self.usecase_invoker.invoke_add_reminder_usecase(
+                        title.to_string(),
+                        *trigger_at,
+                        *every,
+                    )?;
+                }
+                ReminderCommand::Ack { id } => {
+                    let reminder_id = self
+                        .usecase_invoker
+                        .invoke_resolve_id_usecase(PlannableKind::Reminder, id)?;
+                    let reminder = self
+                        .usecase_invoker
+                        .invoke_acknowledge_reminder_usecase(reminder_id)?;
+                    let short_ids = self.usecase_invoker.invoke_get_short_ids_usecase()?;
+                    println!("{}", format_reminder(&reminder, &short_ids));
+                }
+                ReminderCommand::Snooze { id, until } => {
+                    let reminder_id = self
+                        .usecase_invoker
+                        .invoke_resolve_id_usecase(PlannableKind::Reminder, id)?;
+                    let reminder = self
+                        .usecase_invoker
+                        .invoke_snooze_reminder_usecase(reminder_id, *until)?;
+                    let short_ids = self.usecase_invoker.invoke_get_short_ids_usecase()?;
+                    println!("{}", format_reminder(&reminder, &short_ids));
                 }
             },
+            Command::Issue(IssueCli { command }) => match command {
+                IssueCommand::Raise { title, severity } => {
+                    self.usecase_invoker
+                        .invoke_raise_issue_usecase(title.to_string(), *severity)?;
+                }
+            },
+            Command::Impediment(ImpedimentCli { command }) => match command {
+                ImpedimentCommand::Raise {
+                    title,
+                    expires_at,
+                    blocks,
+                } => {
+                    let mut blocked = Vec::new();
+                    for (kind, id) in blocks {
+                        let plannable_id =
+                            self.usecase_invoker.invoke_resolve_id_usecase(*kind, id)?;
+                        blocked.push(plannable_ref(*kind, plannable_id));
+                    }
+                    self.usecase_invoker.invoke_raise_impediment_usecase(
+                        title.to_string(),
+                        *expires_at,
+                        blocked,
+                    )?;
+                }
+            },
+            Command::Today => {
+                let today = self.usecase_invoker.invoke_today_view_usecase()?;
+                let short_ids = self.usecase_invoker.invoke_get_short_ids_usecase()?;
+                let mut kind = None;
+                for item in &today {
+                    if kind != Some(item.kind) {
+                        kind = Some(item.kind);
+                        println!("{} ({})", item.kind, item.kind.urgency());
+                    }
+                    println!("  {}", format_view_item(item, &short_ids));
+                }
+            }
+            Command::Next { window } => {
+                let timeline = self
+                    .usecase_invoker
+                    .invoke_next_hours_view_usecase(*window)?;
+                let short_ids = self.usecase_invoker.invoke_get_short_ids_usecase()?;
+                for entry in &timeline {
+                    match entry {
+                        TimelineEntry::Item(item) => {
+                            println!("{} {}", item.kind, format_view_item(item, &short_ids))
+                        }
+                        TimelineEntry::Gap { from, to } => println!(
+                            "free {} - {}",
+                            from.format("%Y-%m-%d %H:%M"),
+                            to.format("%H:%M")
+                        ),
+                    }
+                }
+            }
+            Command::Week { week } => {
+                let days = self.usecase_invoker.invoke_week_view_usecase(*week)?;
+                println!("{}", format_week(&days));
+            }
+            Command::Report { horizon } => {
+                let report = self
+                    .usecase_invoker
+                    .invoke_expiry_report_usecase(*horizon)?;
+                let short_ids = self.usecase_invoker.invoke_get_short_ids_usecase()?;
+                println!("Overdue");
+                for (item, by) in &report.overdue {
+                    let overdue = format!("{} by {}", item.kind, format_span(by));
+                    println!("  {} {}", overdue, format_view_item(item, &short_ids));
+                }
+                println!("Expiring within {}", format_span(horizon));
+                for (item, within) in &report.expiring {
+                    let expiring = format!("{} in {}", item.kind, format_span(within));
+                    println!("  {} {}", expiring, format_view_item(item, &short_ids));
+                }
+            }
+            Command::Expire => {
+                let expired = self.usecase_invoker.invoke_expire_plannables_usecase()?;
+                let short_ids = self.usecase_invoker.invoke_get_short_ids_usecase()?;
+                for item in &expired {
+                    println!(
+                        "expired {} {}",
+                        item.kind,
+                        format_view_item(item, &short_ids)
+                    );
+                }
+            }
+            Command::Convert { kind, id, into } => {
+                let plannable_id = self.usecase_invoker.invoke_resolve_id_usecase(*kind, id)?;
+                let source = match kind {
+                    PlannableKind::Todo => PlannableRef::Todo(plannable_id),
+                    PlannableKind::Task => PlannableRef::Task(plannable_id),
+                    PlannableKind::Chore => PlannableRef::Chore(plannable_id),
+                    _ => unreachable!("only convertible kinds are parsed"),
+                };
+                let converted = self
+                    .usecase_invoker
+                    .invoke_convert_plannable_usecase(source, *into)?;
+                let short_ids = self.usecase_invoker.invoke_get_short_ids_usecase()?;
+                println!(
+                    "converted into {} {}",
+                    converted.kind(),
+                    short_ids.short(converted.id())
+                );
+            }
         }
         Ok(())
     }
 }
 
+// Maps a failed run to the process exit code, loosely following sysexits.h
+pub fn exit_code(error: &anyhow::Error) -> u8 {
+    let store_error = if let Some(error) = error.downcast_ref::<PlannableError>() {
+        match error {
+            PlannableError::Invalid(_) => return 65,
+            PlannableError::EventStore(error) => error,
+        }
+    } else if let Some(error) = error.downcast_ref::<TodoError>() {
+        match error {
+            TodoError::Invalid(_) => return 65,
+            TodoError::EventStore(error) => error,
+            _ => return 1,
+        }
+    } else if let Some(error) = error.downcast_ref::<IdError>() {
+        match error {
+            IdError::EventStore(error) => error,
+            _ => return 64,
+        }
+    } else if let Some(error) = error.downcast_ref::<ChoreError>() {
+        match error {
+            ChoreError::Invalid(_) => return 65,
+            ChoreError::EventStore(error) => error,
+            _ => return 1,
+        }
+    } else if let Some(error) = error.downcast_ref::<AgendaItemError>() {
+        match error {
+            AgendaItemError::Invalid(_) => return 65,
+            AgendaItemError::EventStore(error) => error,
+            _ => return 1,
+        }
+    } else if let Some(error) = error.downcast_ref::<ReminderError>() {
+        match error {
+            ReminderError::Invalid(_) => return 65,
+            ReminderError::EventStore(error) => error,
+            _ => return 1,
+        }
+    } else if let Some(error) = error.downcast_ref::<ConversionError>() {
+        match error {
+            ConversionError::TargetRuleViolated(_) => return 65,
+            ConversionError::EventStore(error) => error,
+            _ => return 1,
+        }
+    } else if let Some(error) = error.downcast_ref::<ViewError>() {
+        match error {
+            ViewError::EventStore(error) => error,
+            _ => return 64,
+        }
+    } else if let Some(error) = error.downcast_ref::<EventStoreError>() {
+        error
+    } else {
+        return 1;
+    };
+    match store_error {
+        EventStoreError::Connection(_) => 69,
+        EventStoreError::MissingSchema(_) | EventStoreError::Migration(_) => 78,
+        EventStoreError::ConstraintViolation(_) | EventStoreError::ConcurrencyConflict { .. } => 75,
+        EventStoreError::CorruptBody { .. } | EventStoreError::UnknownEventType { .. } => 65,
+        EventStoreError::ForeignEvent { .. } => 70,
+        EventStoreError::Database(_) => 74,
+    }
+}
+
 pub trait AddTodoUseCaseInvoker {
-    fn invoke_add_todo_usecase(&mut self, title: String, end_date: Option<NaiveDateTime>);
+    fn invoke_add_todo_usecase(
+        &mut self,
+        title: String,
+        end_date: Option<NaiveDateTime>,
+    ) -> Result<(), PlannableError>;
 }
 
 pub trait GetTodoUseCaseInvoker {
-    fn invoke_get_todo_usecase(&mut self) -> Vec<TodoCreatedEvent>;
+    fn invoke_get_todo_usecase(&mut self) -> Result<Vec<Todo>, EventStoreError>;
+}
+
+pub trait CompleteTodoUseCaseInvoker {
+    fn invoke_complete_todo_usecase(&mut self, todo_id: Uuid) -> Result<Todo, TodoError>;
+}
+
+pub trait ReopenTodoUseCaseInvoker {
+    fn invoke_reopen_todo_usecase(&mut self, todo_id: Uuid) -> Result<Todo, TodoError>;
+}
+
+pub trait RemoveTodoUseCaseInvoker {
+    fn invoke_remove_todo_usecase(&mut self, todo_id: Uuid) -> Result<Todo, TodoError>;
+}
+
+pub trait EditTodoUseCaseInvoker {
+    fn invoke_edit_todo_usecase(
+        &mut self,
+        todo_id: Uuid,
+        title: Option<String>,
+        end_date: Option<NaiveDateTime>,
+    ) -> Result<Todo, TodoError>;
+}
+
+pub trait AddChoreUseCaseInvoker {
+    fn invoke_add_chore_usecase(
+        &mut self,
+        title: String,
+        due_date: Option<NaiveDateTime>,
+        recurrence: Option<Recurrence>,
+    ) -> Result<(), PlannableError>;
+}
+
+pub trait GetChoreUseCaseInvoker {
+    fn invoke_get_chore_usecase(&mut self) -> Result<Vec<Chore>, EventStoreError>;
+}
+
+pub trait RescheduleChoreUseCaseInvoker {
+    fn invoke_reschedule_chore_usecase(
+        &mut self,
+        chore_id: Uuid,
+        due_date: NaiveDateTime,
+    ) -> Result<Chore, ChoreError>;
+}
+
+pub trait AddAgendaItemUseCaseInvoker {
+    fn invoke_add_agenda_item_usecase(
+        &mut self,
+        title: String,
+        start: NaiveDateTime,
+        end: Option<AgendaItemEnd>,
+        location: Option<String>,
+        recurrence: Option<AgendaRecurrence>,
+    ) -> Result<(), PlannableError>;
+}
+
+pub trait RescheduleAgendaItemUseCaseInvoker {
+    fn invoke_reschedule_agenda_item_usecase(
+        &mut self,
+        agenda_item_id: Uuid,
+        start: NaiveDateTime,
+        end: Option<AgendaItemEnd>,
+    ) -> Result<AgendaItem, AgendaItemError>;
+}
+
+pub trait AddReminderUseCaseInvoker {
+    fn invoke_add_reminder_usecase(
+        &mut self,
+        title: String,
+        trigger_at: NaiveDateTime,
+        recurrence: Option<Recurrence>,
+    ) -> Result<(), PlannableError>;
+}
+
+pub trait AcknowledgeReminderUseCaseInvoker {
+    fn invoke_acknowledge_reminder_usecase(
+        &mut self,
+        reminder_id: Uuid,
+    ) -> Result<Reminder, ReminderError>;
+}
+
+pub trait SnoozeReminderUseCaseInvoker {
+    fn invoke_snooze_reminder_usecase(
+        &mut self,
+        reminder_id: Uuid,
+        until: NaiveDateTime,
+    ) -> Result<Reminder, ReminderError>;
+}
+
+pub trait RaiseIssueUseCaseInvoker {
+    fn invoke_raise_issue_usecase(
+        &mut self,
+        title: String,
+        severity: Severity,
+    ) -> Result<(), PlannableError>;
+}
+
+pub trait RaiseImpedimentUseCaseInvoker {
+    fn invoke_raise_impediment_usecase(
+        &mut self,
+        title: String,
+        expires_at: NaiveDateTime,
+        blocks: Vec<PlannableRef>,
+    ) -> Result<(), PlannableError>;
+}
+
+pub trait TodayViewUseCaseInvoker {
+    fn invoke_today_view_usecase(&mut self) -> Result<Vec<ViewItem>, EventStoreError>;
+}
+
+pub trait NextHoursViewUseCaseInvoker {
+    fn invoke_next_hours_view_usecase(
+        &mut self,
+        window: Duration,
+    ) -> Result<Vec<TimelineEntry>, ViewError>;
+}
+
+pub trait WeekViewUseCaseInvoker {
+    // None is the current week
+    fn invoke_week_view_usecase(
+        &mut self,
+        week: Option<IsoWeek>,
+    ) -> Result<Vec<WeekDay>, EventStoreError>;
+}
+
+pub trait ExpiryReportUseCaseInvoker {
+    fn invoke_expiry_report_usecase(
+        &mut self,
+        horizon: Duration,
+    ) -> Result<ExpiryReport, EventStoreError>;
+}
+
+pub trait ExpirePlannablesUseCaseInvoker {
+    fn invoke_expire_plannables_usecase(&mut self) -> Result<Vec<ViewItem>, EventStoreError>;
+}
+
+pub trait ConvertPlannableUseCaseInvoker {
+    fn invoke_convert_plannable_usecase(
+        &mut self,
+        source: PlannableRef,
+        target: PlannableKind,
+    ) -> Result<PlannableRef, ConversionError>;
+}
+
+pub trait ResolveIdUseCaseInvoker {
+    fn invoke_resolve_id_usecase(
+        &mut self,
+        kind: PlannableKind,
+        id_prefix: &str,
+    ) -> Result<Uuid, IdError>;
+}
+
+pub trait GetShortIdsUseCaseInvoker {
+    fn invoke_get_short_ids_usecase(&mut self) -> Result<ShortIds, EventStoreError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_blocked, parse_window};
+    use chrono::Duration;
+    use entities::plannable::PlannableKind;
+
+    #[test]
+    fn given_windowinunits_when_parsewindow_then_duration() {
+        assert_eq!(parse_window("90m"), Ok(Duration::minutes(90)));
+        assert_eq!(parse_window("3h"), Ok(Duration::hours(3)));
+        assert_eq!(parse_window("2d"), Ok(Duration::days(2)));
+    }
+
+    #[test]
+    fn given_windowtoolongforaduration_when_parsewindow_then_error() {
+        assert!(parse_window("100000000d").is_err());
+        assert!(parse_window(&format!("{}h", i64::MAX)).is_err());
+        assert!(parse_window("36601d").is_err());
+        assert_eq!(parse_window("36600d"), Ok(Duration::days(36600)));
+    }
+
+    #[test]
+    fn given_kindandid_when_parseblocked_then_onlyreferenceablekinds() {
+        assert_eq!(
+            parse_blocked("task:3f2a"),
+            Ok((PlannableKind::Task, String::from("3f2a")))
+        );
+        assert!(parse_blocked("reminder:3f2a").is_err());
+        assert!(parse_blocked("3f2a").is_err());
+    }
 }
@@ -0,0 +1,3 @@
+DROP INDEX plannable_events_kind;
+ALTER TABLE plannable_events DROP COLUMN event_type;
+ALTER TABLE plannable_events DROP COLUMN plannable_kind;
@@ -0,0 +1,12 @@
+-- Rows from before this migration do not say which plannable they belong to. Only todos were stored
+-- with a type, tasks were stored as just a title and an end date, and so were todos created before
+-- their lifecycle events existed. Databases the diesel cli set up then were only ever written by the
+-- todo CLI, so all their rows are todos. Databases set up by the old create_table may hold tasks as
+-- well, those with untyped rows are refused before the migrations run, see
+-- PlannableEventsRepository::baseline. Rows without a type can only be Created events.
+ALTER TABLE plannable_events ADD COLUMN plannable_kind TEXT NOT NULL DEFAULT '';
+ALTER TABLE plannable_events ADD COLUMN event_type TEXT NOT NULL DEFAULT 'Created';
+UPDATE plannable_events
+   SET plannable_kind = 'todo',
+       event_type = COALESCE(json_extract(CAST(body AS TEXT), '$.type'), 'Created');
+CREATE INDEX plannable_events_kind ON plannable_events (plannable_kind, plannable_id);
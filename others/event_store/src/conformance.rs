@@ -0,0 +1,526 @@
+// Behaviour every EventStore backend has to show for every plannable kind. The suite is
+// instantiated per backend and kind with conformance_tests! at the bottom of this file.
+use crate::in_memory_event_store::InMemoryEventStore;
+use crate::plannable_event_store::{EventStore, PlannableEvent};
+use crate::sqlite_event_store::{
+    AgendaItemEventStore, ChoreEventStore, ImpedimentEventStore, IssueEventStore,
+    ReminderEventStore, StoryEventStore, TaskEventStore, TodoEventStore,
+};
+use chrono::{Duration, NaiveDate};
+use entities::agenda_item_events::{
+    AgendaItemCreatedEvent, AgendaItemEnd, AgendaItemEvent, AgendaItemRelocatedEvent,
+    AgendaRecurrence,
+};
+use entities::chore_events::{ChoreCreatedEvent, ChoreEvent, ChoreRescheduledEvent};
+use entities::errors::EventStoreError;
+use entities::impediment_events::{
+    ImpedimentEvent, ImpedimentRaisedEvent, ImpedimentResolvedEvent,
+};
+use entities::issue_events::{IssueCommentedEvent, IssueEvent, IssueRaisedEvent, Severity};
+use entities::plannable::PlannableRef;
+use entities::recurrence::Recurrence;
+use entities::reminder_events::{ReminderCreatedEvent, ReminderEvent, ReminderSnoozedEvent};
+use entities::story_events::{StoryCreatedEvent, StoryEvent, StoryRefinedEvent};
+use entities::task_events::{TaskConvertedEvent, TaskCreatedEvent, TaskEvent};
+use entities::todo_events::{TodoCreatedEvent, TodoEvent, TodoTitleChangedEvent};
+use std::fmt::Debug;
+use uuid::Uuid;
+
+// Lets the suite create events of a kind without knowing its variants
+pub trait SampleEvent: PlannableEvent + PartialEq + Debug + Clone {
+    fn sample(plannable_id: Uuid, sequence: i32, title: &str) -> Self;
+    fn sequence(&self) -> i32;
+}
+
+impl SampleEvent for TodoEvent {
+    fn sample(todo_id: Uuid, sequence: i32, title: &str) -> Self {
+        match sequence {
+            0 => TodoEvent::Created(TodoCreatedEvent {
+                event_id: Uuid::new_v4(),
+                todo_id,
+                sequence,
+                title: String::from(title),
+                end_date: Some(
+                    NaiveDate::from_ymd_opt(2023, 9, 29)
+                        .unwrap()
+                        .and_hms_opt(9, 10, 11)
+                        .unwrap(),
+                ),
+                converted_from: None,
+            }),
+            _ => TodoEvent::TitleChanged(TodoTitleChangedEvent {
+                event_id: Uuid::new_v4(),
+                todo_id,
+                sequence,
+                title: String::from(title),
+            }),
+        }
+    }
+
+    fn sequence(&self) -> i32 {
+        TodoEvent::sequence(self)
+    }
+}
+
+impl SampleEvent for TaskEvent {
+    fn sample(task_id: Uuid, sequence: i32, title: &str) -> Self {
+        match sequence {
+            0 => TaskEvent::Created(TaskCreatedEvent {
+                event_id: Uuid::new_v4(),
+                task_id,
+                sequence,
+                title: String::from(title),
+                end_date: Some(
+                    NaiveDate::from_ymd_opt(2023, 9, 29)
+                        .unwrap()
+                        .and_hms_opt(0, 0, 0)
+                        .unwrap(),
+                ),
+                converted_from: Some(PlannableRef::Todo(Uuid::new_v4())),
+            }),
+            _ => TaskEvent::Converted(TaskConvertedEvent {
+                event_id: Uuid::new_v4(),
+                task_id,
+                sequence,
+                into: PlannableRef::Chore(Uuid::new_v4()),
+            }),
+        }
+    }
+
+    fn sequence(&self) -> i32 {
+        TaskEvent::sequence(self)
+    }
+}
+
+impl SampleEvent for ChoreEvent {
+    fn sample(chore_id: Uuid, sequence: i32, title: &str) -> Self {
+        let due_date = NaiveDate::from_ymd_opt(2023, 9, 29)
+            .unwrap()
+            .and_hms_opt(9, 0, 0);
+        match sequence {
+            0 => ChoreEvent::Created(ChoreCreatedEvent {
+                event_id: Uuid::new_v4(),
+                chore_id,
+                sequence,
+                title: String::from(title),
+                due_date,
+                recurrence: Some(Recurrence::Weeks(1)),
+                converted_from: None,
+            }),
+            _ => ChoreEvent::Rescheduled(ChoreRescheduledEvent {
+                event_id: Uuid::new_v4(),
+                chore_id,
+                sequence,
+                due_date,
+            }),
+        }
+    }
+
+    fn sequence(&self) -> i32 {
+        ChoreEvent::sequence(self)
+    }
+}
+
+impl SampleEvent for AgendaItemEvent {
+    fn sample(agenda_item_id: Uuid, sequence: i32, title: &str) -> Self {
+        match sequence {
+            0 => AgendaItemEvent::Created(AgendaItemCreatedEvent {
+                event_id: Uuid::new_v4(),
+                agenda_item_id,
+                sequence,
+                title: String::from(title),
+                start: NaiveDate::from_ymd_opt(2023, 7, 3)
+                    .unwrap()
+                    .and_hms_opt(18, 0, 0)
+                    .unwrap(),
+                end: Some(AgendaItemEnd::After(Duration::minutes(90))),
+                location: Some(String::from("Gym")),
+                recurrence: Some(AgendaRecurrence {
+                    recurrence: Recurrence::Weeks(1),
+                    until: NaiveDate::from_ymd_opt(2023, 8, 1)
+                        .unwrap()
+                        .and_hms_opt(0, 0, 0),
+                    excluded_dates: vec![NaiveDate::from_ymd_opt(2023, 7, 17).unwrap()],
+                }),
+            }),
+            _ => AgendaItemEvent::Relocated(AgendaItemRelocatedEvent {
+                event_id: Uuid::new_v4(),
+                agenda_item_id,
+                sequence,
+                location: Some(String::from(title)),
+            }),
+        }
+    }
+
+    fn sequence(&self) -> i32 {
+        AgendaItemEvent::sequence(self)
+    }
+}
+
+impl SampleEvent for ReminderEvent {
+    fn sample(reminder_id: Uuid, sequence: i32, title: &str) -> Self {
+        let trigger_at = NaiveDate::from_ymd_opt(2023, 9, 29)
+            .unwrap()
+            .and_hms_opt(8, 0, 0)
+            .unwrap();
+        match sequence {
+            0 => ReminderEvent::Created(ReminderCreatedEvent {
+                event_id: Uuid::new_v4(),
+                reminder_id,
+                sequence,
+                title: String::from(title),
+                trigger_at,
+                recurrence: Some(Recurrence::Years(1)),
+            }),
+            _ => ReminderEvent::Snoozed(ReminderSnoozedEvent {
+                event_id: Uuid::new_v4(),
+                reminder_id,
+                sequence,
+                until: trigger_at + Duration::hours(1),
+            }),
+        }
+    }
+
+    fn sequence(&self) -> i32 {
+        ReminderEvent::sequence(self)
+    }
+}
+
+impl SampleEvent for StoryEvent {
+    fn sample(story_id: Uuid, sequence: i32, title: &str) -> Self {
+        match sequence {
+            0 => StoryEvent::Created(StoryCreatedEvent {
+                event_id: Uuid::new_v4(),
+                story_id,
+                sequence,
+                title: String::from(title),
+                description: String::from("Split the app into layers"),
+            }),
+            _ => StoryEvent::Refined(StoryRefinedEvent {
+                event_id: Uuid::new_v4(),
+                story_id,
+                sequence,
+                description: String::from(title),
+                acceptance_notes: String::from("No usecase depends on diesel"),
+                estimate: Some(3),
+            }),
+        }
+    }
+
+    fn sequence(&self) -> i32 {
+        StoryEvent::sequence(self)
+    }
+}
+
+impl SampleEvent for IssueEvent {
+    fn sample(issue_id: Uuid, sequence: i32, title: &str) -> Self {
+        match sequence {
+            0 => IssueEvent::Raised(IssueRaisedEvent {
+                event_id: Uuid::new_v4(),
+                issue_id,
+                sequence,
+                title: String::from(title),
+                severity: Severity::High,
+            }),
+            _ => IssueEvent::Commented(IssueCommentedEvent {
+                event_id: Uuid::new_v4(),
+                issue_id,
+                sequence,
+                comment: String::from(title),
+            }),
+        }
+    }
+
+    fn sequence(&self) -> i32 {
+        IssueEvent::sequence(self)
+    }
+}
+
+impl SampleEvent for ImpedimentEvent {
+    fn sample(impediment_id: Uuid, sequence: i32, title: &str) -> Self {
+        match sequence {
+            0 => ImpedimentEvent::Raised(ImpedimentRaisedEvent {
+                event_id: Uuid::new_v4(),
+                impediment_id,
+                sequence,
+                title: String::from(title),
+                expires_at: NaiveDate::from_ymd_opt(2023, 9, 29)
+                    .unwrap()
+                    .and_hms_opt(18, 0, 0)
+                    .unwrap(),
+                blocks: vec![
+                    PlannableRef::Todo(Uuid::new_v4()),
+                    PlannableRef::Story(Uuid::new_v4()),
+                ],
+            }),
+            _ => ImpedimentEvent::Resolved(ImpedimentResolvedEvent {
+                event_id: Uuid::new_v4(),
+                impediment_id,
+                sequence,
+            }),
+        }
+    }
+
+    fn sequence(&self) -> i32 {
+        ImpedimentEvent::sequence(self)
+    }
+}
+
+pub fn given_event_when_save_then_getallreturnsit<E: SampleEvent>(
+    eventstore: &mut impl EventStore<E>,
+) {
+    let plannables = vec![E::sample(Uuid::new_v4(), 0, "Read rust book")];
+    let result = eventstore.save(plannables.clone());
+    assert!(result.is_ok());
+    let result = eventstore.get_all().unwrap();
+    assert_eq!(result, plannables);
+}
+
+pub fn given_id_when_read_then_returnalleventsfortheid<E: SampleEvent>(
+    eventstore: &mut impl EventStore<E>,
+) {
+    let plannable_id = Uuid::new_v4();
+    let other = E::sample(Uuid::new_v4(), 0, "Sell rust book");
+    let plannables = vec![
+        E::sample(plannable_id, 0, "Buy rust book"),
+        E::sample(plannable_id, 1, "Read rust book"),
+    ];
+    eventstore.save(vec![other]).unwrap();
+    //Save out of order to make sure read sorts by sequence
+    eventstore
+        .save(plannables.iter().rev().cloned().collect())
+        .unwrap();
+    let result = eventstore.read(plannable_id).unwrap();
+    assert_eq!(result, plannables);
+}
+
+pub fn given_id_when_doesnotexist_then_returnnoevents<E: SampleEvent>(
+    eventstore: &mut impl EventStore<E>,
+) {
+    let result = eventstore.read(Uuid::new_v4()).unwrap();
+    assert_eq!(result.len(), 0);
+}
+
+pub fn given_existingid_when_savemultiplewithduplicate_then_rollback<E: SampleEvent>(
+    eventstore: &mut impl EventStore<E>,
+) {
+    //GIVEN initial event
+    let plannable_id = Uuid::new_v4();
+    let created = vec![E::sample(plannable_id, 0, "Buy rust book")];
+    eventstore.save(created.clone()).unwrap();
+    let changed = vec![
+        E::sample(plannable_id, 1, "Read rust book"),
+        E::sample(plannable_id, 0, "Sell rust book"),
+    ];
+
+    //WHEN Save duplicate, the combination of sequence and plannable_id has to be unique
+    let result = eventstore.save(changed);
+
+    //THEN error
+    assert!(matches!(
+        result,
+        Err(EventStoreError::ConstraintViolation(_))
+    ));
+    //Test rollback by comparing the output to the original insertion
+    assert_eq!(eventstore.read(plannable_id).unwrap(), created);
+}
+
+pub fn given_newstream_when_append_then_storeassignssequences<E: SampleEvent>(
+    eventstore: &mut impl EventStore<E>,
+) {
+    let plannable_id = Uuid::new_v4();
+    let version = eventstore
+        .append(
+            plannable_id,
+            0,
+            vec![E::sample(plannable_id, 0, "Buy rust book")],
+        )
+        .unwrap();
+    assert_eq!(version, 1);
+    let version = eventstore
+        .append(
+            plannable_id,
+            1,
+            vec![
+                E::sample(plannable_id, 42, "Read rust book"),
+                E::sample(plannable_id, 42, "Sell rust book"),
+            ],
+        )
+        .unwrap();
+    assert_eq!(version, 3);
+
+    let sequences: Vec<i32> = eventstore
+        .read(plannable_id)
+        .unwrap()
+        .iter()
+        .map(|event| event.sequence())
+        .collect();
+    assert_eq!(sequences, vec![0, 1, 2]);
+}
+
+pub fn given_staleversion_when_append_then_concurrencyconflict<E: SampleEvent>(
+    eventstore: &mut impl EventStore<E>,
+) {
+    //GIVEN a stream that was already appended to by someone else
+    let plannable_id = Uuid::new_v4();
+    let created = vec![E::sample(plannable_id, 0, "Buy rust book")];
+    eventstore.append(plannable_id, 0, created.clone()).unwrap();
+
+    //WHEN appending with the version from before that change
+    let result = eventstore.append(
+        plannable_id,
+        0,
+        vec![E::sample(plannable_id, 0, "Read rust book")],
+    );
+
+    //THEN a concurrency conflict and nothing is written
+    assert_eq!(
+        result,
+        Err(EventStoreError::ConcurrencyConflict {
+            plannable_id: plannable_id.to_string(),
+            expected_version: 0,
+            actual_version: 1,
+        })
+    );
+    assert_eq!(eventstore.read(plannable_id).unwrap(), created);
+}
+
+pub fn given_eventofotherstream_when_append_then_rejected<E: SampleEvent>(
+    eventstore: &mut impl EventStore<E>,
+) {
+    let plannable_id = Uuid::new_v4();
+    let result = eventstore.append(
+        plannable_id,
+        0,
+        vec![E::sample(Uuid::new_v4(), 0, "Buy rust book")],
+    );
+    assert_eq!(
+        result,
+        Err(EventStoreError::ForeignEvent {
+            kind: String::from(E::KIND),
+            plannable_id: plannable_id.to_string(),
+        })
+    );
+    assert_eq!(eventstore.get_all().unwrap(), vec![]);
+}
+
+pub fn given_idsharingprefix_when_findids_then_distinctmatchesinorder<E: SampleEvent>(
+    eventstore: &mut impl EventStore<E>,
+) {
+    //GIVEN two streams sharing a prefix, one with two events, and one that does not match
+    let first = Uuid::parse_str("5e1f0000-0000-4000-8000-000000000002").unwrap();
+    let second = Uuid::parse_str("5e1f0000-0000-4000-8000-000000000001").unwrap();
+    let other = Uuid::parse_str("5e2f0000-0000-4000-8000-000000000001").unwrap();
+    eventstore
+        .save(vec![
+            E::sample(first, 0, "Buy rust book"),
+            E::sample(first, 1, "Read rust book"),
+            E::sample(second, 0, "Sell rust book"),
+            E::sample(other, 0, "Lend rust book"),
+        ])
+        .unwrap();
+
+    //WHEN
+    let result = eventstore.find_ids("5e1f").unwrap();
+
+    //THEN
+    assert_eq!(result, vec![second, first]);
+    assert_eq!(eventstore.find_ids("5e2f0000-").unwrap(), vec![other]);
+    assert!(eventstore.find_ids("5e3").unwrap().is_empty());
+}
+
+// Runs every test of the suite against the store created by $new_store, which gets a name that is
+// unique per test so backends with files on disk do not share them
+macro_rules! conformance_tests {
+    ($module:ident, $new_store:expr) => {
+        conformance_tests!(
+            @tests $module,
+            $new_store,
+            [
+                given_event_when_save_then_getallreturnsit,
+                given_id_when_read_then_returnalleventsfortheid,
+                given_id_when_doesnotexist_then_returnnoevents,
+                given_existingid_when_savemultiplewithduplicate_then_rollback,
+                given_newstream_when_append_then_storeassignssequences,
+                given_staleversion_when_append_then_concurrencyconflict,
+                given_eventofotherstream_when_append_then_rejected,
+                given_idsharingprefix_when_findids_then_distinctmatchesinorder
+            ]
+        );
+    };
+    (@tests $module:ident, $new_store:expr, [$($test:ident),*]) => {
+        mod $module {
+            use super::*;
+            $(
+                #[test]
+                fn $test() {
+                    let mut eventstore =
+                        ($new_store)(concat!(stringify!($module), "_", stringify!($test)));
+                    super::$test(&mut eventstore);
+                }
+            )*
+        }
+    };
+}
+
+fn sqlite_database_url(name: &str) -> String {
+    format!("/tmp/simple_plan_conformance_{}.db", name)
+}
+
+conformance_tests!(sqlite_todo, |name| TodoEventStore::clean(
+    &sqlite_database_url(name)
+)
+.unwrap());
+conformance_tests!(sqlite_task, |name| TaskEventStore::clean(
+    &sqlite_database_url(name)
+)
+.unwrap());
+conformance_tests!(sqlite_chore, |name| ChoreEventStore::clean(
+    &sqlite_database_url(name)
+)
+.unwrap());
+conformance_tests!(sqlite_agenda_item, |name| AgendaItemEventStore::clean(
+    &sqlite_database_url(name)
+)
+.unwrap());
+conformance_tests!(sqlite_reminder, |name| ReminderEventStore::clean(
+    &sqlite_database_url(name)
+)
+.unwrap());
+conformance_tests!(sqlite_story, |name| StoryEventStore::clean(
+    &sqlite_database_url(name)
+)
+.unwrap());
+conformance_tests!(sqlite_issue, |name| IssueEventStore::clean(
+    &sqlite_database_url(name)
+)
+.unwrap());
+conformance_tests!(sqlite_impediment, |name| ImpedimentEventStore::clean(
+    &sqlite_database_url(name)
+)
+.unwrap());
+conformance_tests!(
+    in_memory_todo,
+    |_name| InMemoryEventStore::<TodoEvent>::new()
+);
+conformance_tests!(in_memory_task, |_name| {
+    InMemoryEventStore::<TaskEvent>::new()
+});
+conformance_tests!(in_memory_chore, |_name| {
+    InMemoryEventStore::<ChoreEvent>::new()
+});
+conformance_tests!(in_memory_agenda_item, |_name| {
+    InMemoryEventStore::<AgendaItemEvent>::new()
+});
+conformance_tests!(in_memory_reminder, |_name| {
+    InMemoryEventStore::<ReminderEvent>::new()
+});
+conformance_tests!(in_memory_story, |_name| {
+    InMemoryEventStore::<StoryEvent>::new()
+});
+conformance_tests!(in_memory_issue, |_name| {
+    InMemoryEventStore::<IssueEvent>::new()
+});
+conformance_tests!(in_memory_impediment, |_name| {
+    InMemoryEventStore::<ImpedimentEvent>::new()
+});
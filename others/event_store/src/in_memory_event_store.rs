@@ -0,0 +1,134 @@
+use crate::models::PlannableEventRow;
+use crate::plannable_event_store::{EventStore, PlannableEvent};
+use entities::errors::EventStoreError;
+use std::marker::PhantomData;
+use uuid::Uuid;
+
+// EventStore that keeps the rows in memory. It encodes and checks them like the plannable_events
+// table does, so it can stand in for SqliteEventStore in tests.
+pub struct InMemoryEventStore<E> {
+    rows: Vec<PlannableEventRow>,
+    events: PhantomData<E>,
+}
+
+impl<E: PlannableEvent> InMemoryEventStore<E> {
+    pub fn new() -> Self {
+        Self {
+            rows: Vec::new(),
+            events: PhantomData,
+        }
+    }
+
+    // Stores all rows or none of them, with the same unique constraints as the table
+    fn insert(&mut self, rows: Vec<PlannableEventRow>) -> Result<(), EventStoreError> {
+        for (index, row) in rows.iter().enumerate() {
+            let stored = self.rows.iter().chain(&rows[..index]);
+            for other in stored {
+                if other.event_id == row.event_id {
+                    return Err(EventStoreError::ConstraintViolation(String::from(
+                        "UNIQUE constraint failed: plannable_events.event_id",
+                    )));
+                }
+                if other.plannable_id == row.plannable_id && other.sequence == row.sequence {
+                    return Err(EventStoreError::ConstraintViolation(String::from(
+                        "UNIQUE constraint failed: plannable_events.plannable_id, plannable_events.sequence",
+                    )));
+                }
+            }
+        }
+        self.rows.extend(rows);
+        Ok(())
+    }
+
+    // Rows of this kind decoded in the order they were stored
+    fn decode<'a>(
+        &self,
+        rows: impl Iterator<Item = &'a PlannableEventRow>,
+    ) -> Result<Vec<E>, EventStoreError> {
+        rows.filter(|row| row.plannable_kind == E::KIND)
+            .map(|row| row.clone().try_into())
+            .collect()
+    }
+}
+
+impl<E: PlannableEvent> Default for InMemoryEventStore<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: PlannableEvent> EventStore<E> for InMemoryEventStore<E> {
+    fn save(&mut self, events: Vec<E>) -> Result<(), EventStoreError> {
+        self.insert(events.into_iter().map(|event| event.into()).collect())
+    }
+
+    fn append(
+        &mut self,
+        plannable_id: Uuid,
+        expected_version: i32,
+        events: Vec<E>,
+    ) -> Result<i32, EventStoreError> {
+        let plannable_id = plannable_id.to_string();
+        let mut rows: Vec<PlannableEventRow> =
+            events.into_iter().map(|event| event.into()).collect();
+        if rows.iter().any(|row| row.plannable_id != plannable_id) {
+            return Err(EventStoreError::ForeignEvent {
+                kind: String::from(E::KIND),
+                plannable_id,
+            });
+        }
+        let actual_version = self
+            .rows
+            .iter()
+            .filter(|row| row.plannable_id == plannable_id)
+            .map(|row| row.sequence + 1)
+            .max()
+            .unwrap_or(0);
+        if actual_version != expected_version {
+            return Err(EventStoreError::ConcurrencyConflict {
+                plannable_id,
+                expected_version,
+                actual_version,
+            });
+        }
+        for (offset, row) in rows.iter_mut().enumerate() {
+            row.sequence = expected_version + offset as i32;
+        }
+        let version = expected_version + rows.len() as i32;
+        self.insert(rows)?;
+        Ok(version)
+    }
+
+    fn read(&mut self, plannable_id: Uuid) -> Result<Vec<E>, EventStoreError> {
+        let plannable_id = plannable_id.to_string();
+        let mut rows: Vec<&PlannableEventRow> = self
+            .rows
+            .iter()
+            .filter(|row| row.plannable_id == plannable_id)
+            .collect();
+        rows.sort_by_key(|row| row.sequence);
+        self.decode(rows.into_iter())
+    }
+
+    fn get_all(&mut self) -> Result<Vec<E>, EventStoreError> {
+        self.decode(self.rows.iter())
+    }
+
+    fn find_ids(&mut self, id_prefix: &str) -> Result<Vec<Uuid>, EventStoreError> {
+        let mut ids: Vec<&str> = self
+            .rows
+            .iter()
+            .filter(|row| row.plannable_kind == E::KIND && row.plannable_id.starts_with(id_prefix))
+            .map(|row| row.plannable_id.as_str())
+            .collect();
+        ids.sort();
+        ids.dedup();
+        ids.iter()
+            .map(|id| {
+                Uuid::parse_str(id).map_err(|error| {
+                    EventStoreError::Database(format!("Stored id {} is not a uuid: {}", id, error))
+                })
+            })
+            .collect()
+    }
+}
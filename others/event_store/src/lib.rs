@@ -1,6 +1,10 @@
 #![allow(dead_code)]
-mod models;
+#[cfg(test)]
+mod conformance;
+pub mod in_memory_event_store;
+pub mod models;
+pub mod plannable_event_store;
 mod plannable_events_repository;
-pub mod plannable_task_event_store;
-pub mod plannable_todo_event_store;
 mod schema;
+pub mod sqlite_event_store;
+mod upcasting;
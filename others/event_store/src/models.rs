@@ -1,11 +1,66 @@
-use chrono::NaiveDateTime;
+use crate::plannable_event_store::PlannableEvent;
+use crate::upcasting;
+use chrono::{Duration, NaiveDate, NaiveDateTime};
 use diesel::prelude::*;
-use entities::task_events::TaskCreatedEvent;
-use entities::todo_events::TodoCreatedEvent;
+use entities::agenda_item_events::{
+    AgendaItemCancelledEvent, AgendaItemCreatedEvent, AgendaItemEnd, AgendaItemEvent,
+    AgendaItemOccurrenceExcludedEvent, AgendaItemRelocatedEvent, AgendaItemRescheduledEvent,
+    AgendaRecurrence,
+};
+use entities::chore_events::{
+    ChoreConvertedEvent, ChoreCreatedEvent, ChoreDoneEvent, ChoreEvent, ChoreExpiredEvent,
+    ChoreRescheduledEvent, ChoreSkippedEvent,
+};
+use entities::errors::EventStoreError;
+use entities::impediment_events::{
+    ImpedimentEvent, ImpedimentExpiredEvent, ImpedimentRaisedEvent, ImpedimentResolvedEvent,
+};
+use entities::issue_events::{
+    IssueCommentedEvent, IssueEvent, IssueRaisedEvent, IssueReopenedEvent, IssueResolvedEvent,
+    Severity,
+};
+use entities::plannable::{PlannableKind, PlannableRef};
+use entities::recurrence::Recurrence;
+use entities::reminder_events::{
+    ReminderAcknowledgedEvent, ReminderCreatedEvent, ReminderEvent, ReminderSnoozedEvent,
+};
+use entities::story_events::{
+    StoryCreatedEvent, StoryEvent, StoryFinishedEvent, StoryMarkedReadyEvent, StoryRefinedEvent,
+    StoryStartedEvent,
+};
+use entities::task_events::{TaskConvertedEvent, TaskCreatedEvent, TaskEvent, TaskExpiredEvent};
+use entities::todo_events::{
+    TodoCompletedEvent, TodoConvertedEvent, TodoCreatedEvent, TodoEndDateChangedEvent, TodoEvent,
+    TodoRemovedEvent, TodoReopenedEvent, TodoTitleChangedEvent,
+};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use std::str;
 use uuid::Uuid;
 
+// Values of the plannable_kind column, a store only reads the streams of its own kind
+pub const TODO_KIND: &str = "todo";
+pub const TASK_KIND: &str = "task";
+pub const CHORE_KIND: &str = "chore";
+pub const AGENDA_ITEM_KIND: &str = "agenda_item";
+pub const REMINDER_KIND: &str = "reminder";
+pub const STORY_KIND: &str = "story";
+pub const ISSUE_KIND: &str = "issue";
+pub const IMPEDIMENT_KIND: &str = "impediment";
+
+// The plannable_kind value the streams of a kind are stored with
+pub fn kind_value(kind: PlannableKind) -> &'static str {
+    match kind {
+        PlannableKind::Todo => TODO_KIND,
+        PlannableKind::Task => TASK_KIND,
+        PlannableKind::Chore => CHORE_KIND,
+        PlannableKind::AgendaItem => AGENDA_ITEM_KIND,
+        PlannableKind::Reminder => REMINDER_KIND,
+        PlannableKind::Story => STORY_KIND,
+        PlannableKind::Issue => ISSUE_KIND,
+        PlannableKind::Impediment => IMPEDIMENT_KIND,
+    }
+}
+
 #[derive(Debug, PartialEq, Clone, Queryable, Selectable, Insertable)]
 #[diesel(table_name = crate::schema::plannable_events)]
 #[diesel(check_for_backend(diesel::sqlite::Sqlite))]
@@ -14,75 +69,1253 @@ pub struct PlannableEventRow {
     pub plannable_id: String,
     pub sequence: i32,
     pub body: Vec<u8>,
+    pub plannable_kind: String,
+    pub event_type: String,
+}
+
+impl PlannableEventRow {
+    fn new<B: Serialize>(
+        plannable_kind: &str,
+        event_type: &str,
+        event_id: Uuid,
+        plannable_id: Uuid,
+        sequence: i32,
+        body: &B,
+    ) -> Self {
+        let mut body = serde_json::to_value(body).unwrap();
+        let schema_version = upcasting::registry().current_version(plannable_kind, event_type);
+        upcasting::set_schema_version(&mut body, schema_version).unwrap();
+        PlannableEventRow {
+            //event_id: (*todo_created_events.event_id.as_bytes()).to_vec(),
+            event_id: event_id.to_string().into(),
+            plannable_id: plannable_id.to_string(),
+            sequence,
+            body: serde_json::to_string(&body).unwrap().into(),
+            plannable_kind: plannable_kind.to_string(),
+            event_type: event_type.to_string(),
+        }
+    }
+
+    fn corrupt(&self, reason: String) -> EventStoreError {
+        EventStoreError::CorruptBody {
+            event_id: String::from_utf8_lossy(&self.event_id).to_string(),
+            reason,
+        }
+    }
+
+    fn event_uuid(&self) -> Result<Uuid, EventStoreError> {
+        Uuid::try_parse_ascii(&self.event_id).map_err(|error| self.corrupt(error.to_string()))
+    }
+
+    fn plannable_uuid(&self) -> Result<Uuid, EventStoreError> {
+        Uuid::parse_str(&self.plannable_id).map_err(|error| self.corrupt(error.to_string()))
+    }
+
+    // Brings the stored body up to the current schema version before deserializing it
+    fn decode_body<T: DeserializeOwned>(&self) -> Result<T, EventStoreError> {
+        let body =
+            serde_json::from_slice(&self.body).map_err(|error| self.corrupt(error.to_string()))?;
+        let body = upcasting::registry()
+            .upcast(&self.plannable_kind, &self.event_type, body)
+            .map_err(|reason| self.corrupt(reason))?;
+        serde_json::from_value(body).map_err(|error| self.corrupt(error.to_string()))
+    }
+
+    fn decode_ref(&self, plannable: PlannableRefBody) -> Result<PlannableRef, EventStoreError> {
+        PlannableRef::try_from(plannable).map_err(|reason| self.corrupt(reason))
+    }
+
+    fn unknown_event_type(&self) -> EventStoreError {
+        EventStoreError::UnknownEventType {
+            event_id: String::from_utf8_lossy(&self.event_id).to_string(),
+            event_type: self.event_type.clone(),
+        }
+    }
+}
+
+// Bodies only hold the fields specific to the event, its type is in the event_type column
+#[derive(Serialize, Deserialize, Debug)]
+struct TodoCreatedBody {
+    title: String,
+    end_date: Option<NaiveDateTime>,
+    // Left out for plannables that were created directly, which keeps their bodies as they were
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    converted_from: Option<PlannableRefBody>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
-struct TodoBody {
+struct TodoTitleChangedBody {
     title: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct TodoEndDateChangedBody {
     end_date: Option<NaiveDateTime>,
 }
 
-impl From<TodoCreatedEvent> for PlannableEventRow {
-    fn from(todo_created_events: TodoCreatedEvent) -> Self {
-        let body = TodoBody {
-            title: todo_created_events.title,
-            end_date: todo_created_events.end_date,
+#[derive(Serialize, Deserialize, Debug)]
+struct EmptyBody {}
+
+// A reference by the plannable_kind and plannable_id the referenced events are stored under
+#[derive(Serialize, Deserialize, Debug)]
+struct PlannableRefBody {
+    kind: String,
+    id: String,
+}
+
+impl From<PlannableRef> for PlannableRefBody {
+    fn from(plannable: PlannableRef) -> Self {
+        let (kind, id) = match plannable {
+            PlannableRef::Todo(id) => (TODO_KIND, id),
+            PlannableRef::Task(id) => (TASK_KIND, id),
+            PlannableRef::Chore(id) => (CHORE_KIND, id),
+            PlannableRef::Story(id) => (STORY_KIND, id),
         };
-        PlannableEventRow {
-            //event_id: (*todo_created_events.event_id.as_bytes()).to_vec(),
-            event_id: todo_created_events.event_id.to_string().into(),
-            plannable_id: todo_created_events.todo_id.to_string(),
-            sequence: todo_created_events.sequence,
-            body: serde_json::to_string(&body).unwrap().into(),
+        PlannableRefBody {
+            kind: kind.to_string(),
+            id: id.to_string(),
         }
     }
 }
 
-impl From<PlannableEventRow> for TodoCreatedEvent {
-    fn from(row: PlannableEventRow) -> Self {
-        let deserialized: TodoBody =
-            serde_json::from_str(str::from_utf8(&row.body).unwrap()).unwrap();
-        TodoCreatedEvent {
-            event_id: Uuid::parse_str(str::from_utf8(&row.event_id).unwrap()).unwrap(),
-            todo_id: Uuid::parse_str(&row.plannable_id).unwrap(),
-            sequence: row.sequence,
-            title: deserialized.title,
-            end_date: deserialized.end_date,
+impl TryFrom<PlannableRefBody> for PlannableRef {
+    type Error = String;
+
+    fn try_from(plannable: PlannableRefBody) -> Result<Self, Self::Error> {
+        let id = Uuid::parse_str(&plannable.id).map_err(|error| error.to_string())?;
+        match plannable.kind.as_str() {
+            TODO_KIND => Ok(PlannableRef::Todo(id)),
+            TASK_KIND => Ok(PlannableRef::Task(id)),
+            CHORE_KIND => Ok(PlannableRef::Chore(id)),
+            STORY_KIND => Ok(PlannableRef::Story(id)),
+            kind => Err(format!("Can not reference a plannable of kind {}", kind)),
         }
     }
 }
 
+// Body of the event that closes a stream of any kind after a conversion
+#[derive(Serialize, Deserialize, Debug)]
+struct ConvertedBody {
+    into: PlannableRefBody,
+}
+
+impl From<TodoEvent> for PlannableEventRow {
+    fn from(todo_event: TodoEvent) -> Self {
+        let event_id = todo_event.event_id();
+        let todo_id = todo_event.todo_id();
+        let sequence = todo_event.sequence();
+        match todo_event {
+            TodoEvent::Created(event) => PlannableEventRow::new(
+                TODO_KIND,
+                "Created",
+                event_id,
+                todo_id,
+                sequence,
+                &TodoCreatedBody {
+                    title: event.title,
+                    end_date: event.end_date,
+                    converted_from: event.converted_from.map(PlannableRefBody::from),
+                },
+            ),
+            TodoEvent::TitleChanged(event) => PlannableEventRow::new(
+                TODO_KIND,
+                "TitleChanged",
+                event_id,
+                todo_id,
+                sequence,
+                &TodoTitleChangedBody { title: event.title },
+            ),
+            TodoEvent::EndDateChanged(event) => PlannableEventRow::new(
+                TODO_KIND,
+                "EndDateChanged",
+                event_id,
+                todo_id,
+                sequence,
+                &TodoEndDateChangedBody {
+                    end_date: event.end_date,
+                },
+            ),
+            TodoEvent::Completed(_) => PlannableEventRow::new(
+                TODO_KIND,
+                "Completed",
+                event_id,
+                todo_id,
+                sequence,
+                &EmptyBody {},
+            ),
+            TodoEvent::Reopened(_) => PlannableEventRow::new(
+                TODO_KIND,
+                "Reopened",
+                event_id,
+                todo_id,
+                sequence,
+                &EmptyBody {},
+            ),
+            TodoEvent::Removed(_) => PlannableEventRow::new(
+                TODO_KIND,
+                "Removed",
+                event_id,
+                todo_id,
+                sequence,
+                &EmptyBody {},
+            ),
+            TodoEvent::Converted(event) => PlannableEventRow::new(
+                TODO_KIND,
+                "Converted",
+                event_id,
+                todo_id,
+                sequence,
+                &ConvertedBody {
+                    into: event.into.into(),
+                },
+            ),
+        }
+    }
+}
+
+impl TryFrom<PlannableEventRow> for TodoEvent {
+    type Error = EventStoreError;
+
+    fn try_from(row: PlannableEventRow) -> Result<Self, Self::Error> {
+        let event_id = row.event_uuid()?;
+        let todo_id = row.plannable_uuid()?;
+        let sequence = row.sequence;
+        Ok(match row.event_type.as_str() {
+            "Created" => {
+                let body: TodoCreatedBody = row.decode_body()?;
+                TodoEvent::Created(TodoCreatedEvent {
+                    event_id,
+                    todo_id,
+                    sequence,
+                    title: body.title,
+                    end_date: body.end_date,
+                    converted_from: body
+                        .converted_from
+                        .map(|plannable| row.decode_ref(plannable))
+                        .transpose()?,
+                })
+            }
+            "TitleChanged" => {
+                let body: TodoTitleChangedBody = row.decode_body()?;
+                TodoEvent::TitleChanged(TodoTitleChangedEvent {
+                    event_id,
+                    todo_id,
+                    sequence,
+                    title: body.title,
+                })
+            }
+            "EndDateChanged" => {
+                let body: TodoEndDateChangedBody = row.decode_body()?;
+                TodoEvent::EndDateChanged(TodoEndDateChangedEvent {
+                    event_id,
+                    todo_id,
+                    sequence,
+                    end_date: body.end_date,
+                })
+            }
+            "Completed" => TodoEvent::Completed(TodoCompletedEvent {
+                event_id,
+                todo_id,
+                sequence,
+            }),
+            "Reopened" => TodoEvent::Reopened(TodoReopenedEvent {
+                event_id,
+                todo_id,
+                sequence,
+            }),
+            "Removed" => TodoEvent::Removed(TodoRemovedEvent {
+                event_id,
+                todo_id,
+                sequence,
+            }),
+            "Converted" => {
+                let body: ConvertedBody = row.decode_body()?;
+                TodoEvent::Converted(TodoConvertedEvent {
+                    event_id,
+                    todo_id,
+                    sequence,
+                    into: row.decode_ref(body.into)?,
+                })
+            }
+            _ => return Err(row.unknown_event_type()),
+        })
+    }
+}
+
+impl PlannableEvent for TodoEvent {
+    const KIND: &'static str = TODO_KIND;
+
+    fn plannable_id(&self) -> Uuid {
+        self.todo_id()
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 struct TaskBody {
     title: String,
     end_date: Option<NaiveDateTime>,
+    // Left out for plannables that were created directly, which keeps their bodies as they were
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    converted_from: Option<PlannableRefBody>,
 }
 
-impl From<TaskCreatedEvent> for PlannableEventRow {
-    fn from(task_created_events: TaskCreatedEvent) -> Self {
-        let body = TaskBody {
-            title: task_created_events.title,
-            end_date: task_created_events.end_date,
-        };
-        PlannableEventRow {
-            event_id: task_created_events.event_id.to_string().into(),
-            plannable_id: task_created_events.task_id.to_string(),
-            sequence: task_created_events.sequence,
-            body: serde_json::to_string(&body).unwrap().into(),
+impl From<TaskEvent> for PlannableEventRow {
+    fn from(task_event: TaskEvent) -> Self {
+        let event_id = task_event.event_id();
+        let task_id = task_event.task_id();
+        let sequence = task_event.sequence();
+        match task_event {
+            TaskEvent::Created(event) => PlannableEventRow::new(
+                TASK_KIND,
+                "Created",
+                event_id,
+                task_id,
+                sequence,
+                &TaskBody {
+                    title: event.title,
+                    end_date: event.end_date,
+                    converted_from: event.converted_from.map(PlannableRefBody::from),
+                },
+            ),
+            TaskEvent::Converted(event) => PlannableEventRow::new(
+                TASK_KIND,
+                "Converted",
+                event_id,
+                task_id,
+                sequence,
+                &ConvertedBody {
+                    into: event.into.into(),
+                },
+            ),
+            TaskEvent::Expired(_) => PlannableEventRow::new(
+                TASK_KIND,
+                "Expired",
+                event_id,
+                task_id,
+                sequence,
+                &EmptyBody {},
+            ),
+        }
+    }
+}
+
+impl TryFrom<PlannableEventRow> for TaskEvent {
+    type Error = EventStoreError;
+
+    fn try_from(row: PlannableEventRow) -> Result<Self, Self::Error> {
+        let event_id = row.event_uuid()?;
+        let task_id = row.plannable_uuid()?;
+        let sequence = row.sequence;
+        Ok(match row.event_type.as_str() {
+            "Created" => {
+                let body: TaskBody = row.decode_body()?;
+                TaskEvent::Created(TaskCreatedEvent {
+                    event_id,
+                    task_id,
+                    sequence,
+                    title: body.title,
+                    end_date: body.end_date,
+                    converted_from: body
+                        .converted_from
+                        .map(|plannable| row.decode_ref(plannable))
+                        .transpose()?,
+                })
+            }
+            "Converted" => {
+                let body: ConvertedBody = row.decode_body()?;
+                TaskEvent::Converted(TaskConvertedEvent {
+                    event_id,
+                    task_id,
+                    sequence,
+                    into: row.decode_ref(body.into)?,
+                })
+            }
+            "Expired" => TaskEvent::Expired(TaskExpiredEvent {
+                event_id,
+                task_id,
+                sequence,
+            }),
+            _ => return Err(row.unknown_event_type()),
+        })
+    }
+}
+
+impl PlannableEvent for TaskEvent {
+    const KIND: &'static str = TASK_KIND;
+
+    fn plannable_id(&self) -> Uuid {
+        self.task_id()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+enum RecurrenceBody {
+    Days(u32),
+    Weeks(u32),
+    Months(u32),
+    Years(u32),
+}
+
+impl From<Recurrence> for RecurrenceBody {
+    fn from(recurrence: Recurrence) -> Self {
+        match recurrence {
+            Recurrence::Days(days) => RecurrenceBody::Days(days),
+            Recurrence::Weeks(weeks) => RecurrenceBody::Weeks(weeks),
+            Recurrence::Months(months) => RecurrenceBody::Months(months),
+            Recurrence::Years(years) => RecurrenceBody::Years(years),
+        }
+    }
+}
+
+impl From<RecurrenceBody> for Recurrence {
+    fn from(recurrence: RecurrenceBody) -> Self {
+        match recurrence {
+            RecurrenceBody::Days(days) => Recurrence::Days(days),
+            RecurrenceBody::Weeks(weeks) => Recurrence::Weeks(weeks),
+            RecurrenceBody::Months(months) => Recurrence::Months(months),
+            RecurrenceBody::Years(years) => Recurrence::Years(years),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct ChoreCreatedBody {
+    title: String,
+    due_date: Option<NaiveDateTime>,
+    recurrence: Option<RecurrenceBody>,
+    // Left out for plannables that were created directly, which keeps their bodies as they were
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    converted_from: Option<PlannableRefBody>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct ChoreRescheduledBody {
+    due_date: Option<NaiveDateTime>,
+}
+
+impl From<ChoreEvent> for PlannableEventRow {
+    fn from(chore_event: ChoreEvent) -> Self {
+        let event_id = chore_event.event_id();
+        let chore_id = chore_event.chore_id();
+        let sequence = chore_event.sequence();
+        match chore_event {
+            ChoreEvent::Created(event) => PlannableEventRow::new(
+                CHORE_KIND,
+                "Created",
+                event_id,
+                chore_id,
+                sequence,
+                &ChoreCreatedBody {
+                    title: event.title,
+                    due_date: event.due_date,
+                    recurrence: event.recurrence.map(RecurrenceBody::from),
+                    converted_from: event.converted_from.map(PlannableRefBody::from),
+                },
+            ),
+            ChoreEvent::Rescheduled(event) => PlannableEventRow::new(
+                CHORE_KIND,
+                "Rescheduled",
+                event_id,
+                chore_id,
+                sequence,
+                &ChoreRescheduledBody {
+                    due_date: event.due_date,
+                },
+            ),
+            ChoreEvent::Done(_) => PlannableEventRow::new(
+                CHORE_KIND,
+                "Done",
+                event_id,
+                chore_id,
+                sequence,
+                &EmptyBody {},
+            ),
+            ChoreEvent::Skipped(_) => PlannableEventRow::new(
+                CHORE_KIND,
+                "Skipped",
+                event_id,
+                chore_id,
+                sequence,
+                &EmptyBody {},
+            ),
+            ChoreEvent::Expired(_) => PlannableEventRow::new(
+                CHORE_KIND,
+                "Expired",
+                event_id,
+                chore_id,
+                sequence,
+                &EmptyBody {},
+            ),
+            ChoreEvent::Converted(event) => PlannableEventRow::new(
+                CHORE_KIND,
+                "Converted",
+                event_id,
+                chore_id,
+                sequence,
+                &ConvertedBody {
+                    into: event.into.into(),
+                },
+            ),
+        }
+    }
+}
+
+impl TryFrom<PlannableEventRow> for ChoreEvent {
+    type Error = EventStoreError;
+
+    fn try_from(row: PlannableEventRow) -> Result<Self, Self::Error> {
+        let event_id = row.event_uuid()?;
+        let chore_id = row.plannable_uuid()?;
+        let sequence = row.sequence;
+        Ok(match row.event_type.as_str() {
+            "Created" => {
+                let body: ChoreCreatedBody = row.decode_body()?;
+                ChoreEvent::Created(ChoreCreatedEvent {
+                    event_id,
+                    chore_id,
+                    sequence,
+                    title: body.title,
+                    due_date: body.due_date,
+                    recurrence: body.recurrence.map(Recurrence::from),
+                    converted_from: body
+                        .converted_from
+                        .map(|plannable| row.decode_ref(plannable))
+                        .transpose()?,
+                })
+            }
+            "Rescheduled" => {
+                let body: ChoreRescheduledBody = row.decode_body()?;
+                ChoreEvent::Rescheduled(ChoreRescheduledEvent {
+                    event_id,
+                    chore_id,
+                    sequence,
+                    due_date: body.due_date,
+                })
+            }
+            "Done" => ChoreEvent::Done(ChoreDoneEvent {
+                event_id,
+                chore_id,
+                sequence,
+            }),
+            "Skipped" => ChoreEvent::Skipped(ChoreSkippedEvent {
+                event_id,
+                chore_id,
+                sequence,
+            }),
+            "Expired" => ChoreEvent::Expired(ChoreExpiredEvent {
+                event_id,
+                chore_id,
+                sequence,
+            }),
+            "Converted" => {
+                let body: ConvertedBody = row.decode_body()?;
+                ChoreEvent::Converted(ChoreConvertedEvent {
+                    event_id,
+                    chore_id,
+                    sequence,
+                    into: row.decode_ref(body.into)?,
+                })
+            }
+            _ => return Err(row.unknown_event_type()),
+        })
+    }
+}
+
+impl PlannableEvent for ChoreEvent {
+    const KIND: &'static str = CHORE_KIND;
+
+    fn plannable_id(&self) -> Uuid {
+        self.chore_id()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+enum AgendaItemEndBody {
+    At(NaiveDateTime),
+    AfterSeconds(i64),
+}
+
+impl From<AgendaItemEnd> for AgendaItemEndBody {
+    fn from(end: AgendaItemEnd) -> Self {
+        match end {
+            AgendaItemEnd::At(end) => AgendaItemEndBody::At(end),
+            AgendaItemEnd::After(duration) => {
+                AgendaItemEndBody::AfterSeconds(duration.num_seconds())
+            }
+        }
+    }
+}
+
+impl From<AgendaItemEndBody> for AgendaItemEnd {
+    fn from(end: AgendaItemEndBody) -> Self {
+        match end {
+            AgendaItemEndBody::At(end) => AgendaItemEnd::At(end),
+            AgendaItemEndBody::AfterSeconds(seconds) => {
+                AgendaItemEnd::After(Duration::seconds(seconds))
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct AgendaRecurrenceBody {
+    recurrence: RecurrenceBody,
+    until: Option<NaiveDateTime>,
+    excluded_dates: Vec<NaiveDate>,
+}
+
+impl From<AgendaRecurrence> for AgendaRecurrenceBody {
+    fn from(recurrence: AgendaRecurrence) -> Self {
+        AgendaRecurrenceBody {
+            recurrence: recurrence.recurrence.into(),
+            until: recurrence.until,
+            excluded_dates: recurrence.excluded_dates,
         }
     }
 }
 
-impl From<PlannableEventRow> for TaskCreatedEvent {
-    fn from(row: PlannableEventRow) -> Self {
-        let deserialized: TaskBody =
-            serde_json::from_str(str::from_utf8(&row.body).unwrap()).unwrap();
-        TaskCreatedEvent {
-            event_id: Uuid::parse_str(str::from_utf8(&row.event_id).unwrap()).unwrap(),
-            task_id: Uuid::parse_str(&row.plannable_id).unwrap(),
-            sequence: row.sequence,
-            title: deserialized.title,
-            end_date: deserialized.end_date,
+impl From<AgendaRecurrenceBody> for AgendaRecurrence {
+    fn from(recurrence: AgendaRecurrenceBody) -> Self {
+        AgendaRecurrence {
+            recurrence: recurrence.recurrence.into(),
+            until: recurrence.until,
+            excluded_dates: recurrence.excluded_dates,
         }
     }
 }
+
+#[derive(Serialize, Deserialize, Debug)]
+struct AgendaItemCreatedBody {
+    title: String,
+    start: NaiveDateTime,
+    end: Option<AgendaItemEndBody>,
+    location: Option<String>,
+    recurrence: Option<AgendaRecurrenceBody>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct AgendaItemRescheduledBody {
+    start: NaiveDateTime,
+    end: Option<AgendaItemEndBody>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct AgendaItemRelocatedBody {
+    location: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct AgendaItemOccurrenceExcludedBody {
+    date: NaiveDate,
+}
+
+impl From<AgendaItemEvent> for PlannableEventRow {
+    fn from(agenda_item_event: AgendaItemEvent) -> Self {
+        let event_id = agenda_item_event.event_id();
+        let agenda_item_id = agenda_item_event.agenda_item_id();
+        let sequence = agenda_item_event.sequence();
+        match agenda_item_event {
+            AgendaItemEvent::Created(event) => PlannableEventRow::new(
+                AGENDA_ITEM_KIND,
+                "Created",
+                event_id,
+                agenda_item_id,
+                sequence,
+                &AgendaItemCreatedBody {
+                    title: event.title,
+                    start: event.start,
+                    end: event.end.map(AgendaItemEndBody::from),
+                    location: event.location,
+                    recurrence: event.recurrence.map(AgendaRecurrenceBody::from),
+                },
+            ),
+            AgendaItemEvent::Rescheduled(event) => PlannableEventRow::new(
+                AGENDA_ITEM_KIND,
+                "Rescheduled",
+                event_id,
+                agenda_item_id,
+                sequence,
+                &AgendaItemRescheduledBody {
+                    start: event.start,
+                    end: event.end.map(AgendaItemEndBody::from),
+                },
+            ),
+            AgendaItemEvent::Relocated(event) => PlannableEventRow::new(
+                AGENDA_ITEM_KIND,
+                "Relocated",
+                event_id,
+                agenda_item_id,
+                sequence,
+                &AgendaItemRelocatedBody {
+                    location: event.location,
+                },
+            ),
+            AgendaItemEvent::OccurrenceExcluded(event) => PlannableEventRow::new(
+                AGENDA_ITEM_KIND,
+                "OccurrenceExcluded",
+                event_id,
+                agenda_item_id,
+                sequence,
+                &AgendaItemOccurrenceExcludedBody { date: event.date },
+            ),
+            AgendaItemEvent::Cancelled(_) => PlannableEventRow::new(
+                AGENDA_ITEM_KIND,
+                "Cancelled",
+                event_id,
+                agenda_item_id,
+                sequence,
+                &EmptyBody {},
+            ),
+        }
+    }
+}
+
+impl TryFrom<PlannableEventRow> for AgendaItemEvent {
+    type Error = EventStoreError;
+
+    fn try_from(row: PlannableEventRow) -> Result<Self, Self::Error> {
+        let event_id = row.event_uuid()?;
+        let agenda_item_id = row.plannable_uuid()?;
+        let sequence = row.sequence;
+        Ok(match row.event_type.as_str() {
+            "Created" => {
+                let body: AgendaItemCreatedBody = row.decode_body()?;
+                AgendaItemEvent::Created(AgendaItemCreatedEvent {
+                    event_id,
+                    agenda_item_id,
+                    sequence,
+                    title: body.title,
+                    start: body.start,
+                    end: body.end.map(AgendaItemEnd::from),
+                    location: body.location,
+                    recurrence: body.recurrence.map(AgendaRecurrence::from),
+                })
+            }
+            "Rescheduled" => {
+                let body: AgendaItemRescheduledBody = row.decode_body()?;
+                AgendaItemEvent::Rescheduled(AgendaItemRescheduledEvent {
+                    event_id,
+                    agenda_item_id,
+                    sequence,
+                    start: body.start,
+                    end: body.end.map(AgendaItemEnd::from),
+                })
+            }
+            "Relocated" => {
+                let body: AgendaItemRelocatedBody = row.decode_body()?;
+                AgendaItemEvent::Relocated(AgendaItemRelocatedEvent {
+                    event_id,
+                    agenda_item_id,
+                    sequence,
+                    location: body.location,
+                })
+            }
+            "OccurrenceExcluded" => {
+                let body: AgendaItemOccurrenceExcludedBody = row.decode_body()?;
+                AgendaItemEvent::OccurrenceExcluded(AgendaItemOccurrenceExcludedEvent {
+                    event_id,
+                    agenda_item_id,
+                    sequence,
+                    date: body.date,
+                })
+            }
+            "Cancelled" => AgendaItemEvent::Cancelled(AgendaItemCancelledEvent {
+                event_id,
+                agenda_item_id,
+                sequence,
+            }),
+            _ => return Err(row.unknown_event_type()),
+        })
+    }
+}
+
+impl PlannableEvent for AgendaItemEvent {
+    const KIND: &'static str = AGENDA_ITEM_KIND;
+
+    fn plannable_id(&self) -> Uuid {
+        self.agenda_item_id()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct ReminderCreatedBody {
+    title: String,
+    trigger_at: NaiveDateTime,
+    recurrence: Option<RecurrenceBody>,
+}
+
+// Acknowledgements stored before the time was recorded have no acknowledged_at, they only move on
+// by one occurrence
+#[derive(Serialize, Deserialize, Debug)]
+struct ReminderAcknowledgedBody {
+    #[serde(default)]
+    acknowledged_at: Option<NaiveDateTime>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct ReminderSnoozedBody {
+    until: NaiveDateTime,
+}
+
+impl From<ReminderEvent> for PlannableEventRow {
+    fn from(reminder_event: ReminderEvent) -> Self {
+        let event_id = reminder_event.event_id();
+        let reminder_id = reminder_event.reminder_id();
+        let sequence = reminder_event.sequence();
+        match reminder_event {
+            ReminderEvent::Created(event) => PlannableEventRow::new(
+                REMINDER_KIND,
+                "Created",
+                event_id,
+                reminder_id,
+                sequence,
+                &ReminderCreatedBody {
+                    title: event.title,
+                    trigger_at: event.trigger_at,
+                    recurrence: event.recurrence.map(RecurrenceBody::from),
+                },
+            ),
+            ReminderEvent::Acknowledged(event) => PlannableEventRow::new(
+                REMINDER_KIND,
+                "Acknowledged",
+                event_id,
+                reminder_id,
+                sequence,
+                &ReminderAcknowledgedBody {
+                    acknowledged_at: Some(event.acknowledged_at),
+                },
+            ),
+            ReminderEvent::Snoozed(event) => PlannableEventRow::new(
+                REMINDER_KIND,
+                "Snoozed",
+                event_id,
+                reminder_id,
+                sequence,
+                &ReminderSnoozedBody { until: event.until },
+            ),
+        }
+    }
+}
+
+impl TryFrom<PlannableEventRow> for ReminderEvent {
+    type Error = EventStoreError;
+
+    fn try_from(row: PlannableEventRow) -> Result<Self, Self::Error> {
+        let event_id = row.event_uuid()?;
+        let reminder_id = row.plannable_uuid()?;
+        let sequence = row.sequence;
+        Ok(match row.event_type.as_str() {
+            "Created" => {
+                let body: ReminderCreatedBody = row.decode_body()?;
+                ReminderEvent::Created(ReminderCreatedEvent {
+                    event_id,
+                    reminder_id,
+                    sequence,
+                    title: body.title,
+                    trigger_at: body.trigger_at,
+                    recurrence: body.recurrence.map(Recurrence::from),
+                })
+            }
+            "Acknowledged" => {
+                let body: ReminderAcknowledgedBody = row.decode_body()?;
+                ReminderEvent::Acknowledged(ReminderAcknowledgedEvent {
+                    event_id,
+                    reminder_id,
+                    sequence,
+                    acknowledged_at: body.acknowledged_at.unwrap_or(NaiveDateTime::MIN),
+                })
+            }
+            "Snoozed" => {
+                let body: ReminderSnoozedBody = row.decode_body()?;
+                ReminderEvent::Snoozed(ReminderSnoozedEvent {
+                    event_id,
+                    reminder_id,
+                    sequence,
+                    until: body.until,
+                })
+            }
+            _ => return Err(row.unknown_event_type()),
+        })
+    }
+}
+
+impl PlannableEvent for ReminderEvent {
+    const KIND: &'static str = REMINDER_KIND;
+
+    fn plannable_id(&self) -> Uuid {
+        self.reminder_id()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct StoryCreatedBody {
+    title: String,
+    description: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct StoryRefinedBody {
+    description: String,
+    acceptance_notes: String,
+    estimate: Option<u32>,
+}
+
+impl From<StoryEvent> for PlannableEventRow {
+    fn from(story_event: StoryEvent) -> Self {
+        let event_id = story_event.event_id();
+        let story_id = story_event.story_id();
+        let sequence = story_event.sequence();
+        match story_event {
+            StoryEvent::Created(event) => PlannableEventRow::new(
+                STORY_KIND,
+                "Created",
+                event_id,
+                story_id,
+                sequence,
+                &StoryCreatedBody {
+                    title: event.title,
+                    description: event.description,
+                },
+            ),
+            StoryEvent::Refined(event) => PlannableEventRow::new(
+                STORY_KIND,
+                "Refined",
+                event_id,
+                story_id,
+                sequence,
+                &StoryRefinedBody {
+                    description: event.description,
+                    acceptance_notes: event.acceptance_notes,
+                    estimate: event.estimate,
+                },
+            ),
+            StoryEvent::MarkedReady(_) => PlannableEventRow::new(
+                STORY_KIND,
+                "MarkedReady",
+                event_id,
+                story_id,
+                sequence,
+                &EmptyBody {},
+            ),
+            StoryEvent::Started(_) => PlannableEventRow::new(
+                STORY_KIND,
+                "Started",
+                event_id,
+                story_id,
+                sequence,
+                &EmptyBody {},
+            ),
+            StoryEvent::Finished(_) => PlannableEventRow::new(
+                STORY_KIND,
+                "Finished",
+                event_id,
+                story_id,
+                sequence,
+                &EmptyBody {},
+            ),
+        }
+    }
+}
+
+impl TryFrom<PlannableEventRow> for StoryEvent {
+    type Error = EventStoreError;
+
+    fn try_from(row: PlannableEventRow) -> Result<Self, Self::Error> {
+        let event_id = row.event_uuid()?;
+        let story_id = row.plannable_uuid()?;
+        let sequence = row.sequence;
+        Ok(match row.event_type.as_str() {
+            "Created" => {
+                let body: StoryCreatedBody = row.decode_body()?;
+                StoryEvent::Created(StoryCreatedEvent {
+                    event_id,
+                    story_id,
+                    sequence,
+                    title: body.title,
+                    description: body.description,
+                })
+            }
+            "Refined" => {
+                let body: StoryRefinedBody = row.decode_body()?;
+                StoryEvent::Refined(StoryRefinedEvent {
+                    event_id,
+                    story_id,
+                    sequence,
+                    description: body.description,
+                    acceptance_notes: body.acceptance_notes,
+                    estimate: body.estimate,
+                })
+            }
+            "MarkedReady" => StoryEvent::MarkedReady(StoryMarkedReadyEvent {
+                event_id,
+                story_id,
+                sequence,
+            }),
+            "Started" => StoryEvent::Started(StoryStartedEvent {
+                event_id,
+                story_id,
+                sequence,
+            }),
+            "Finished" => StoryEvent::Finished(StoryFinishedEvent {
+                event_id,
+                story_id,
+                sequence,
+            }),
+            _ => return Err(row.unknown_event_type()),
+        })
+    }
+}
+
+impl PlannableEvent for StoryEvent {
+    const KIND: &'static str = STORY_KIND;
+
+    fn plannable_id(&self) -> Uuid {
+        self.story_id()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+enum SeverityBody {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl From<Severity> for SeverityBody {
+    fn from(severity: Severity) -> Self {
+        match severity {
+            Severity::Low => SeverityBody::Low,
+            Severity::Medium => SeverityBody::Medium,
+            Severity::High => SeverityBody::High,
+            Severity::Critical => SeverityBody::Critical,
+        }
+    }
+}
+
+impl From<SeverityBody> for Severity {
+    fn from(severity: SeverityBody) -> Self {
+        match severity {
+            SeverityBody::Low => Severity::Low,
+            SeverityBody::Medium => Severity::Medium,
+            SeverityBody::High => Severity::High,
+            SeverityBody::Critical => Severity::Critical,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct IssueRaisedBody {
+    title: String,
+    severity: SeverityBody,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct IssueCommentedBody {
+    comment: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct IssueResolvedBody {
+    resolution: String,
+}
+
+impl From<IssueEvent> for PlannableEventRow {
+    fn from(issue_event: IssueEvent) -> Self {
+        let event_id = issue_event.event_id();
+        let issue_id = issue_event.issue_id();
+        let sequence = issue_event.sequence();
+        match issue_event {
+            IssueEvent::Raised(event) => PlannableEventRow::new(
+                ISSUE_KIND,
+                "Raised",
+                event_id,
+                issue_id,
+                sequence,
+                &IssueRaisedBody {
+                    title: event.title,
+                    severity: event.severity.into(),
+                },
+            ),
+            IssueEvent::Commented(event) => PlannableEventRow::new(
+                ISSUE_KIND,
+                "Commented",
+                event_id,
+                issue_id,
+                sequence,
+                &IssueCommentedBody {
+                    comment: event.comment,
+                },
+            ),
+            IssueEvent::Resolved(event) => PlannableEventRow::new(
+                ISSUE_KIND,
+                "Resolved",
+                event_id,
+                issue_id,
+                sequence,
+                &IssueResolvedBody {
+                    resolution: event.resolution,
+                },
+            ),
+            IssueEvent::Reopened(_) => PlannableEventRow::new(
+                ISSUE_KIND,
+                "Reopened",
+                event_id,
+                issue_id,
+                sequence,
+                &EmptyBody {},
+            ),
+        }
+    }
+}
+
+impl TryFrom<PlannableEventRow> for IssueEvent {
+    type Error = EventStoreError;
+
+    fn try_from(row: PlannableEventRow) -> Result<Self, Self::Error> {
+        let event_id = row.event_uuid()?;
+        let issue_id = row.plannable_uuid()?;
+        let sequence = row.sequence;
+        Ok(match row.event_type.as_str() {
+            "Raised" => {
+                let body: IssueRaisedBody = row.decode_body()?;
+                IssueEvent::Raised(IssueRaisedEvent {
+                    event_id,
+                    issue_id,
+                    sequence,
+                    title: body.title,
+                    severity: body.severity.into(),
+                })
+            }
+            "Commented" => {
+                let body: IssueCommentedBody = row.decode_body()?;
+                IssueEvent::Commented(IssueCommentedEvent {
+                    event_id,
+                    issue_id,
+                    sequence,
+                    comment: body.comment,
+                })
+            }
+            "Resolved" => {
+                let body: IssueResolvedBody = row.decode_body()?;
+                IssueEvent::Resolved(IssueResolvedEvent {
+                    event_id,
+                    issue_id,
+                    sequence,
+                    resolution: body.resolution,
+                })
+            }
+            "Reopened" => IssueEvent::Reopened(IssueReopenedEvent {
+                event_id,
+                issue_id,
+                sequence,
+            }),
+            _ => return Err(row.unknown_event_type()),
+        })
+    }
+}
+
+impl PlannableEvent for IssueEvent {
+    const KIND: &'static str = ISSUE_KIND;
+
+    fn plannable_id(&self) -> Uuid {
+        self.issue_id()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct ImpedimentRaisedBody {
+    title: String,
+    expires_at: NaiveDateTime,
+    blocks: Vec<PlannableRefBody>,
+}
+
+impl From<ImpedimentEvent> for PlannableEventRow {
+    fn from(impediment_event: ImpedimentEvent) -> Self {
+        let event_id = impediment_event.event_id();
+        let impediment_id = impediment_event.impediment_id();
+        let sequence = impediment_event.sequence();
+        match impediment_event {
+            ImpedimentEvent::Raised(event) => PlannableEventRow::new(
+                IMPEDIMENT_KIND,
+                "Raised",
+                event_id,
+                impediment_id,
+                sequence,
+                &ImpedimentRaisedBody {
+                    title: event.title,
+                    expires_at: event.expires_at,
+                    blocks: event
+                        .blocks
+                        .into_iter()
+                        .map(PlannableRefBody::from)
+                        .collect(),
+                },
+            ),
+            ImpedimentEvent::Resolved(_) => PlannableEventRow::new(
+                IMPEDIMENT_KIND,
+                "Resolved",
+                event_id,
+                impediment_id,
+                sequence,
+                &EmptyBody {},
+            ),
+            ImpedimentEvent::Expired(_) => PlannableEventRow::new(
+                IMPEDIMENT_KIND,
+                "Expired",
+                event_id,
+                impediment_id,
+                sequence,
+                &EmptyBody {},
+            ),
+        }
+    }
+}
+
+impl TryFrom<PlannableEventRow> for ImpedimentEvent {
+    type Error = EventStoreError;
+
+    fn try_from(row: PlannableEventRow) -> Result<Self, Self::Error> {
+        let event_id = row.event_uuid()?;
+        let impediment_id = row.plannable_uuid()?;
+        let sequence = row.sequence;
+        Ok(match row.event_type.as_str() {
+            "Raised" => {
+                let body: ImpedimentRaisedBody = row.decode_body()?;
+                let blocks = body
+                    .blocks
+                    .into_iter()
+                    .map(PlannableRef::try_from)
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|reason| row.corrupt(reason))?;
+                ImpedimentEvent::Raised(ImpedimentRaisedEvent {
+                    event_id,
+                    impediment_id,
+                    sequence,
+                    title: body.title,
+                    expires_at: body.expires_at,
+                    blocks,
+                })
+            }
+            "Resolved" => ImpedimentEvent::Resolved(ImpedimentResolvedEvent {
+                event_id,
+                impediment_id,
+                sequence,
+            }),
+            "Expired" => ImpedimentEvent::Expired(ImpedimentExpiredEvent {
+                event_id,
+                impediment_id,
+                sequence,
+            }),
+            _ => return Err(row.unknown_event_type()),
+        })
+    }
+}
+
+impl PlannableEvent for ImpedimentEvent {
+    const KIND: &'static str = IMPEDIMENT_KIND;
+
+    fn plannable_id(&self) -> Uuid {
+        self.impediment_id()
+    }
+}
@@ -0,0 +1,39 @@
+use crate::models::PlannableEventRow;
+use entities::errors::EventStoreError;
+use uuid::Uuid;
+
+// An event of one plannable kind that knows how to encode itself into a PlannableEventRow and
+// decode itself from one. Adding a domain type to the store only takes implementing this.
+pub trait PlannableEvent:
+    Into<PlannableEventRow> + TryFrom<PlannableEventRow, Error = EventStoreError>
+{
+    // Value of the plannable_kind column for these events
+    const KIND: &'static str;
+
+    fn plannable_id(&self) -> Uuid;
+}
+
+// The event streams of one plannable kind, one stream per plannable id
+pub trait EventStore<E: PlannableEvent> {
+    // Stores the events with the sequences they carry, all of them or none
+    fn save(&mut self, events: Vec<E>) -> Result<(), EventStoreError>;
+
+    // Appends events to the stream of plannable_id. expected_version is the version the caller
+    // last saw, 0 for a new plannable. The store assigns the sequences and fails with a
+    // ConcurrencyConflict when the stream has moved on. Returns the new version of the stream.
+    fn append(
+        &mut self,
+        plannable_id: Uuid,
+        expected_version: i32,
+        events: Vec<E>,
+    ) -> Result<i32, EventStoreError>;
+
+    // Returns the full history of a plannable ordered by sequence
+    fn read(&mut self, plannable_id: Uuid) -> Result<Vec<E>, EventStoreError>;
+
+    fn get_all(&mut self) -> Result<Vec<E>, EventStoreError>;
+
+    // Returns the distinct ids of this kind that start with id_prefix, in ascending order. Only
+    // the ids are looked at, no event is decoded.
+    fn find_ids(&mut self, id_prefix: &str) -> Result<Vec<Uuid>, EventStoreError>;
+}
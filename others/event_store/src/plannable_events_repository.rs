@@ -1,28 +1,143 @@
 use crate::models::PlannableEventRow;
 use crate::schema::plannable_events::dsl::*;
 use diesel::delete;
+use diesel::dsl::{max, sql};
 use diesel::insert_into;
 use diesel::prelude::*;
+use diesel::result::DatabaseErrorKind;
+use diesel::sql_types::{Bool, Text};
 use diesel::SqliteConnection;
-use std::io::Error;
-use std::io::ErrorKind;
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+use entities::errors::EventStoreError;
+
+const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
+// Versions of the migrations in the migrations directory that baseline records
+const PLANNABLE_EVENTS_VERSION: &str = "20230805162827";
+const PLANNABLE_KIND_VERSION: &str = "20261018090000";
+
+pub enum AppendError {
+    ConcurrencyConflict {
+        plannable_id: String,
+        expected_version: i32,
+        actual_version: i32,
+    },
+    Diesel(diesel::result::Error),
+}
+
+impl From<diesel::result::Error> for AppendError {
+    fn from(error: diesel::result::Error) -> Self {
+        AppendError::Diesel(error)
+    }
+}
+
+impl AppendError {
+    pub fn into_event_store_error(self) -> EventStoreError {
+        match self {
+            AppendError::ConcurrencyConflict {
+                plannable_id: id,
+                expected_version,
+                actual_version,
+            } => EventStoreError::ConcurrencyConflict {
+                plannable_id: id,
+                expected_version,
+                actual_version,
+            },
+            AppendError::Diesel(error) => map_diesel_error(error),
+        }
+    }
+}
+
+pub fn map_diesel_error(error: diesel::result::Error) -> EventStoreError {
+    match error {
+        diesel::result::Error::DatabaseError(
+            DatabaseErrorKind::UniqueViolation
+            | DatabaseErrorKind::NotNullViolation
+            | DatabaseErrorKind::CheckViolation
+            | DatabaseErrorKind::ForeignKeyViolation,
+            info,
+        ) => EventStoreError::ConstraintViolation(info.message().to_string()),
+        diesel::result::Error::DatabaseError(_kind, info)
+            if info.message().starts_with("no such table") =>
+        {
+            EventStoreError::MissingSchema(info.message().to_string())
+        }
+        error => EventStoreError::Database(error.to_string()),
+    }
+}
 
 pub struct PlannableEventsRepository {
     sql_connection: SqliteConnection,
 }
 
 impl PlannableEventsRepository {
-    pub fn initialize(database_url: &str) -> Result<Self, std::io::Error> {
+    pub fn initialize(database_url: &str) -> Result<Self, EventStoreError> {
         Ok(Self {
             sql_connection: establish_connection(database_url)?,
         })
     }
+    // The UNIQUE(plannable_id, sequence) ON CONFLICT ROLLBACK clause makes SQLite end the
+    // transaction itself, diesel's transaction manager would then report its own failed rollback
+    // instead of the violation. So the rows are inserted one by one in a hand-rolled transaction.
     pub fn save(&mut self, eventrow: Vec<PlannableEventRow>) -> Result<(), diesel::result::Error> {
-        insert_into(plannable_events)
-            .values(&eventrow)
+        diesel::sql_query("BEGIN").execute(&mut self.sql_connection)?;
+        for row in &eventrow {
+            if let Err(error) = insert_into(plannable_events)
+                .values(row)
+                .execute(&mut self.sql_connection)
+            {
+                // Already rolled back by SQLite on a conflict, the result does not matter
+                let _ = diesel::sql_query("ROLLBACK").execute(&mut self.sql_connection);
+                return Err(error);
+            }
+        }
+        diesel::sql_query("COMMIT")
             .execute(&mut self.sql_connection)
             .map(|_size| ())
     }
+    // Appends the rows to the stream of id when its current version is expected_version and assigns
+    // their sequences. The version is checked inside an immediate transaction so other connections
+    // to the same database can not interleave. Returns the new version of the stream.
+    pub fn append(
+        &mut self,
+        id: &str,
+        expected_version: i32,
+        eventrow: Vec<PlannableEventRow>,
+    ) -> Result<i32, AppendError> {
+        self.append_streams(vec![(id, expected_version, eventrow)])
+            .map(|versions| versions[0])
+    }
+    // Appends to every stream like append does in one transaction, all of them or none. Returns
+    // the new versions in the order of the streams.
+    pub fn append_streams(
+        &mut self,
+        streams: Vec<(&str, i32, Vec<PlannableEventRow>)>,
+    ) -> Result<Vec<i32>, AppendError> {
+        self.sql_connection.immediate_transaction(|connection| {
+            let mut versions = Vec::new();
+            for (id, expected_version, mut eventrow) in streams {
+                let actual_version = plannable_events
+                    .filter(plannable_id.eq(id))
+                    .select(max(sequence))
+                    .first::<Option<i32>>(connection)?
+                    .map_or(0, |last_sequence| last_sequence + 1);
+                if actual_version != expected_version {
+                    return Err(AppendError::ConcurrencyConflict {
+                        plannable_id: id.to_string(),
+                        expected_version,
+                        actual_version,
+                    });
+                }
+                for (offset, row) in eventrow.iter_mut().enumerate() {
+                    row.sequence = expected_version + offset as i32;
+                }
+                insert_into(plannable_events)
+                    .values(&eventrow)
+                    .execute(connection)?;
+                versions.push(expected_version + eventrow.len() as i32);
+            }
+            Ok(versions)
+        })
+    }
     fn reset(&mut self) -> Result<(), diesel::result::Error> {
         delete(plannable_events)
             .execute(&mut self.sql_connection)
@@ -30,36 +145,101 @@ impl PlannableEventsRepository {
     }
     pub fn drop_table(&mut self) -> Result<(), diesel::result::Error> {
         diesel::sql_query("DROP TABLE IF EXISTS plannable_events")
+            .execute(&mut self.sql_connection)?;
+        diesel::sql_query("DROP TABLE IF EXISTS __diesel_schema_migrations")
             .execute(&mut self.sql_connection)
             .map(|_size| ())
     }
-    pub fn create_table(&mut self) -> Result<(), diesel::result::Error> {
-        diesel::sql_query(
-            "CREATE TABLE plannable_events (
-             event_id BLOB PRIMARY KEY NOT NULL,
-             plannable_id TEXT NOT NULL,
-             sequence INTEGER NOT NULL,
-             body BLOB NOT NULL,
-             UNIQUE(plannable_id, sequence) 
-             ON CONFLICT ROLLBACK)",
-        )
-        .execute(&mut self.sql_connection)
-        .map(|_size| ())
+    // Brings the schema up to date with the migrations embedded from the migrations directory,
+    // diesel records the applied ones in __diesel_schema_migrations
+    pub fn run_migrations(&mut self) -> Result<(), EventStoreError> {
+        self.baseline()?;
+        self.sql_connection
+            .run_pending_migrations(MIGRATIONS)
+            .map(|_versions| ())
+            .map_err(|error| EventStoreError::Migration(error.to_string()))
     }
-    pub fn read(&mut self, id: &String) -> Result<Vec<PlannableEventRow>, diesel::result::Error> {
+    // Databases set up by the hand-written create_table that came before the migrations have the
+    // table but no record of any migration, later versions of it already added the kind and type
+    // columns. The migrations their schema already has are recorded as applied so only the rest run.
+    fn baseline(&mut self) -> Result<(), EventStoreError> {
+        let applied = self
+            .sql_connection
+            .applied_migrations()
+            .map_err(|error| EventStoreError::Migration(error.to_string()))?;
+        if !applied.is_empty()
+            || !self.exists(
+                "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'plannable_events'",
+            )?
+        {
+            return Ok(());
+        }
+        let mut versions = vec![PLANNABLE_EVENTS_VERSION];
+        if self.exists(
+            "SELECT 1 FROM pragma_table_info('plannable_events') WHERE name = 'plannable_kind'",
+        )? {
+            versions.push(PLANNABLE_KIND_VERSION);
+        } else if self.exists(
+            "SELECT 1 FROM plannable_events GROUP BY plannable_id
+             HAVING COUNT(json_extract(CAST(body AS TEXT), '$.type')) = 0",
+        )? {
+            // create_table served the todo and the task store alike, and a plannable with only
+            // untyped rows could be either, so its kind is not guessed
+            return Err(EventStoreError::Migration(String::from(
+                "plannable_events holds plannables stored without a kind that could be todos or tasks",
+            )));
+        }
+        for migration_version in versions {
+            diesel::sql_query("INSERT INTO __diesel_schema_migrations (version) VALUES (?)")
+                .bind::<Text, _>(migration_version)
+                .execute(&mut self.sql_connection)
+                .map_err(|error| EventStoreError::Migration(error.to_string()))?;
+        }
+        Ok(())
+    }
+    fn exists(&mut self, query: &str) -> Result<bool, EventStoreError> {
+        diesel::select(sql::<Bool>(&format!("EXISTS ({})", query)))
+            .get_result(&mut self.sql_connection)
+            .map_err(|error| EventStoreError::Migration(error.to_string()))
+    }
+    pub fn read(
+        &mut self,
+        kind: &str,
+        id: &str,
+    ) -> Result<Vec<PlannableEventRow>, diesel::result::Error> {
         plannable_events
+            .filter(plannable_kind.eq(kind))
             .filter(plannable_id.eq(id))
+            .order(sequence.asc())
             .select(PlannableEventRow::as_select())
             .load(&mut self.sql_connection)
     }
-    pub fn get_all(&mut self) -> Result<Vec<PlannableEventRow>, diesel::result::Error> {
+    pub fn get_all(&mut self, kind: &str) -> Result<Vec<PlannableEventRow>, diesel::result::Error> {
         plannable_events
+            .filter(plannable_kind.eq(kind))
             .select(PlannableEventRow::as_select())
             .load(&mut self.sql_connection)
     }
+    // A range instead of a LIKE so the (plannable_kind, plannable_id) index is used, no text
+    // starting with the prefix sorts after the prefix followed by the largest char
+    pub fn find_ids(
+        &mut self,
+        kind: &str,
+        id_prefix: &str,
+    ) -> Result<Vec<String>, diesel::result::Error> {
+        let upper_bound = format!("{}{}", id_prefix, char::MAX);
+        plannable_events
+            .filter(plannable_kind.eq(kind))
+            .filter(plannable_id.ge(id_prefix))
+            .filter(plannable_id.lt(upper_bound))
+            .select(plannable_id)
+            .distinct()
+            .order(plannable_id.asc())
+            .load(&mut self.sql_connection)
+    }
 }
 
-fn establish_connection(database_url: &str) -> Result<SqliteConnection, Error> {
-    Ok(SqliteConnection::establish(&database_url)
-        .map_err(|_error| Error::new(ErrorKind::Other, "SqliteConnection"))?)
+fn establish_connection(database_url: &str) -> Result<SqliteConnection, EventStoreError> {
+    SqliteConnection::establish(database_url)
+        .map_err(|error| EventStoreError::Connection(error.to_string()))
 }
@@ -8,5 +8,7 @@ diesel::table! {
         plannable_id -> Text,
         sequence -> Integer,
         body -> Binary,
+        plannable_kind -> Text,
+        event_type -> Text,
     }
 }
@@ -0,0 +1,703 @@
+use crate::models::{kind_value, PlannableEventRow};
+use crate::plannable_event_store::{EventStore, PlannableEvent};
+use crate::plannable_events_repository::{
+    map_diesel_error, AppendError, PlannableEventsRepository,
+};
+use entities::agenda_item_events::AgendaItemEvent;
+use entities::chore_events::ChoreEvent;
+use entities::errors::EventStoreError;
+use entities::impediment_events::ImpedimentEvent;
+use entities::issue_events::IssueEvent;
+use entities::plannable::PlannableKind;
+use entities::reminder_events::ReminderEvent;
+use entities::story_events::StoryEvent;
+use entities::task_events::TaskEvent;
+use entities::todo_events::TodoEvent;
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::rc::Rc;
+use uuid::Uuid;
+
+pub type TodoEventStore = SqliteEventStore<TodoEvent>;
+pub type TaskEventStore = SqliteEventStore<TaskEvent>;
+pub type ChoreEventStore = SqliteEventStore<ChoreEvent>;
+pub type AgendaItemEventStore = SqliteEventStore<AgendaItemEvent>;
+pub type ReminderEventStore = SqliteEventStore<ReminderEvent>;
+pub type StoryEventStore = SqliteEventStore<StoryEvent>;
+pub type IssueEventStore = SqliteEventStore<IssueEvent>;
+pub type ImpedimentEventStore = SqliteEventStore<ImpedimentEvent>;
+
+// EventStore on the plannable_events table, every plannable kind shares the table. The stores
+// handed out by share use the same connection.
+pub struct SqliteEventStore<E> {
+    repository: Rc<RefCell<PlannableEventsRepository>>,
+    events: PhantomData<E>,
+}
+
+impl<E: PlannableEvent> SqliteEventStore<E> {
+    // Opens the database and applies any pending migrations, existing events are kept
+    pub fn new(database_url: &str) -> Result<Self, EventStoreError> {
+        let mut repository = PlannableEventsRepository::initialize(database_url)?;
+        repository.run_migrations()?;
+        Ok(Self {
+            repository: Rc::new(RefCell::new(repository)),
+            events: PhantomData,
+        })
+    }
+
+    // A store for another kind on the same connection, the migrations already ran
+    pub fn share<F: PlannableEvent>(&self) -> SqliteEventStore<F> {
+        SqliteEventStore {
+            repository: Rc::clone(&self.repository),
+            events: PhantomData,
+        }
+    }
+
+    // Like find_ids but for the ids of any kind
+    pub fn find_plannable_ids(
+        &mut self,
+        kind: PlannableKind,
+        id_prefix: &str,
+    ) -> Result<Vec<Uuid>, EventStoreError> {
+        self.find_kind_ids(kind_value(kind), id_prefix)
+    }
+
+    fn find_kind_ids(&mut self, kind: &str, id_prefix: &str) -> Result<Vec<Uuid>, EventStoreError> {
+        let ids = self
+            .repository
+            .borrow_mut()
+            .find_ids(kind, id_prefix)
+            .map_err(map_diesel_error)?;
+
+        ids.iter()
+            .map(|id| {
+                Uuid::parse_str(id).map_err(|error| {
+                    EventStoreError::Database(format!("Stored id {} is not a uuid: {}", id, error))
+                })
+            })
+            .collect()
+    }
+
+    // Appends to the stream of plannable_id like append and, in the same transaction, to the
+    // stream of other_id, which may be of another kind. Either both are stored or neither is.
+    // Returns the new versions of both streams.
+    pub fn append_with<F: PlannableEvent>(
+        &mut self,
+        plannable_id: Uuid,
+        expected_version: i32,
+        events: Vec<E>,
+        other_id: Uuid,
+        other_expected_version: i32,
+        other_events: Vec<F>,
+    ) -> Result<(i32, i32), EventStoreError> {
+        let (plannable_id, other_id) = (plannable_id.to_string(), other_id.to_string());
+        let rows = stream_rows(&plannable_id, events)?;
+        let other_rows = stream_rows(&other_id, other_events)?;
+        let versions = self
+            .repository
+            .borrow_mut()
+            .append_streams(vec![
+                (&plannable_id, expected_version, rows),
+                (&other_id, other_expected_version, other_rows),
+            ])
+            .map_err(AppendError::into_event_store_error)?;
+        Ok((versions[0], versions[1]))
+    }
+
+    //#[cfg(test)]
+    pub fn clean(database_url: &str) -> Result<Self, EventStoreError> {
+        let mut repository = PlannableEventsRepository::initialize(database_url)?;
+        repository.drop_table().map_err(map_diesel_error)?;
+        repository.run_migrations()?;
+        Ok(Self {
+            repository: Rc::new(RefCell::new(repository)),
+            events: PhantomData,
+        })
+    }
+}
+
+impl<E: PlannableEvent> EventStore<E> for SqliteEventStore<E> {
+    fn save(&mut self, events: Vec<E>) -> Result<(), EventStoreError> {
+        let rows: Vec<PlannableEventRow> = events.into_iter().map(|event| event.into()).collect();
+        self.repository
+            .borrow_mut()
+            .save(rows)
+            .map_err(map_diesel_error)
+    }
+
+    fn append(
+        &mut self,
+        plannable_id: Uuid,
+        expected_version: i32,
+        events: Vec<E>,
+    ) -> Result<i32, EventStoreError> {
+        let plannable_id = plannable_id.to_string();
+        let rows = stream_rows(&plannable_id, events)?;
+        self.repository
+            .borrow_mut()
+            .append(&plannable_id, expected_version, rows)
+            .map_err(AppendError::into_event_store_error)
+    }
+
+    fn read(&mut self, plannable_id: Uuid) -> Result<Vec<E>, EventStoreError> {
+        let rows = self
+            .repository
+            .borrow_mut()
+            .read(E::KIND, &plannable_id.to_string())
+            .map_err(map_diesel_error)?;
+
+        rows.into_iter().map(|row| row.try_into()).collect()
+    }
+
+    fn get_all(&mut self) -> Result<Vec<E>, EventStoreError> {
+        let rows = self
+            .repository
+            .borrow_mut()
+            .get_all(E::KIND)
+            .map_err(map_diesel_error)?;
+
+        rows.into_iter().map(|row| row.try_into()).collect()
+    }
+
+    fn find_ids(&mut self, id_prefix: &str) -> Result<Vec<Uuid>, EventStoreError> {
+        self.find_kind_ids(E::KIND, id_prefix)
+    }
+}
+
+// Encodes the events of one stream, refusing any that belongs to another
+fn stream_rows<E: PlannableEvent>(
+    plannable_id: &str,
+    events: Vec<E>,
+) -> Result<Vec<PlannableEventRow>, EventStoreError> {
+    let rows: Vec<PlannableEventRow> = events.into_iter().map(|event| event.into()).collect();
+    if rows.iter().any(|row| row.plannable_id != plannable_id) {
+        return Err(EventStoreError::ForeignEvent {
+            kind: String::from(E::KIND),
+            plannable_id: plannable_id.to_string(),
+        });
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::models::{PlannableEventRow, REMINDER_KIND, TODO_KIND};
+    use crate::plannable_event_store::EventStore;
+    use crate::sqlite_event_store::{ReminderEventStore, TaskEventStore, TodoEventStore};
+    use chrono::{NaiveDate, NaiveDateTime};
+    use diesel::{Connection, RunQueryDsl, SqliteConnection};
+    use entities::errors::EventStoreError;
+    use entities::plannable::PlannableKind;
+    use entities::reminder_events::{ReminderAcknowledgedEvent, ReminderEvent};
+    use entities::task_events::{TaskCreatedEvent, TaskEvent};
+    use entities::todo_events::{
+        TodoCompletedEvent, TodoCreatedEvent, TodoEndDateChangedEvent, TodoEvent, TodoRemovedEvent,
+        TodoReopenedEvent, TodoTitleChangedEvent,
+    };
+    use uuid::Uuid;
+
+    #[test]
+    fn given_todolifecycle_when_read_then_returneveryeventinsequenceorder() {
+        let database_url = "/tmp/simple_plan_readtodolifecycle.db";
+        let mut eventstore = TodoEventStore::clean(database_url).unwrap();
+        let todo_id = Uuid::new_v4();
+        let lifecycle = vec![
+            TodoEvent::Created(TodoCreatedEvent {
+                event_id: Uuid::new_v4(),
+                todo_id,
+                sequence: 0,
+                title: String::from("Buy rust book"),
+                end_date: None,
+                converted_from: None,
+            }),
+            TodoEvent::TitleChanged(TodoTitleChangedEvent {
+                event_id: Uuid::new_v4(),
+                todo_id,
+                sequence: 1,
+                title: String::from("Read rust book"),
+            }),
+            TodoEvent::EndDateChanged(TodoEndDateChangedEvent {
+                event_id: Uuid::new_v4(),
+                todo_id,
+                sequence: 2,
+                end_date: Some(
+                    NaiveDate::from_ymd_opt(2023, 10, 1)
+                        .unwrap()
+                        .and_hms_opt(12, 0, 0)
+                        .unwrap(),
+                ),
+            }),
+            TodoEvent::Completed(TodoCompletedEvent {
+                event_id: Uuid::new_v4(),
+                todo_id,
+                sequence: 3,
+            }),
+            TodoEvent::Reopened(TodoReopenedEvent {
+                event_id: Uuid::new_v4(),
+                todo_id,
+                sequence: 4,
+            }),
+            TodoEvent::Removed(TodoRemovedEvent {
+                event_id: Uuid::new_v4(),
+                todo_id,
+                sequence: 5,
+            }),
+        ];
+        //Save out of order to make sure read sorts by sequence
+        let mut shuffled = lifecycle.clone();
+        shuffled.reverse();
+        eventstore.save(shuffled).unwrap();
+        let result = eventstore.read(todo_id).unwrap();
+        assert_eq!(result, lifecycle);
+    }
+
+    #[test]
+    fn given_duplicatesequence_when_save_then_constraintviolation() {
+        let database_url = "/tmp/simple_plan_savetodoconstraint.db";
+        let mut eventstore = TodoEventStore::clean(database_url).unwrap();
+        let todo_id = Uuid::new_v4();
+        let completed = |sequence| {
+            TodoEvent::Completed(TodoCompletedEvent {
+                event_id: Uuid::new_v4(),
+                todo_id,
+                sequence,
+            })
+        };
+        let result = eventstore.save(vec![completed(0), completed(0)]);
+        assert!(matches!(
+            result,
+            Err(EventStoreError::ConstraintViolation(_))
+        ));
+    }
+
+    #[test]
+    fn given_droppedtable_when_getall_then_missingschema() {
+        let database_url = "/tmp/simple_plan_todomissingschema.db";
+        let mut eventstore = TodoEventStore::clean(database_url).unwrap();
+        eventstore.repository.borrow_mut().drop_table().unwrap();
+        let result = eventstore.get_all();
+        assert!(matches!(result, Err(EventStoreError::MissingSchema(_))));
+    }
+
+    #[test]
+    fn given_freshdatabase_when_new_then_schemacreated() {
+        let database_url = "/tmp/simple_plan_todofreshdatabase.db";
+        let _ = std::fs::remove_file(database_url);
+        let mut eventstore = TodoEventStore::new(database_url).unwrap();
+        assert_eq!(eventstore.get_all().unwrap(), vec![]);
+    }
+
+    #[test]
+    fn given_sharedstore_when_appended_then_foundthroughtheotherkind() {
+        let database_url = "/tmp/simple_plan_sharedstore.db";
+        let mut todo_event_store = TodoEventStore::clean(database_url).unwrap();
+        let mut task_event_store: TaskEventStore = todo_event_store.share();
+        let task_id = Uuid::new_v4();
+        task_event_store
+            .append(
+                task_id,
+                0,
+                vec![TaskEvent::Created(TaskCreatedEvent {
+                    event_id: Uuid::new_v4(),
+                    task_id,
+                    sequence: 0,
+                    title: String::from("Complete your homework"),
+                    end_date: None,
+                    converted_from: None,
+                })],
+            )
+            .unwrap();
+        let id_prefix = &task_id.to_string()[..8];
+        assert_eq!(
+            todo_event_store
+                .find_plannable_ids(PlannableKind::Task, id_prefix)
+                .unwrap(),
+            vec![task_id]
+        );
+        assert!(todo_event_store.find_ids(id_prefix).unwrap().is_empty());
+    }
+
+    // Sets up a database the way an older version left it
+    fn create_database(database_url: &str, statements: &[&str]) {
+        let _ = std::fs::remove_file(database_url);
+        let mut connection = SqliteConnection::establish(database_url).unwrap();
+        for statement in statements {
+            diesel::sql_query(*statement)
+                .execute(&mut connection)
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn given_2023database_when_new_then_migratedwithoutlosingevents() {
+        //GIVEN a database migrated by the diesel cli in 2023 holding one todo
+        let database_url = "/tmp/simple_plan_todo2023database.db";
+        create_database(
+            database_url,
+            &[
+                "CREATE TABLE __diesel_schema_migrations (
+                    version VARCHAR(50) PRIMARY KEY NOT NULL,
+                    run_on TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP)",
+                "INSERT INTO __diesel_schema_migrations (version) VALUES ('20230805162827')",
+                include_str!("../migrations/2023-08-05-162827_plannable_events/up.sql"),
+                "INSERT INTO plannable_events VALUES (
+                    'f7a4bd9f-5b0c-4a8e-9f59-2b7d4fbd2a10',
+                    '0c6e4a2b-7d3c-4a8f-b9a1-5e2f6c7d8e90',
+                    0,
+                    '{\"title\":\"Read rust book\",\"end_date\":null}')",
+            ],
+        );
+
+        //WHEN opened twice, as two runs of the cli would
+        TodoEventStore::new(database_url).unwrap();
+        let mut eventstore = TodoEventStore::new(database_url).unwrap();
+
+        //THEN the 2023 todo is still there and new ones can be added
+        let todo_id = Uuid::parse_str("0c6e4a2b-7d3c-4a8f-b9a1-5e2f6c7d8e90").unwrap();
+        assert_eq!(
+            eventstore.read(todo_id).unwrap(),
+            vec![TodoEvent::Created(TodoCreatedEvent {
+                event_id: Uuid::parse_str("f7a4bd9f-5b0c-4a8e-9f59-2b7d4fbd2a10").unwrap(),
+                todo_id,
+                sequence: 0,
+                title: String::from("Read rust book"),
+                end_date: None,
+                converted_from: None,
+            })]
+        );
+        let version = eventstore
+            .append(
+                todo_id,
+                1,
+                vec![TodoEvent::Completed(TodoCompletedEvent {
+                    event_id: Uuid::new_v4(),
+                    todo_id,
+                    sequence: 1,
+                })],
+            )
+            .unwrap();
+        assert_eq!(version, 2);
+    }
+
+    #[test]
+    fn given_createtabledatabase_when_new_then_baselinedandmigrated() {
+        //GIVEN a database set up by the old create_table, without any record of migrations
+        let database_url = "/tmp/simple_plan_todocreatetabledatabase.db";
+        create_database(
+            database_url,
+            &[
+                include_str!("../migrations/2023-08-05-162827_plannable_events/up.sql"),
+                "INSERT INTO plannable_events VALUES (
+                    'f7a4bd9f-5b0c-4a8e-9f59-2b7d4fbd2a10',
+                    '0c6e4a2b-7d3c-4a8f-b9a1-5e2f6c7d8e90',
+                    0,
+                    '{\"type\":\"Created\",\"title\":\"Read rust book\",\"end_date\":null}')",
+            ],
+        );
+
+        //WHEN opened twice, as two runs of the cli would
+        TodoEventStore::new(database_url).unwrap();
+        let mut eventstore = TodoEventStore::new(database_url).unwrap();
+
+        //THEN the table is kept and only the kind columns were added
+        let todo_id = Uuid::parse_str("0c6e4a2b-7d3c-4a8f-b9a1-5e2f6c7d8e90").unwrap();
+        assert_eq!(eventstore.read(todo_id).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn given_createtabledatabasewithuntypedtask_when_new_then_migrationrefused() {
+        //GIVEN a database set up by the old create_table holding a task as it was stored then
+        let database_url = "/tmp/simple_plan_taskcreatetabledatabase.db";
+        create_database(
+            database_url,
+            &[
+                include_str!("../migrations/2023-08-05-162827_plannable_events/up.sql"),
+                "INSERT INTO plannable_events VALUES (
+                    'f7a4bd9f-5b0c-4a8e-9f59-2b7d4fbd2a10',
+                    '0c6e4a2b-7d3c-4a8f-b9a1-5e2f6c7d8e90',
+                    0,
+                    '{\"title\":\"Complete your homework\",\"end_date\":null}')",
+            ],
+        );
+
+        //WHEN
+        let result = TaskEventStore::new(database_url);
+
+        //THEN it is not taken for a todo, the database is left as it was
+        assert!(matches!(result, Err(EventStoreError::Migration(_))));
+        assert!(matches!(
+            TaskEventStore::new(database_url),
+            Err(EventStoreError::Migration(_))
+        ));
+    }
+
+    #[test]
+    fn given_createtabledatabasewithkinds_when_new_then_nocolumnaddedtwice() {
+        //GIVEN a database set up by the create_table that already added the kind columns
+        let database_url = "/tmp/simple_plan_taskcreatetablewithkinds.db";
+        create_database(
+            database_url,
+            &[
+                "CREATE TABLE plannable_events (
+                    event_id BLOB PRIMARY KEY NOT NULL,
+                    plannable_id TEXT NOT NULL,
+                    sequence INTEGER NOT NULL,
+                    body BLOB NOT NULL,
+                    plannable_kind TEXT NOT NULL DEFAULT 'todo',
+                    event_type TEXT NOT NULL DEFAULT 'Created',
+                    UNIQUE(plannable_id, sequence)
+                    ON CONFLICT ROLLBACK)",
+                "CREATE INDEX plannable_events_kind ON plannable_events (plannable_kind, plannable_id)",
+                "INSERT INTO plannable_events VALUES (
+                    'f7a4bd9f-5b0c-4a8e-9f59-2b7d4fbd2a10',
+                    '0c6e4a2b-7d3c-4a8f-b9a1-5e2f6c7d8e90',
+                    0,
+                    '{\"title\":\"Complete your homework\",\"end_date\":null}',
+                    'task',
+                    'Created')",
+            ],
+        );
+
+        //WHEN
+        let mut eventstore = TaskEventStore::new(database_url).unwrap();
+
+        //THEN the task is still a task
+        let task_id = Uuid::parse_str("0c6e4a2b-7d3c-4a8f-b9a1-5e2f6c7d8e90").unwrap();
+        assert_eq!(
+            eventstore.read(task_id).unwrap(),
+            vec![TaskEvent::Created(TaskCreatedEvent {
+                event_id: Uuid::parse_str("f7a4bd9f-5b0c-4a8e-9f59-2b7d4fbd2a10").unwrap(),
+                task_id,
+                sequence: 0,
+                title: String::from("Complete your homework"),
+                end_date: None,
+                converted_from: None,
+            })]
+        );
+    }
+
+    #[test]
+    fn given_corruptrow_when_read_then_corruptbody() {
+        let database_url = "/tmp/simple_plan_readcorrupttodo.db";
+        let mut eventstore = TodoEventStore::clean(database_url).unwrap();
+        let todo_id = Uuid::new_v4();
+        let event_id = Uuid::new_v4();
+        eventstore
+            .repository
+            .borrow_mut()
+            .save(vec![PlannableEventRow {
+                event_id: event_id.to_string().into(),
+                plannable_id: todo_id.to_string(),
+                sequence: 0,
+                body: b"{\"title\": ".to_vec(),
+                plannable_kind: String::from(TODO_KIND),
+                event_type: String::from("Created"),
+            }])
+            .unwrap();
+        let result = eventstore.read(todo_id);
+        assert!(matches!(
+            result,
+            Err(EventStoreError::CorruptBody { event_id: id, .. }) if id == event_id.to_string()
+        ));
+    }
+
+    #[test]
+    fn given_rowfromnewerversion_when_read_then_unknowneventtype() {
+        let database_url = "/tmp/simple_plan_readunknowntodo.db";
+        let mut eventstore = TodoEventStore::clean(database_url).unwrap();
+        let todo_id = Uuid::new_v4();
+        let event_id = Uuid::new_v4();
+        eventstore
+            .repository
+            .borrow_mut()
+            .save(vec![PlannableEventRow {
+                event_id: event_id.to_string().into(),
+                plannable_id: todo_id.to_string(),
+                sequence: 0,
+                body: b"{}".to_vec(),
+                plannable_kind: String::from(TODO_KIND),
+                event_type: String::from("Archived"),
+            }])
+            .unwrap();
+        let result = eventstore.read(todo_id);
+        assert_eq!(
+            result,
+            Err(EventStoreError::UnknownEventType {
+                event_id: event_id.to_string(),
+                event_type: String::from("Archived"),
+            })
+        );
+    }
+
+    #[test]
+    fn given_todosandtasksinonedatabase_when_read_then_onlytodos() {
+        let database_url = "/tmp/simple_plan_todokindfilter.db";
+        let mut eventstore = TodoEventStore::clean(database_url).unwrap();
+        let mut task_eventstore = TaskEventStore::new(database_url).unwrap();
+        let task_id = Uuid::new_v4();
+        task_eventstore
+            .save(vec![TaskEvent::Created(TaskCreatedEvent {
+                event_id: Uuid::new_v4(),
+                task_id,
+                sequence: 0,
+                title: String::from("Complete your homework"),
+                end_date: None,
+                converted_from: None,
+            })])
+            .unwrap();
+        let todos = vec![TodoEvent::Created(TodoCreatedEvent {
+            event_id: Uuid::new_v4(),
+            todo_id: Uuid::new_v4(),
+            sequence: 0,
+            title: String::from("Read all the LOTR books"),
+            end_date: None,
+            converted_from: None,
+        })];
+        eventstore.save(todos.clone()).unwrap();
+
+        assert_eq!(eventstore.get_all().unwrap(), todos);
+        assert_eq!(eventstore.read(task_id).unwrap(), vec![]);
+        assert_eq!(task_eventstore.get_all().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn given_stalesecondstream_when_appendwith_then_neitherstored() {
+        let database_url = "/tmp/simple_plan_todoappendwith.db";
+        let mut eventstore = TodoEventStore::clean(database_url).unwrap();
+        let mut task_eventstore = TaskEventStore::new(database_url).unwrap();
+        let todo_id = Uuid::new_v4();
+        let todo = vec![TodoEvent::Created(TodoCreatedEvent {
+            event_id: Uuid::new_v4(),
+            todo_id,
+            sequence: 0,
+            title: String::from("Complete your homework"),
+            end_date: None,
+            converted_from: None,
+        })];
+        let task = |task_id| {
+            vec![TaskEvent::Created(TaskCreatedEvent {
+                event_id: Uuid::new_v4(),
+                task_id,
+                sequence: 0,
+                title: String::from("Complete your homework"),
+                end_date: None,
+                converted_from: None,
+            })]
+        };
+        let stale_id = Uuid::new_v4();
+        task_eventstore.save(task(stale_id)).unwrap();
+
+        //The task stream is no longer new, so the todo is not stored either
+        let result = eventstore.append_with(todo_id, 0, todo.clone(), stale_id, 0, task(stale_id));
+        assert_eq!(
+            result,
+            Err(EventStoreError::ConcurrencyConflict {
+                plannable_id: stale_id.to_string(),
+                expected_version: 0,
+                actual_version: 1,
+            })
+        );
+        assert_eq!(eventstore.read(todo_id).unwrap(), vec![]);
+
+        //At the right versions both are stored, each readable by the store of its kind
+        let task_id = Uuid::new_v4();
+        let result = eventstore.append_with(todo_id, 0, todo, task_id, 0, task(task_id));
+        assert_eq!(result, Ok((1, 1)));
+        assert_eq!(eventstore.read(todo_id).unwrap().len(), 1);
+        assert_eq!(task_eventstore.read(task_id).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn given_2023layoutbody_when_read_then_upcasttocurrentevent() {
+        //GIVEN a row as written in 2023, without a schema version, and a current one
+        let database_url = "/tmp/simple_plan_read2023todo.db";
+        let mut eventstore = TodoEventStore::clean(database_url).unwrap();
+        let todo_id = Uuid::new_v4();
+        let event_id = Uuid::new_v4();
+        eventstore
+            .repository
+            .borrow_mut()
+            .save(vec![PlannableEventRow {
+                event_id: event_id.to_string().into(),
+                plannable_id: todo_id.to_string(),
+                sequence: 0,
+                body: br#"{"title":"Read rust book","end_date":"2023-09-29T09:10:11"}"#.to_vec(),
+                plannable_kind: String::from(TODO_KIND),
+                event_type: String::from("Created"),
+            }])
+            .unwrap();
+        let completed = TodoEvent::Completed(TodoCompletedEvent {
+            event_id: Uuid::new_v4(),
+            todo_id,
+            sequence: 1,
+        });
+        eventstore.save(vec![completed.clone()]).unwrap();
+
+        //WHEN read
+        let result = eventstore.read(todo_id).unwrap();
+
+        //THEN both decode and every new body carries its schema version
+        assert_eq!(
+            result,
+            vec![
+                TodoEvent::Created(TodoCreatedEvent {
+                    event_id,
+                    todo_id,
+                    sequence: 0,
+                    title: String::from("Read rust book"),
+                    end_date: Some(
+                        NaiveDate::from_ymd_opt(2023, 9, 29)
+                            .unwrap()
+                            .and_hms_opt(9, 10, 11)
+                            .unwrap(),
+                    ),
+                    converted_from: None,
+                }),
+                completed,
+            ]
+        );
+        let rows = eventstore
+            .repository
+            .borrow_mut()
+            .read(TODO_KIND, &todo_id.to_string())
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&rows[1].body).unwrap();
+        assert_eq!(body["schema_version"], 2);
+    }
+
+    #[test]
+    fn given_acknowledgementwithouttime_when_read_then_acknowledgedatfirstdate() {
+        //GIVEN an acknowledgement stored before the time was recorded
+        let database_url = "/tmp/simple_plan_readuntimedacknowledgement.db";
+        let mut eventstore = ReminderEventStore::clean(database_url).unwrap();
+        let reminder_id = Uuid::new_v4();
+        let event_id = Uuid::new_v4();
+        eventstore
+            .repository
+            .borrow_mut()
+            .save(vec![PlannableEventRow {
+                event_id: event_id.to_string().into(),
+                plannable_id: reminder_id.to_string(),
+                sequence: 1,
+                body: br#"{"schema_version":1}"#.to_vec(),
+                plannable_kind: String::from(REMINDER_KIND),
+                event_type: String::from("Acknowledged"),
+            }])
+            .unwrap();
+
+        //WHEN read
+        let result = eventstore.read(reminder_id).unwrap();
+
+        //THEN it decodes and only moves the reminder on by one occurrence
+        assert_eq!(
+            result,
+            vec![ReminderEvent::Acknowledged(ReminderAcknowledgedEvent {
+                event_id,
+                reminder_id,
+                sequence: 1,
+                acknowledged_at: NaiveDateTime::MIN,
+            })]
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use crate::models::TODO_KIND;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+// Every stored body carries the schema version it was written with in this field. Bodies from
+// before versioning, the 2023 layout and the tagged todo bodies that followed it, have no such
+// field and count as version 1.
+pub const SCHEMA_VERSION_FIELD: &str = "schema_version";
+
+// Migrates a body from one schema version to the next
+pub type Upcaster = fn(Value) -> Value;
+
+#[derive(Default)]
+pub struct Upcasters {
+    upcasters: HashMap<(String, String, u64), Upcaster>,
+    current_versions: HashMap<(String, String), u64>,
+}
+
+impl Upcasters {
+    // Registers the migration of a body of plannable_kind/event_type from from_version to
+    // from_version + 1, which then becomes the current version if it is the highest one so far
+    pub fn register(
+        mut self,
+        plannable_kind: &str,
+        event_type: &str,
+        from_version: u64,
+        upcaster: Upcaster,
+    ) -> Self {
+        let key = (plannable_kind.to_string(), event_type.to_string());
+        let current_version = self.current_versions.entry(key).or_insert(1);
+        *current_version = (*current_version).max(from_version + 1);
+        self.upcasters.insert(
+            (
+                plannable_kind.to_string(),
+                event_type.to_string(),
+                from_version,
+            ),
+            upcaster,
+        );
+        self
+    }
+
+    pub fn current_version(&self, plannable_kind: &str, event_type: &str) -> u64 {
+        self.current_versions
+            .get(&(plannable_kind.to_string(), event_type.to_string()))
+            .copied()
+            .unwrap_or(1)
+    }
+
+    // Applies the upcasters one version at a time until the body has the current shape
+    pub fn upcast(
+        &self,
+        plannable_kind: &str,
+        event_type: &str,
+        mut body: Value,
+    ) -> Result<Value, String> {
+        let current_version = self.current_version(plannable_kind, event_type);
+        let mut version = schema_version(&body)?;
+        if version > current_version {
+            return Err(format!(
+                "schema version {} is newer than the supported version {}",
+                version, current_version
+            ));
+        }
+        while version < current_version {
+            let upcaster = self
+                .upcasters
+                .get(&(plannable_kind.to_string(), event_type.to_string(), version))
+                .ok_or(format!("no upcaster from schema version {}", version))?;
+            body = upcaster(body);
+            version += 1;
+        }
+        set_schema_version(&mut body, version)?;
+        Ok(body)
+    }
+}
+
+fn schema_version(body: &Value) -> Result<u64, String> {
+    match body.get(SCHEMA_VERSION_FIELD) {
+        None => Ok(1),
+        Some(version) => version
+            .as_u64()
+            .ok_or(format!("invalid schema version {}", version)),
+    }
+}
+
+pub fn set_schema_version(body: &mut Value, version: u64) -> Result<(), String> {
+    body.as_object_mut()
+        .ok_or(String::from("body is not a JSON object"))?
+        .insert(SCHEMA_VERSION_FIELD.to_string(), Value::from(version));
+    Ok(())
+}
+
+// The upcasters of every body that changed shape since it was first stored. Register new ones
+// here whenever a body gets a field renamed, added without a default, or removed.
+pub fn registry() -> &'static Upcasters {
+    static REGISTRY: OnceLock<Upcasters> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        TAGGED_TODO_EVENT_TYPES
+            .iter()
+            .fold(Upcasters::default(), |upcasters, event_type| {
+                upcasters.register(TODO_KIND, event_type, 1, remove_type_tag)
+            })
+    })
+}
+
+// The todo events that were stored before the event type got a column of its own
+const TAGGED_TODO_EVENT_TYPES: [&str; 6] = [
+    "Created",
+    "TitleChanged",
+    "EndDateChanged",
+    "Completed",
+    "Reopened",
+    "Removed",
+];
+
+// Those bodies were tagged with the event type in a type field, which is now in the event_type
+// column. Bodies from 2023 were not tagged yet and are left as they are.
+fn remove_type_tag(mut body: Value) -> Value {
+    if let Some(fields) = body.as_object_mut() {
+        fields.remove("type");
+    }
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{registry, Upcasters};
+    use serde_json::json;
+
+    fn test_upcasters() -> Upcasters {
+        Upcasters::default()
+            .register("todo", "Created", 1, |mut body| {
+                let title = body.as_object_mut().unwrap().remove("title").unwrap();
+                body["name"] = title;
+                body
+            })
+            .register("todo", "Created", 2, |mut body| {
+                body["tags"] = json!([]);
+                body
+            })
+    }
+
+    #[test]
+    fn given_unversionedbody_when_upcast_then_everyupcasterapplied() {
+        let upcasters = test_upcasters();
+        let body = json!({"title": "Read rust book", "end_date": null});
+        let result = upcasters.upcast("todo", "Created", body).unwrap();
+        assert_eq!(
+            result,
+            json!({
+                "name": "Read rust book",
+                "end_date": null,
+                "tags": [],
+                "schema_version": 3
+            })
+        );
+    }
+
+    #[test]
+    fn given_currentbody_when_upcast_then_unchanged() {
+        let upcasters = test_upcasters();
+        let body = json!({"name": "Read rust book", "tags": ["books"], "schema_version": 3});
+        let result = upcasters.upcast("todo", "Created", body.clone()).unwrap();
+        assert_eq!(result, body);
+    }
+
+    #[test]
+    fn given_bodyfromnewerversion_when_upcast_then_error() {
+        let upcasters = test_upcasters();
+        let body = json!({"name": "Read rust book", "schema_version": 4});
+        assert!(upcasters.upcast("todo", "Created", body).is_err());
+    }
+
+    #[test]
+    fn given_otherkind_when_currentversion_then_one() {
+        let upcasters = test_upcasters();
+        assert_eq!(upcasters.current_version("task", "Created"), 1);
+        assert_eq!(upcasters.current_version("todo", "Created"), 3);
+    }
+
+    #[test]
+    fn given_taggedtodobody_when_upcastwithregistry_then_tagremoved() {
+        let body = json!({"type": "TitleChanged", "title": "Read rust book"});
+        let result = registry().upcast("todo", "TitleChanged", body).unwrap();
+        assert_eq!(
+            result,
+            json!({"title": "Read rust book", "schema_version": 2})
+        );
+
+        //A 2023 body was never tagged, a task body was never versioned
+        let body = json!({"title": "Read rust book", "end_date": null});
+        let result = registry().upcast("todo", "Created", body).unwrap();
+        assert_eq!(
+            result,
+            json!({"title": "Read rust book", "end_date": null, "schema_version": 2})
+        );
+        let body = json!({"title": "Complete your homework", "end_date": null});
+        let result = registry().upcast("task", "Created", body).unwrap();
+        assert_eq!(
+            result,
+            json!({"title": "Complete your homework", "end_date": null, "schema_version": 1})
+        );
+    }
+}
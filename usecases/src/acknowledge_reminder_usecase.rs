@@ -0,0 +1,169 @@
+use crate::clock::Clock;
+use crate::id_generator::IdGenerator;
+use entities::errors::{EventStoreError, ReminderError};
+use entities::reminder::Reminder;
+use entities::reminder_events::{ReminderAcknowledgedEvent, ReminderEvent, ReminderId};
+use uuid::Uuid;
+
+pub struct AcknowledgeReminderUsecase<'a> {
+    change_reminder_events: &'a mut dyn ChangeReminderEvents,
+    clock: &'a dyn Clock,
+    id_generator: &'a mut dyn IdGenerator,
+}
+
+impl<'a> AcknowledgeReminderUsecase<'a> {
+    // Marks the current notification as seen. A recurring reminder moves on to its first trigger
+    // time after now, the notifications missed in between are not repeated.
+    pub fn execute(self, reminder_id: ReminderId) -> Result<Reminder, ReminderError> {
+        let events = self.change_reminder_events.read(reminder_id)?;
+        let mut reminder = Reminder::from_events(&events)
+            .ok_or_else(|| ReminderError::NotFound(reminder_id.to_string()))?;
+        if reminder.acknowledged {
+            return Err(ReminderError::Acknowledged(reminder_id.to_string()));
+        }
+        let acknowledged = vec![ReminderEvent::Acknowledged(ReminderAcknowledgedEvent {
+            reminder_id,
+            event_id: self.id_generator.next_id(),
+            sequence: reminder.version,
+            acknowledged_at: self.clock.now(),
+        })];
+        self.change_reminder_events
+            .append(reminder_id, reminder.version, acknowledged.clone())?;
+        acknowledged.iter().for_each(|event| reminder.apply(event));
+        Ok(reminder)
+    }
+    pub fn new(
+        change_reminder_events: &'a mut dyn ChangeReminderEvents,
+        clock: &'a dyn Clock,
+        id_generator: &'a mut dyn IdGenerator,
+    ) -> Self {
+        Self {
+            change_reminder_events,
+            clock,
+            id_generator,
+        }
+    }
+}
+
+// Port of the usecases that change a single existing reminder
+pub trait ChangeReminderEvents {
+    fn read(&mut self, reminder_id: Uuid) -> Result<Vec<ReminderEvent>, EventStoreError>;
+
+    // Appends to the reminder stream when it is still at expected_version, returns the new version
+    fn append(
+        &mut self,
+        reminder_id: Uuid,
+        expected_version: i32,
+        reminder_events: Vec<ReminderEvent>,
+    ) -> Result<i32, EventStoreError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AcknowledgeReminderUsecase;
+    use crate::acknowledge_reminder_usecase::ChangeReminderEvents;
+    use crate::clock::FixedClock;
+    use crate::id_generator::SequentialIdGenerator;
+    use chrono::{NaiveDate, NaiveDateTime};
+    use entities::errors::{EventStoreError, ReminderError};
+    use entities::recurrence::Recurrence;
+    use entities::reminder_events::{
+        ReminderAcknowledgedEvent, ReminderCreatedEvent, ReminderEvent,
+    };
+    use event_store::in_memory_event_store::InMemoryEventStore;
+    use event_store::plannable_event_store::EventStore;
+    use uuid::Uuid;
+
+    //Used for testing to call store functions
+    struct TestEventStore {
+        real_event_store: InMemoryEventStore<ReminderEvent>,
+    }
+
+    impl TestEventStore {
+        fn with_reminder(reminder_id: Uuid, recurrence: Option<Recurrence>) -> Self {
+            let mut real_event_store = InMemoryEventStore::new();
+            real_event_store
+                .save(vec![ReminderEvent::Created(ReminderCreatedEvent {
+                    title: String::from("Put the bins out"),
+                    reminder_id,
+                    event_id: Uuid::new_v4(),
+                    sequence: 0,
+                    trigger_at: date(1),
+                    recurrence,
+                })])
+                .unwrap();
+            Self { real_event_store }
+        }
+    }
+
+    impl ChangeReminderEvents for TestEventStore {
+        fn read(&mut self, reminder_id: Uuid) -> Result<Vec<ReminderEvent>, EventStoreError> {
+            self.real_event_store.read(reminder_id)
+        }
+        fn append(
+            &mut self,
+            reminder_id: Uuid,
+            expected_version: i32,
+            reminder_events: Vec<ReminderEvent>,
+        ) -> Result<i32, EventStoreError> {
+            self.real_event_store
+                .append(reminder_id, expected_version, reminder_events)
+        }
+    }
+
+    fn date(day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, 9, day)
+            .unwrap()
+            .and_hms_opt(19, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn given_weeklyreminderlagging_when_acknowledged_then_firstoccurrenceafternow() {
+        //GIVEN a weekly reminder from Sep 1 that nobody acknowledged on Sep 8 and Sep 15
+        let reminder_id = Uuid::new_v4();
+        let mut eventstore = TestEventStore::with_reminder(reminder_id, Some(Recurrence::Weeks(1)));
+
+        //WHEN acknowledged on Sep 20
+        let reminder = AcknowledgeReminderUsecase::new(
+            &mut eventstore,
+            &FixedClock(date(20)),
+            &mut SequentialIdGenerator::new(),
+        )
+        .execute(reminder_id)
+        .unwrap();
+
+        //THEN it is not due again before Sep 22
+        assert_eq!(reminder.trigger_at, date(22));
+        assert!(!reminder.is_due(date(21)));
+        let events = eventstore.real_event_store.read(reminder_id).unwrap();
+        assert!(matches!(
+            &events[1],
+            ReminderEvent::Acknowledged(ReminderAcknowledgedEvent { acknowledged_at, .. })
+                if *acknowledged_at == date(20)
+        ));
+    }
+
+    #[test]
+    fn given_acknowledgedoneoffreminder_when_acknowledged_then_alreadyacknowledged() {
+        let reminder_id = Uuid::new_v4();
+        let mut eventstore = TestEventStore::with_reminder(reminder_id, None);
+        let clock = FixedClock(date(2));
+        let mut id_generator = SequentialIdGenerator::new();
+        AcknowledgeReminderUsecase::new(&mut eventstore, &clock, &mut id_generator)
+            .execute(reminder_id)
+            .unwrap();
+
+        let result = AcknowledgeReminderUsecase::new(&mut eventstore, &clock, &mut id_generator)
+            .execute(reminder_id);
+
+        assert_eq!(
+            result,
+            Err(ReminderError::Acknowledged(reminder_id.to_string()))
+        );
+        assert_eq!(
+            eventstore.real_event_store.read(reminder_id).unwrap().len(),
+            2
+        );
+    }
+}
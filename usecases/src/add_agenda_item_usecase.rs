@@ -0,0 +1,209 @@
+use crate::clock::Clock;
+use crate::id_generator::IdGenerator;
+use crate::validation::validate_all;
+use chrono::NaiveDateTime;
+use entities::agenda_item_events::{
+    AgendaItemCreatedEvent, AgendaItemEnd, AgendaItemEvent, AgendaItemId, AgendaRecurrence,
+};
+use entities::errors::{EventStoreError, PlannableError};
+use uuid::Uuid;
+pub struct AddAgendaItemUsecase<'a> {
+    store_agenda_item_events: &'a mut dyn StoreAgendaItemEvents,
+    clock: &'a dyn Clock,
+    id_generator: &'a mut dyn IdGenerator,
+    allow_past: bool,
+}
+
+impl<'a> AddAgendaItemUsecase<'a> {
+    // Adds the agenda item and returns its id
+    pub fn execute(
+        self,
+        title: String,
+        start: NaiveDateTime,
+        end: Option<AgendaItemEnd>,
+        location: Option<String>,
+        recurrence: Option<AgendaRecurrence>,
+    ) -> Result<AgendaItemId, PlannableError> {
+        let agenda_item_id = self.id_generator.next_id();
+        let agenda_item = vec![AgendaItemEvent::Created(AgendaItemCreatedEvent {
+            title,
+            agenda_item_id,
+            event_id: self.id_generator.next_id(),
+            sequence: 0,
+            start,
+            end,
+            location,
+            recurrence,
+        })];
+        let now = (!self.allow_past).then(|| self.clock.now());
+        validate_all(&agenda_item, now)?;
+        self.store_agenda_item_events
+            .append(agenda_item_id, 0, agenda_item)?;
+        Ok(agenda_item_id)
+    }
+    pub fn new(
+        store_agenda_item_events: &'a mut dyn StoreAgendaItemEvents,
+        clock: &'a dyn Clock,
+        id_generator: &'a mut dyn IdGenerator,
+    ) -> Self {
+        Self {
+            store_agenda_item_events,
+            clock,
+            id_generator,
+            allow_past: false,
+        }
+    }
+
+    // Also accepts an agenda item that is already over, e.g. when importing old agenda items
+    pub fn allow_past(self) -> Self {
+        Self {
+            allow_past: true,
+            ..self
+        }
+    }
+}
+
+pub trait StoreAgendaItemEvents {
+    // Appends to the agenda item stream when it is still at expected_version, returns the new version
+    fn append(
+        &mut self,
+        agenda_item_id: Uuid,
+        expected_version: i32,
+        agenda_item_events: Vec<AgendaItemEvent>,
+    ) -> Result<i32, EventStoreError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AddAgendaItemUsecase;
+    use crate::add_agenda_item_usecase::StoreAgendaItemEvents;
+    use crate::clock::FixedClock;
+    use crate::id_generator::SequentialIdGenerator;
+    use chrono::{Duration, NaiveDate, NaiveDateTime};
+    use entities::agenda_item_events::{AgendaItemEnd, AgendaItemEvent};
+    use entities::errors::{EventStoreError, PlannableError, ValidationError, Violation};
+    use entities::plannable::PlannableKind;
+    use event_store::in_memory_event_store::InMemoryEventStore;
+    use event_store::plannable_event_store::EventStore;
+    use uuid::Uuid;
+
+    //Used for testing to call store functions
+    struct TestEventStore {
+        real_event_store: InMemoryEventStore<AgendaItemEvent>,
+    }
+
+    impl StoreAgendaItemEvents for TestEventStore {
+        fn append(
+            &mut self,
+            agenda_item_id: Uuid,
+            expected_version: i32,
+            agenda_item_events: Vec<AgendaItemEvent>,
+        ) -> Result<i32, EventStoreError> {
+            self.real_event_store
+                .append(agenda_item_id, expected_version, agenda_item_events)
+        }
+    }
+
+    fn date(day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, 9, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn given_agendaitem_when_added_then_createdeventstored() {
+        let mut eventstore = TestEventStore {
+            real_event_store: InMemoryEventStore::new(),
+        };
+        let agenda_item_id = AddAgendaItemUsecase::new(
+            &mut eventstore,
+            &FixedClock(date(25, 9)),
+            &mut SequentialIdGenerator::new(),
+        )
+        .execute(
+            String::from("Dentist appointment"),
+            date(26, 10),
+            Some(AgendaItemEnd::After(Duration::hours(1))),
+            Some(String::from("Main street 1")),
+            None,
+        )
+        .unwrap();
+        let events = eventstore.real_event_store.read(agenda_item_id).unwrap();
+        match &events[..] {
+            [AgendaItemEvent::Created(created)] => {
+                assert_eq!(created.title, "Dentist appointment");
+                assert_eq!(created.start, date(26, 10));
+            }
+            other => panic!("Expected one Created event, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn given_endbeforestart_when_added_then_invalidandnothingstored() {
+        let mut eventstore = TestEventStore {
+            real_event_store: InMemoryEventStore::new(),
+        };
+        let result = AddAgendaItemUsecase::new(
+            &mut eventstore,
+            &FixedClock(date(25, 9)),
+            &mut SequentialIdGenerator::new(),
+        )
+        .execute(
+            String::from("Dentist appointment"),
+            date(26, 10),
+            Some(AgendaItemEnd::At(date(26, 9))),
+            None,
+            None,
+        );
+        assert_eq!(
+            result,
+            Err(PlannableError::Invalid(ValidationError {
+                kind: PlannableKind::AgendaItem,
+                violations: vec![Violation::NotAfter {
+                    field: "end",
+                    other: "start",
+                }],
+            }))
+        );
+        assert!(eventstore.real_event_store.get_all().unwrap().is_empty());
+    }
+
+    #[test]
+    fn given_agendaiteminthepast_when_added_then_rejectedunlesspastallowed() {
+        let mut eventstore = TestEventStore {
+            real_event_store: InMemoryEventStore::new(),
+        };
+        let clock = FixedClock(date(25, 9));
+        let mut id_generator = SequentialIdGenerator::new();
+        let end = Some(AgendaItemEnd::After(Duration::hours(1)));
+        let result = AddAgendaItemUsecase::new(&mut eventstore, &clock, &mut id_generator).execute(
+            String::from("Dentist appointment"),
+            date(24, 10),
+            end,
+            None,
+            None,
+        );
+        assert_eq!(
+            result,
+            Err(PlannableError::Invalid(ValidationError {
+                kind: PlannableKind::AgendaItem,
+                violations: vec![Violation::InThePast { field: "end" }],
+            }))
+        );
+        assert!(eventstore.real_event_store.get_all().unwrap().is_empty());
+
+        //An import may bring in agenda items that are already over
+        let result = AddAgendaItemUsecase::new(&mut eventstore, &clock, &mut id_generator)
+            .allow_past()
+            .execute(
+                String::from("Dentist appointment"),
+                date(24, 10),
+                end,
+                None,
+                None,
+            );
+        assert!(result.is_ok());
+        assert_eq!(eventstore.real_event_store.get_all().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,146 @@
+use crate::clock::Clock;
+use crate::id_generator::IdGenerator;
+use crate::validation::validate_all;
+use chrono::NaiveDateTime;
+use entities::chore_events::{ChoreCreatedEvent, ChoreEvent};
+use entities::errors::{EventStoreError, PlannableError};
+use entities::recurrence::Recurrence;
+use uuid::Uuid;
+pub struct AddChoreUsecase<'a> {
+    store_chore_events: &'a mut dyn StoreChoreEvents,
+    clock: &'a dyn Clock,
+    id_generator: &'a mut dyn IdGenerator,
+    allow_past: bool,
+}
+
+impl<'a> AddChoreUsecase<'a> {
+    pub fn execute(
+        self,
+        title: String,
+        due_date: Option<NaiveDateTime>,
+        recurrence: Option<Recurrence>,
+    ) -> Result<(), PlannableError> {
+        let chore_id = self.id_generator.next_id();
+        let chore = vec![ChoreEvent::Created(ChoreCreatedEvent {
+            title,
+            due_date,
+            recurrence,
+            chore_id,
+            event_id: self.id_generator.next_id(),
+            sequence: 0,
+            converted_from: None,
+        })];
+        let now = (!self.allow_past).then(|| self.clock.now());
+        validate_all(&chore, now)?;
+        self.store_chore_events.append(chore_id, 0, chore)?;
+        Ok(())
+    }
+    pub fn new(
+        store_chore_events: &'a mut dyn StoreChoreEvents,
+        clock: &'a dyn Clock,
+        id_generator: &'a mut dyn IdGenerator,
+    ) -> Self {
+        Self {
+            store_chore_events,
+            clock,
+            id_generator,
+            allow_past: false,
+        }
+    }
+
+    // Also accepts a chore that is already over, e.g. when importing old chores
+    pub fn allow_past(self) -> Self {
+        Self {
+            allow_past: true,
+            ..self
+        }
+    }
+}
+
+pub trait StoreChoreEvents {
+    // Appends to the chore stream when it is still at expected_version, returns the new version
+    fn append(
+        &mut self,
+        chore_id: Uuid,
+        expected_version: i32,
+        chore_events: Vec<ChoreEvent>,
+    ) -> Result<i32, EventStoreError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AddChoreUsecase;
+    use crate::add_chore_usecase::StoreChoreEvents;
+    use crate::clock::FixedClock;
+    use crate::id_generator::SequentialIdGenerator;
+    use chrono::NaiveDate;
+    use entities::chore_events::ChoreEvent;
+    use entities::errors::EventStoreError;
+    use entities::recurrence::Recurrence;
+    use event_store::in_memory_event_store::InMemoryEventStore;
+    use event_store::plannable_event_store::EventStore;
+    use uuid::Uuid;
+
+    //Used for testing to call store functions
+    struct TestEventStore {
+        real_event_store: InMemoryEventStore<ChoreEvent>,
+    }
+
+    impl TestEventStore {
+        fn new() -> Self {
+            Self {
+                real_event_store: InMemoryEventStore::new(),
+            }
+        }
+        fn get_all_chores(&mut self) -> Result<Vec<ChoreEvent>, EventStoreError> {
+            self.real_event_store.get_all()
+        }
+    }
+
+    impl StoreChoreEvents for TestEventStore {
+        fn append(
+            &mut self,
+            chore_id: Uuid,
+            expected_version: i32,
+            chore_events: Vec<ChoreEvent>,
+        ) -> Result<i32, EventStoreError> {
+            self.real_event_store
+                .append(chore_id, expected_version, chore_events)
+        }
+    }
+
+    #[test]
+    fn given_recurringchore_when_added_then_createdeventstored() {
+        let mut eventstore = TestEventStore::new();
+        let due_date = Some(
+            NaiveDate::from_ymd_opt(2023, 9, 29)
+                .unwrap()
+                .and_hms_opt(9, 0, 0)
+                .unwrap(),
+        );
+        let clock = FixedClock(
+            NaiveDate::from_ymd_opt(2023, 9, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+        );
+        let result =
+            AddChoreUsecase::new(&mut eventstore, &clock, &mut SequentialIdGenerator::new())
+                .execute(
+                    String::from("Wash the car"),
+                    due_date,
+                    Some(Recurrence::Weeks(2)),
+                );
+        assert!(result.is_ok());
+        let events = eventstore.get_all_chores().unwrap();
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            ChoreEvent::Created(created) => {
+                assert_eq!(created.title, "Wash the car");
+                assert_eq!(created.due_date, due_date);
+                assert_eq!(created.recurrence, Some(Recurrence::Weeks(2)));
+            }
+            other => panic!("Expected a Created event, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use crate::clock::Clock;
+use crate::id_generator::IdGenerator;
+use crate::validation::validate_all;
+use chrono::NaiveDateTime;
+use entities::errors::{EventStoreError, PlannableError};
+use entities::recurrence::Recurrence;
+use entities::reminder_events::{ReminderCreatedEvent, ReminderEvent, ReminderId};
+use uuid::Uuid;
+pub struct AddReminderUsecase<'a> {
+    store_reminder_events: &'a mut dyn StoreReminderEvents,
+    clock: &'a dyn Clock,
+    id_generator: &'a mut dyn IdGenerator,
+    allow_past: bool,
+}
+
+impl<'a> AddReminderUsecase<'a> {
+    // Adds the reminder and returns its id
+    pub fn execute(
+        self,
+        title: String,
+        trigger_at: NaiveDateTime,
+        recurrence: Option<Recurrence>,
+    ) -> Result<ReminderId, PlannableError> {
+        let reminder_id = self.id_generator.next_id();
+        let reminder = vec![ReminderEvent::Created(ReminderCreatedEvent {
+            title,
+            reminder_id,
+            event_id: self.id_generator.next_id(),
+            sequence: 0,
+            trigger_at,
+            recurrence,
+        })];
+        let now = (!self.allow_past).then(|| self.clock.now());
+        validate_all(&reminder, now)?;
+        self.store_reminder_events
+            .append(reminder_id, 0, reminder)?;
+        Ok(reminder_id)
+    }
+    pub fn new(
+        store_reminder_events: &'a mut dyn StoreReminderEvents,
+        clock: &'a dyn Clock,
+        id_generator: &'a mut dyn IdGenerator,
+    ) -> Self {
+        Self {
+            store_reminder_events,
+            clock,
+            id_generator,
+            allow_past: false,
+        }
+    }
+
+    // Also accepts a reminder that is already over, e.g. when importing old reminders
+    pub fn allow_past(self) -> Self {
+        Self {
+            allow_past: true,
+            ..self
+        }
+    }
+}
+
+pub trait StoreReminderEvents {
+    // Appends to the reminder stream when it is still at expected_version, returns the new version
+    fn append(
+        &mut self,
+        reminder_id: Uuid,
+        expected_version: i32,
+        reminder_events: Vec<ReminderEvent>,
+    ) -> Result<i32, EventStoreError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AddReminderUsecase;
+    use crate::add_reminder_usecase::StoreReminderEvents;
+    use crate::clock::FixedClock;
+    use crate::id_generator::SequentialIdGenerator;
+    use chrono::{NaiveDate, NaiveDateTime};
+    use entities::errors::{EventStoreError, PlannableError, ValidationError, Violation};
+    use entities::plannable::PlannableKind;
+    use entities::recurrence::Recurrence;
+    use entities::reminder_events::ReminderEvent;
+    use event_store::in_memory_event_store::InMemoryEventStore;
+    use event_store::plannable_event_store::EventStore;
+    use uuid::Uuid;
+
+    //Used for testing to call store functions
+    struct TestEventStore {
+        real_event_store: InMemoryEventStore<ReminderEvent>,
+    }
+
+    impl StoreReminderEvents for TestEventStore {
+        fn append(
+            &mut self,
+            reminder_id: Uuid,
+            expected_version: i32,
+            reminder_events: Vec<ReminderEvent>,
+        ) -> Result<i32, EventStoreError> {
+            self.real_event_store
+                .append(reminder_id, expected_version, reminder_events)
+        }
+    }
+
+    fn date(day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, 9, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn given_recurringreminderfromthepast_when_added_then_createdeventstored() {
+        let mut eventstore = TestEventStore {
+            real_event_store: InMemoryEventStore::new(),
+        };
+        let reminder_id = AddReminderUsecase::new(
+            &mut eventstore,
+            &FixedClock(date(25, 9)),
+            &mut SequentialIdGenerator::new(),
+        )
+        .execute(
+            String::from("Water the plants"),
+            date(18, 19),
+            Some(Recurrence::Days(3)),
+        )
+        .unwrap();
+        let events = eventstore.real_event_store.read(reminder_id).unwrap();
+        match &events[..] {
+            [ReminderEvent::Created(created)] => {
+                assert_eq!(created.title, "Water the plants");
+                assert_eq!(created.recurrence, Some(Recurrence::Days(3)));
+            }
+            other => panic!("Expected one Created event, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn given_oneofreminderinthepast_when_added_then_rejectedunlesspastallowed() {
+        let mut eventstore = TestEventStore {
+            real_event_store: InMemoryEventStore::new(),
+        };
+        let clock = FixedClock(date(25, 9));
+        let mut id_generator = SequentialIdGenerator::new();
+        let result = AddReminderUsecase::new(&mut eventstore, &clock, &mut id_generator).execute(
+            String::from("Call grandma"),
+            date(24, 19),
+            None,
+        );
+        assert_eq!(
+            result,
+            Err(PlannableError::Invalid(ValidationError {
+                kind: PlannableKind::Reminder,
+                violations: vec![Violation::InThePast {
+                    field: "trigger date"
+                }],
+            }))
+        );
+        assert!(eventstore.real_event_store.get_all().unwrap().is_empty());
+
+        //An import may bring in reminders that are already over
+        let result = AddReminderUsecase::new(&mut eventstore, &clock, &mut id_generator)
+            .allow_past()
+            .execute(String::from("Call grandma"), date(24, 19), None);
+        assert!(result.is_ok());
+        assert_eq!(eventstore.real_event_store.get_all().unwrap().len(), 1);
+    }
+}
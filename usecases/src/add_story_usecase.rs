@@ -0,0 +1,92 @@
+use crate::id_generator::IdGenerator;
+use crate::validation::validate_all;
+use entities::errors::{EventStoreError, PlannableError};
+use entities::story_events::{StoryCreatedEvent, StoryEvent, StoryId};
+use uuid::Uuid;
+pub struct AddStoryUsecase<'a> {
+    store_story_events: &'a mut dyn StoreStoryEvents,
+    id_generator: &'a mut dyn IdGenerator,
+}
+
+impl<'a> AddStoryUsecase<'a> {
+    // Adds the story as a draft and returns its id
+    pub fn execute(self, title: String, description: String) -> Result<StoryId, PlannableError> {
+        let story_id = self.id_generator.next_id();
+        let story = vec![StoryEvent::Created(StoryCreatedEvent {
+            title,
+            description,
+            story_id,
+            event_id: self.id_generator.next_id(),
+            sequence: 0,
+        })];
+        validate_all(&story, None)?;
+        self.store_story_events.append(story_id, 0, story)?;
+        Ok(story_id)
+    }
+    pub fn new(
+        store_story_events: &'a mut dyn StoreStoryEvents,
+        id_generator: &'a mut dyn IdGenerator,
+    ) -> Self {
+        Self {
+            store_story_events,
+            id_generator,
+        }
+    }
+}
+
+pub trait StoreStoryEvents {
+    // Appends to the story stream when it is still at expected_version, returns the new version
+    fn append(
+        &mut self,
+        story_id: Uuid,
+        expected_version: i32,
+        story_events: Vec<StoryEvent>,
+    ) -> Result<i32, EventStoreError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AddStoryUsecase;
+    use crate::add_story_usecase::StoreStoryEvents;
+    use crate::id_generator::SequentialIdGenerator;
+    use entities::errors::EventStoreError;
+    use entities::story::{Story, StoryStatus};
+    use entities::story_events::StoryEvent;
+    use event_store::in_memory_event_store::InMemoryEventStore;
+    use event_store::plannable_event_store::EventStore;
+    use uuid::Uuid;
+
+    //Used for testing to call store functions
+    struct TestEventStore {
+        real_event_store: InMemoryEventStore<StoryEvent>,
+    }
+
+    impl StoreStoryEvents for TestEventStore {
+        fn append(
+            &mut self,
+            story_id: Uuid,
+            expected_version: i32,
+            story_events: Vec<StoryEvent>,
+        ) -> Result<i32, EventStoreError> {
+            self.real_event_store
+                .append(story_id, expected_version, story_events)
+        }
+    }
+
+    #[test]
+    fn given_story_when_added_then_storedasdraft() {
+        let mut eventstore = TestEventStore {
+            real_event_store: InMemoryEventStore::new(),
+        };
+        let story_id = AddStoryUsecase::new(&mut eventstore, &mut SequentialIdGenerator::new())
+            .execute(
+                String::from("Refactor code to use clean architecture"),
+                String::from("Split the app into layers"),
+            )
+            .unwrap();
+        let events = eventstore.real_event_store.read(story_id).unwrap();
+        let story = Story::from_events(&events).unwrap();
+        assert_eq!(story.status, StoryStatus::Draft);
+        assert_eq!(story.description, "Split the app into layers");
+    }
+}
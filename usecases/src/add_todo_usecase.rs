@@ -1,8 +1,15 @@
+use crate::clock::Clock;
+use crate::id_generator::IdGenerator;
+use crate::validation::validate_all;
 use chrono::NaiveDateTime;
-use entities::todo_events::TodoCreatedEvent;
+use entities::errors::{EventStoreError, PlannableError};
+use entities::todo_events::{TodoCreatedEvent, TodoEvent};
 use uuid::Uuid;
 pub struct AddTodoUsecase<'a> {
     store_todo_events: &'a mut dyn StoreTodoEvents,
+    clock: &'a dyn Clock,
+    id_generator: &'a mut dyn IdGenerator,
+    allow_past: bool,
 }
 
 impl<'a> AddTodoUsecase<'a> {
@@ -10,62 +17,114 @@ impl<'a> AddTodoUsecase<'a> {
         self,
         title: String,
         end_date: Option<NaiveDateTime>,
-    ) -> Result<(), std::io::Error> {
-        let todo = vec![TodoCreatedEvent {
+    ) -> Result<(), PlannableError> {
+        let todo_id = self.id_generator.next_id();
+        let todo = vec![TodoEvent::Created(TodoCreatedEvent {
             title,
             end_date,
-            todo_id: Uuid::new_v4(),
-            event_id: Uuid::new_v4(),
+            todo_id,
+            event_id: self.id_generator.next_id(),
             sequence: 0,
-        }];
-        self.store_todo_events.save(todo)
+            converted_from: None,
+        })];
+        let now = (!self.allow_past).then(|| self.clock.now());
+        validate_all(&todo, now)?;
+        self.store_todo_events.append(todo_id, 0, todo)?;
+        Ok(())
     }
-    pub fn new(store_todo_events: &'a mut dyn StoreTodoEvents) -> Self {
-        Self { store_todo_events }
+    pub fn new(
+        store_todo_events: &'a mut dyn StoreTodoEvents,
+        clock: &'a dyn Clock,
+        id_generator: &'a mut dyn IdGenerator,
+    ) -> Self {
+        Self {
+            store_todo_events,
+            clock,
+            id_generator,
+            allow_past: false,
+        }
+    }
+
+    // Also accepts a todo that is already over, e.g. when importing old todos
+    pub fn allow_past(self) -> Self {
+        Self {
+            allow_past: true,
+            ..self
+        }
     }
 }
 
 pub trait StoreTodoEvents {
-    fn save(&mut self, todo_events: Vec<TodoCreatedEvent>) -> Result<(), std::io::Error>;
+    // Appends to the todo stream when it is still at expected_version, returns the new version
+    fn append(
+        &mut self,
+        todo_id: Uuid,
+        expected_version: i32,
+        todo_events: Vec<TodoEvent>,
+    ) -> Result<i32, EventStoreError>;
 }
 
 #[cfg(test)]
 mod tests {
     use super::AddTodoUsecase;
     use crate::add_todo_usecase::StoreTodoEvents;
+    use crate::clock::FixedClock;
+    use crate::id_generator::SequentialIdGenerator;
     use chrono::NaiveDate;
-    use entities::todo_events::TodoCreatedEvent;
-    use event_store::plannable_todo_event_store::TodoEventStore;
+    use entities::errors::{EventStoreError, PlannableError, ValidationError, Violation};
+    use entities::plannable::PlannableKind;
+    use entities::todo_events::{TodoCreatedEvent, TodoEvent};
+    use event_store::in_memory_event_store::InMemoryEventStore;
+    use event_store::plannable_event_store::EventStore;
+    use uuid::Uuid;
 
     //Used for testing to call store functions
     struct TestEventStore {
-        real_event_store: TodoEventStore,
+        real_event_store: InMemoryEventStore<TodoEvent>,
     }
 
     impl TestEventStore {
-        fn new(name: String) -> Self {
-            let database_url = format!("/tmp/{}.db", name);
-            let eventstore = TodoEventStore::clean(&database_url).unwrap();
+        fn new() -> Self {
             Self {
-                real_event_store: eventstore,
+                real_event_store: InMemoryEventStore::new(),
             }
         }
-        fn get_all_todos(&mut self) -> Result<Vec<TodoCreatedEvent>, std::io::Error> {
+        fn get_all_todos(&mut self) -> Result<Vec<TodoEvent>, EventStoreError> {
             self.real_event_store.get_all()
         }
     }
 
     impl StoreTodoEvents for TestEventStore {
-        fn save(&mut self, todo_events: Vec<TodoCreatedEvent>) -> Result<(), std::io::Error> {
-            self.real_event_store.save(todo_events)
+        fn append(
+            &mut self,
+            todo_id: Uuid,
+            expected_version: i32,
+            todo_events: Vec<TodoEvent>,
+        ) -> Result<i32, EventStoreError> {
+            self.real_event_store
+                .append(todo_id, expected_version, todo_events)
         }
     }
 
+    fn september_first() -> FixedClock {
+        FixedClock(
+            NaiveDate::from_ymd_opt(2023, 9, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+        )
+    }
+
     #[test]
     fn given_todoevent_when_stored_then_ok() {
-        let mut eventstore = TestEventStore::new(String::from("testtodousecase"));
+        let mut eventstore = TestEventStore::new();
+        let clock = september_first();
+        let mut id_generator = SequentialIdGenerator::new();
         let usecase = AddTodoUsecase {
             store_todo_events: &mut eventstore,
+            clock: &clock,
+            id_generator: &mut id_generator,
+            allow_past: false,
         };
         let end_date = Some(
             NaiveDate::from_ymd_opt(2023, 9, 29)
@@ -77,6 +136,62 @@ mod tests {
         let result = usecase.execute(title, end_date);
         assert!(result.is_ok());
         let result = eventstore.get_all_todos();
-        assert!(result.is_ok());
+        assert_eq!(
+            result,
+            Ok(vec![TodoEvent::Created(TodoCreatedEvent {
+                title: String::from("Read rust book"),
+                todo_id: Uuid::from_u128(1),
+                event_id: Uuid::from_u128(2),
+                sequence: 0,
+                end_date,
+                converted_from: None,
+            })])
+        );
+    }
+
+    #[test]
+    fn given_blanktitle_when_added_then_invalidandnothingstored() {
+        let mut eventstore = TestEventStore::new();
+        let result = AddTodoUsecase::new(
+            &mut eventstore,
+            &september_first(),
+            &mut SequentialIdGenerator::new(),
+        )
+        .execute(String::from(" "), None);
+        assert_eq!(
+            result,
+            Err(PlannableError::Invalid(ValidationError {
+                kind: PlannableKind::Todo,
+                violations: vec![Violation::Empty { field: "title" }],
+            }))
+        );
+        assert_eq!(eventstore.get_all_todos().unwrap(), vec![]);
+    }
+
+    #[test]
+    fn given_enddateinthepast_when_added_then_rejectedunlesspastallowed() {
+        let mut eventstore = TestEventStore::new();
+        let clock = september_first();
+        let mut id_generator = SequentialIdGenerator::new();
+        let end_date = NaiveDate::from_ymd_opt(2023, 8, 31)
+            .unwrap()
+            .and_hms_opt(23, 59, 59);
+
+        let result = AddTodoUsecase::new(&mut eventstore, &clock, &mut id_generator)
+            .execute(String::from("Read rust book"), end_date);
+        assert_eq!(
+            result,
+            Err(PlannableError::Invalid(ValidationError {
+                kind: PlannableKind::Todo,
+                violations: vec![Violation::InThePast { field: "end date" }],
+            }))
+        );
+
+        //An import may bring in todos that are already over
+        let result = AddTodoUsecase::new(&mut eventstore, &clock, &mut id_generator)
+            .allow_past()
+            .execute(String::from("Read rust book"), end_date);
+        assert_eq!(result, Ok(()));
+        assert_eq!(eventstore.get_all_todos().unwrap().len(), 1);
     }
 }
@@ -0,0 +1,102 @@
+use crate::validation::validate_all;
+use chrono::NaiveDateTime;
+use entities::errors::{EventStoreError, TodoError};
+use entities::todo::Todo;
+use entities::todo_events::{TodoEvent, TodoId};
+use uuid::Uuid;
+
+// Port shared by the usecases that change a single existing todo
+pub trait ChangeTodoEvents {
+    fn read(&mut self, todo_id: Uuid) -> Result<Vec<TodoEvent>, EventStoreError>;
+
+    // Appends to the todo stream when it is still at expected_version, returns the new version
+    fn append(
+        &mut self,
+        todo_id: Uuid,
+        expected_version: i32,
+        todo_events: Vec<TodoEvent>,
+    ) -> Result<i32, EventStoreError>;
+}
+
+// Rebuilds a todo that can still be changed, a removed todo is gone and a converted one is closed
+pub(crate) fn read_todo(
+    change_todo_events: &mut dyn ChangeTodoEvents,
+    todo_id: TodoId,
+) -> Result<Todo, TodoError> {
+    let events = change_todo_events.read(todo_id)?;
+    let todo = Todo::from_events(&events)
+        .filter(|todo| !todo.removed)
+        .ok_or_else(|| TodoError::NotFound(todo_id.to_string()))?;
+    match todo.converted_into {
+        Some(into) => Err(TodoError::Converted {
+            todo_id: todo_id.to_string(),
+            into,
+        }),
+        None => Ok(todo),
+    }
+}
+
+// Validates the changes, appends them at the version the todo was read at and returns the todo
+// as it is afterwards
+pub(crate) fn append_changes(
+    change_todo_events: &mut dyn ChangeTodoEvents,
+    mut todo: Todo,
+    changes: Vec<TodoEvent>,
+    now: Option<NaiveDateTime>,
+) -> Result<Todo, TodoError> {
+    if changes.is_empty() {
+        return Ok(todo);
+    }
+    validate_all(&changes, now)?;
+    change_todo_events.append(todo.todo_id, todo.version, changes.clone())?;
+    changes.iter().for_each(|change| todo.apply(change));
+    Ok(todo)
+}
+
+#[cfg(test)]
+pub(crate) mod fixture {
+    use super::ChangeTodoEvents;
+    use entities::errors::EventStoreError;
+    use entities::todo_events::{TodoCreatedEvent, TodoEvent};
+    use event_store::in_memory_event_store::InMemoryEventStore;
+    use event_store::plannable_event_store::EventStore;
+    use uuid::Uuid;
+
+    //Used by the tests of the usecases that change a todo to call store functions
+    pub(crate) struct TestEventStore {
+        pub(crate) real_event_store: InMemoryEventStore<TodoEvent>,
+    }
+
+    impl TestEventStore {
+        pub(crate) fn with_todo(todo_id: Uuid) -> Self {
+            let mut real_event_store = InMemoryEventStore::new();
+            real_event_store
+                .save(vec![TodoEvent::Created(TodoCreatedEvent {
+                    title: String::from("Buy rust book"),
+                    todo_id,
+                    event_id: Uuid::new_v4(),
+                    sequence: 0,
+                    end_date: None,
+                    converted_from: None,
+                })])
+                .unwrap();
+            Self { real_event_store }
+        }
+    }
+
+    impl ChangeTodoEvents for TestEventStore {
+        fn read(&mut self, todo_id: Uuid) -> Result<Vec<TodoEvent>, EventStoreError> {
+            self.real_event_store.read(todo_id)
+        }
+
+        fn append(
+            &mut self,
+            todo_id: Uuid,
+            expected_version: i32,
+            todo_events: Vec<TodoEvent>,
+        ) -> Result<i32, EventStoreError> {
+            self.real_event_store
+                .append(todo_id, expected_version, todo_events)
+        }
+    }
+}
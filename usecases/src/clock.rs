@@ -0,0 +1,24 @@
+use chrono::{Local, NaiveDateTime};
+
+// Tells the usecases what time it is, so they can be run against a fixed time
+pub trait Clock {
+    // The local time, as plannable dates are stored without a time zone
+    fn now(&self) -> NaiveDateTime;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> NaiveDateTime {
+        Local::now().naive_local()
+    }
+}
+
+// Always tells the same time
+pub struct FixedClock(pub NaiveDateTime);
+
+impl Clock for FixedClock {
+    fn now(&self) -> NaiveDateTime {
+        self.0
+    }
+}
@@ -0,0 +1,97 @@
+use crate::change_todo::{append_changes, read_todo, ChangeTodoEvents};
+use crate::id_generator::IdGenerator;
+use entities::errors::TodoError;
+use entities::todo::Todo;
+use entities::todo_events::{TodoCompletedEvent, TodoEvent, TodoId};
+
+pub struct CompleteTodoUsecase<'a> {
+    change_todo_events: &'a mut dyn ChangeTodoEvents,
+    id_generator: &'a mut dyn IdGenerator,
+}
+
+impl<'a> CompleteTodoUsecase<'a> {
+    pub fn execute(self, todo_id: TodoId) -> Result<Todo, TodoError> {
+        let todo = read_todo(self.change_todo_events, todo_id)?;
+        if todo.completed {
+            return Err(TodoError::AlreadyCompleted(todo_id.to_string()));
+        }
+        let completed = TodoEvent::Completed(TodoCompletedEvent {
+            todo_id,
+            event_id: self.id_generator.next_id(),
+            sequence: todo.version,
+        });
+        append_changes(self.change_todo_events, todo, vec![completed], None)
+    }
+    pub fn new(
+        change_todo_events: &'a mut dyn ChangeTodoEvents,
+        id_generator: &'a mut dyn IdGenerator,
+    ) -> Self {
+        Self {
+            change_todo_events,
+            id_generator,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CompleteTodoUsecase;
+    use crate::change_todo::fixture::TestEventStore;
+    use crate::id_generator::SequentialIdGenerator;
+    use entities::errors::TodoError;
+    use entities::plannable::PlannableRef;
+    use entities::todo_events::{TodoCompletedEvent, TodoConvertedEvent, TodoEvent};
+    use event_store::plannable_event_store::EventStore;
+    use uuid::Uuid;
+
+    #[test]
+    fn given_opentodo_when_completedtwice_then_secondrejected() {
+        //GIVEN
+        let todo_id = Uuid::new_v4();
+        let mut eventstore = TestEventStore::with_todo(todo_id);
+        let mut id_generator = SequentialIdGenerator::new();
+
+        //WHEN
+        let todo = CompleteTodoUsecase::new(&mut eventstore, &mut id_generator)
+            .execute(todo_id)
+            .unwrap();
+        let again = CompleteTodoUsecase::new(&mut eventstore, &mut id_generator).execute(todo_id);
+
+        //THEN
+        assert!(todo.completed);
+        assert_eq!(again, Err(TodoError::AlreadyCompleted(todo_id.to_string())));
+        assert_eq!(
+            eventstore.real_event_store.read(todo_id).unwrap()[1..],
+            [TodoEvent::Completed(TodoCompletedEvent {
+                todo_id,
+                event_id: Uuid::from_u128(1),
+                sequence: 1,
+            })]
+        );
+    }
+
+    #[test]
+    fn given_convertedtodo_when_completed_then_rejected() {
+        let todo_id = Uuid::new_v4();
+        let into = PlannableRef::Task(Uuid::new_v4());
+        let mut eventstore = TestEventStore::with_todo(todo_id);
+        eventstore
+            .real_event_store
+            .save(vec![TodoEvent::Converted(TodoConvertedEvent {
+                todo_id,
+                event_id: Uuid::new_v4(),
+                sequence: 1,
+                into,
+            })])
+            .unwrap();
+        let result = CompleteTodoUsecase::new(&mut eventstore, &mut SequentialIdGenerator::new())
+            .execute(todo_id);
+        assert_eq!(
+            result,
+            Err(TodoError::Converted {
+                todo_id: todo_id.to_string(),
+                into,
+            })
+        );
+    }
+}
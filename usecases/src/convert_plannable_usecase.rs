@@ -0,0 +1,453 @@
+use crate::id_generator::IdGenerator;
+use crate::validation::Validate;
+use chrono::NaiveDateTime;
+use entities::chore::Chore;
+use entities::chore_events::{ChoreConvertedEvent, ChoreCreatedEvent, ChoreEvent};
+use entities::errors::{ConversionError, EventStoreError, ValidationError};
+use entities::plannable::{PlannableKind, PlannableRef};
+use entities::task::Task;
+use entities::task_events::{TaskConvertedEvent, TaskCreatedEvent, TaskEvent};
+use entities::todo::Todo;
+use entities::todo_events::{TodoConvertedEvent, TodoCreatedEvent, TodoEvent};
+use uuid::Uuid;
+
+pub struct ConvertPlannableUsecase<'a> {
+    convert_plannable_events: &'a mut dyn ConvertPlannableEvents,
+    id_generator: &'a mut dyn IdGenerator,
+}
+
+// What a conversion carries over from the source, whatever its kind
+struct Convertible {
+    title: String,
+    // End date of a todo or task, due date of a chore
+    date: Option<NaiveDateTime>,
+    version: i32,
+    converted_into: Option<PlannableRef>,
+}
+
+impl<'a> ConvertPlannableUsecase<'a> {
+    // Closes the source with a Converted event and opens a plannable of the target kind that keeps
+    // the title and date and points back at the source. Returns the new plannable.
+    pub fn execute(
+        mut self,
+        source: PlannableRef,
+        target: PlannableKind,
+    ) -> Result<PlannableRef, ConversionError> {
+        let unsupported = ConversionError::Unsupported {
+            from: source.kind(),
+            to: target,
+        };
+        let convertible = self
+            .read(source)?
+            .ok_or(ConversionError::NotFound(source))?;
+        if let Some(into) = convertible.converted_into {
+            return Err(ConversionError::AlreadyConverted { source, into });
+        }
+        if source.kind() == target {
+            return Err(unsupported);
+        }
+        let into = match target {
+            PlannableKind::Todo => PlannableRef::Todo(self.id_generator.next_id()),
+            PlannableKind::Task => PlannableRef::Task(self.id_generator.next_id()),
+            PlannableKind::Chore => PlannableRef::Chore(self.id_generator.next_id()),
+            _ => return Err(unsupported),
+        };
+        let version = convertible.version;
+        let opened =
+            ConversionEvent::created(into, self.id_generator.next_id(), source, convertible);
+        opened
+            .validate()
+            .map_err(ConversionError::TargetRuleViolated)?;
+
+        // Both are stored as one, the expected version of the source makes the conversion fail
+        // when the source changed since it was read
+        let closed = self.converted(source, version, into);
+        self.convert_plannable_events
+            .append_conversion(closed, opened)?;
+        Ok(into)
+    }
+    pub fn new(
+        convert_plannable_events: &'a mut dyn ConvertPlannableEvents,
+        id_generator: &'a mut dyn IdGenerator,
+    ) -> Self {
+        Self {
+            convert_plannable_events,
+            id_generator,
+        }
+    }
+
+    fn read(&mut self, source: PlannableRef) -> Result<Option<Convertible>, ConversionError> {
+        let events = &mut self.convert_plannable_events;
+        Ok(match source {
+            // A removed todo is gone, as it is for the todo usecases
+            PlannableRef::Todo(todo_id) => Todo::from_events(&events.read_todo(todo_id)?)
+                .filter(|todo| !todo.removed)
+                .map(|todo| Convertible {
+                    title: todo.title,
+                    date: todo.end_date,
+                    version: todo.version,
+                    converted_into: todo.converted_into,
+                }),
+            PlannableRef::Task(task_id) => {
+                Task::from_events(&events.read_task(task_id)?).map(|task| Convertible {
+                    title: task.title,
+                    date: task.end_date,
+                    version: task.version,
+                    converted_into: task.converted_into,
+                })
+            }
+            PlannableRef::Chore(chore_id) => {
+                Chore::from_events(&events.read_chore(chore_id)?).map(|chore| Convertible {
+                    title: chore.title,
+                    date: chore.due_date,
+                    version: chore.version,
+                    converted_into: chore.converted_into,
+                })
+            }
+            PlannableRef::Story(_) => None,
+        })
+    }
+
+    // The Converted event that closes the source at the version it was read at
+    fn converted(
+        &mut self,
+        source: PlannableRef,
+        version: i32,
+        into: PlannableRef,
+    ) -> ConversionEvent {
+        let event_id = self.id_generator.next_id();
+        match source {
+            PlannableRef::Todo(todo_id) => {
+                ConversionEvent::Todo(TodoEvent::Converted(TodoConvertedEvent {
+                    todo_id,
+                    event_id,
+                    sequence: version,
+                    into,
+                }))
+            }
+            PlannableRef::Task(task_id) => {
+                ConversionEvent::Task(TaskEvent::Converted(TaskConvertedEvent {
+                    task_id,
+                    event_id,
+                    sequence: version,
+                    into,
+                }))
+            }
+            PlannableRef::Chore(chore_id) => {
+                ConversionEvent::Chore(ChoreEvent::Converted(ChoreConvertedEvent {
+                    chore_id,
+                    event_id,
+                    sequence: version,
+                    into,
+                }))
+            }
+            PlannableRef::Story(_) => unreachable!("stories are never read as a conversion source"),
+        }
+    }
+}
+
+// An event a conversion appends, the Converted event that closes the source or the Created event
+// that opens the target. Its sequence is the version the stream is expected to be at.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ConversionEvent {
+    Todo(TodoEvent),
+    Task(TaskEvent),
+    Chore(ChoreEvent),
+}
+
+impl ConversionEvent {
+    fn created(
+        into: PlannableRef,
+        event_id: Uuid,
+        source: PlannableRef,
+        convertible: Convertible,
+    ) -> Self {
+        match into {
+            PlannableRef::Todo(todo_id) => {
+                ConversionEvent::Todo(TodoEvent::Created(TodoCreatedEvent {
+                    title: convertible.title,
+                    todo_id,
+                    event_id,
+                    sequence: 0,
+                    end_date: convertible.date,
+                    converted_from: Some(source),
+                }))
+            }
+            PlannableRef::Task(task_id) => {
+                ConversionEvent::Task(TaskEvent::Created(TaskCreatedEvent {
+                    title: convertible.title,
+                    task_id,
+                    event_id,
+                    sequence: 0,
+                    end_date: convertible.date,
+                    converted_from: Some(source),
+                }))
+            }
+            PlannableRef::Chore(chore_id) => {
+                ConversionEvent::Chore(ChoreEvent::Created(ChoreCreatedEvent {
+                    title: convertible.title,
+                    chore_id,
+                    event_id,
+                    sequence: 0,
+                    due_date: convertible.date,
+                    recurrence: None,
+                    converted_from: Some(source),
+                }))
+            }
+            PlannableRef::Story(_) => unreachable!("stories are never a conversion target"),
+        }
+    }
+
+    // Checked before anything is stored. The target keeps the dates of the source, a past date
+    // stays as it was.
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            ConversionEvent::Todo(created) => created.validate(None),
+            ConversionEvent::Task(created) => created.validate(None),
+            ConversionEvent::Chore(created) => created.validate(None),
+        }
+    }
+}
+
+pub trait ConvertPlannableEvents {
+    fn read_todo(&mut self, todo_id: Uuid) -> Result<Vec<TodoEvent>, EventStoreError>;
+    fn read_task(&mut self, task_id: Uuid) -> Result<Vec<TaskEvent>, EventStoreError>;
+    fn read_chore(&mut self, chore_id: Uuid) -> Result<Vec<ChoreEvent>, EventStoreError>;
+
+    // Appends the event that closes the source and the one that opens the target as one, neither
+    // is stored when either stream is not at the version of its event
+    fn append_conversion(
+        &mut self,
+        closed: ConversionEvent,
+        opened: ConversionEvent,
+    ) -> Result<(), EventStoreError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ConversionEvent, ConvertPlannableUsecase};
+    use crate::convert_plannable_usecase::ConvertPlannableEvents;
+    use crate::id_generator::SequentialIdGenerator;
+    use chrono::NaiveDate;
+    use entities::chore_events::ChoreEvent;
+    use entities::errors::{ConversionError, EventStoreError, ValidationError, Violation};
+    use entities::plannable::{PlannableKind, PlannableRef};
+    use entities::task::Task;
+    use entities::task_events::{TaskCreatedEvent, TaskEvent};
+    use entities::todo::Todo;
+    use entities::todo_events::{TodoCreatedEvent, TodoEvent, TodoRemovedEvent};
+    use event_store::in_memory_event_store::InMemoryEventStore;
+    use event_store::plannable_event_store::EventStore;
+    use uuid::Uuid;
+
+    //Used for testing to call store functions
+    struct TestEventStore {
+        todo_event_store: InMemoryEventStore<TodoEvent>,
+        task_event_store: InMemoryEventStore<TaskEvent>,
+        chore_event_store: InMemoryEventStore<ChoreEvent>,
+    }
+
+    impl TestEventStore {
+        fn with_todo(todo_id: Uuid, end_date: bool) -> Self {
+            let mut todo_event_store = InMemoryEventStore::new();
+            todo_event_store
+                .save(vec![TodoEvent::Created(TodoCreatedEvent {
+                    event_id: Uuid::new_v4(),
+                    todo_id,
+                    sequence: 0,
+                    title: String::from("Complete your homework"),
+                    end_date: end_date.then(|| {
+                        NaiveDate::from_ymd_opt(2023, 9, 29)
+                            .unwrap()
+                            .and_hms_opt(17, 0, 0)
+                            .unwrap()
+                    }),
+                    converted_from: None,
+                })])
+                .unwrap();
+            Self {
+                todo_event_store,
+                task_event_store: InMemoryEventStore::new(),
+                chore_event_store: InMemoryEventStore::new(),
+            }
+        }
+    }
+
+    impl ConvertPlannableEvents for TestEventStore {
+        fn read_todo(&mut self, todo_id: Uuid) -> Result<Vec<TodoEvent>, EventStoreError> {
+            self.todo_event_store.read(todo_id)
+        }
+        fn read_task(&mut self, task_id: Uuid) -> Result<Vec<TaskEvent>, EventStoreError> {
+            self.task_event_store.read(task_id)
+        }
+        fn read_chore(&mut self, chore_id: Uuid) -> Result<Vec<ChoreEvent>, EventStoreError> {
+            self.chore_event_store.read(chore_id)
+        }
+        fn append_conversion(
+            &mut self,
+            closed: ConversionEvent,
+            opened: ConversionEvent,
+        ) -> Result<(), EventStoreError> {
+            // Both streams are checked before either is appended to, as the one transaction of
+            // the sqlite store does
+            for event in [&closed, &opened] {
+                let (plannable_id, expected_version, actual_version) = match event {
+                    ConversionEvent::Todo(event) => (
+                        event.todo_id(),
+                        event.sequence(),
+                        self.todo_event_store.read(event.todo_id())?.len(),
+                    ),
+                    ConversionEvent::Task(event) => (
+                        event.task_id(),
+                        event.sequence(),
+                        self.task_event_store.read(event.task_id())?.len(),
+                    ),
+                    ConversionEvent::Chore(event) => (
+                        event.chore_id(),
+                        event.sequence(),
+                        self.chore_event_store.read(event.chore_id())?.len(),
+                    ),
+                };
+                if actual_version as i32 != expected_version {
+                    return Err(EventStoreError::ConcurrencyConflict {
+                        plannable_id: plannable_id.to_string(),
+                        expected_version,
+                        actual_version: actual_version as i32,
+                    });
+                }
+            }
+            for event in [closed, opened] {
+                match event {
+                    ConversionEvent::Todo(event) => {
+                        self.todo_event_store
+                            .append(event.todo_id(), event.sequence(), vec![event])
+                    }
+                    ConversionEvent::Task(event) => {
+                        self.task_event_store
+                            .append(event.task_id(), event.sequence(), vec![event])
+                    }
+                    ConversionEvent::Chore(event) => self.chore_event_store.append(
+                        event.chore_id(),
+                        event.sequence(),
+                        vec![event],
+                    ),
+                }?;
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn given_todowithenddate_when_convertedtotask_then_todoclosedandtaskopened() {
+        let todo_id = Uuid::new_v4();
+        let mut eventstore = TestEventStore::with_todo(todo_id, true);
+        let source = PlannableRef::Todo(todo_id);
+
+        let into = ConvertPlannableUsecase::new(&mut eventstore, &mut SequentialIdGenerator::new())
+            .execute(source, PlannableKind::Task)
+            .unwrap();
+
+        let todo = Todo::from_events(&eventstore.todo_event_store.read(todo_id).unwrap()).unwrap();
+        assert_eq!(todo.converted_into, Some(into));
+        let task =
+            Task::from_events(&eventstore.task_event_store.read(into.id()).unwrap()).unwrap();
+        assert_eq!(task.title, todo.title);
+        assert_eq!(task.end_date, todo.end_date);
+        assert_eq!(task.converted_from, Some(source));
+
+        //Converting again fails because the todo is closed
+        let result =
+            ConvertPlannableUsecase::new(&mut eventstore, &mut SequentialIdGenerator::new())
+                .execute(source, PlannableKind::Chore);
+        assert_eq!(
+            result,
+            Err(ConversionError::AlreadyConverted { source, into })
+        );
+    }
+
+    #[test]
+    fn given_todowithoutenddate_when_convertedtotask_then_ruleviolatedandnothingstored() {
+        let todo_id = Uuid::new_v4();
+        let mut eventstore = TestEventStore::with_todo(todo_id, false);
+
+        let result =
+            ConvertPlannableUsecase::new(&mut eventstore, &mut SequentialIdGenerator::new())
+                .execute(PlannableRef::Todo(todo_id), PlannableKind::Task);
+
+        assert_eq!(
+            result,
+            Err(ConversionError::TargetRuleViolated(ValidationError {
+                kind: PlannableKind::Task,
+                violations: vec![Violation::MissingDate { field: "end date" }],
+            }))
+        );
+        assert_eq!(eventstore.todo_event_store.read(todo_id).unwrap().len(), 1);
+        assert_eq!(eventstore.task_event_store.get_all().unwrap(), vec![]);
+    }
+
+    #[test]
+    fn given_targetstreamtaken_when_converted_then_sourcestaysopen() {
+        //GIVEN the id the conversion hands to the task is taken by another task
+        let todo_id = Uuid::new_v4();
+        let mut eventstore = TestEventStore::with_todo(todo_id, true);
+        let taken_id = Uuid::from_u128(1);
+        eventstore
+            .task_event_store
+            .save(vec![TaskEvent::Created(TaskCreatedEvent {
+                event_id: Uuid::new_v4(),
+                task_id: taken_id,
+                sequence: 0,
+                title: String::from("Read rust book"),
+                end_date: None,
+                converted_from: None,
+            })])
+            .unwrap();
+
+        //WHEN
+        let result =
+            ConvertPlannableUsecase::new(&mut eventstore, &mut SequentialIdGenerator::new())
+                .execute(PlannableRef::Todo(todo_id), PlannableKind::Task);
+
+        //THEN the task can not be opened and the todo is not closed either
+        assert_eq!(
+            result,
+            Err(ConversionError::EventStore(
+                EventStoreError::ConcurrencyConflict {
+                    plannable_id: taken_id.to_string(),
+                    expected_version: 0,
+                    actual_version: 1,
+                }
+            ))
+        );
+        let todo = Todo::from_events(&eventstore.todo_event_store.read(todo_id).unwrap()).unwrap();
+        assert_eq!(todo.converted_into, None);
+        assert_eq!(eventstore.task_event_store.get_all().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn given_removedtodo_when_converted_then_notfoundandnothingstored() {
+        let todo_id = Uuid::new_v4();
+        let mut eventstore = TestEventStore::with_todo(todo_id, true);
+        eventstore
+            .todo_event_store
+            .append(
+                todo_id,
+                1,
+                vec![TodoEvent::Removed(TodoRemovedEvent {
+                    todo_id,
+                    event_id: Uuid::new_v4(),
+                    sequence: 1,
+                })],
+            )
+            .unwrap();
+        let source = PlannableRef::Todo(todo_id);
+
+        let result =
+            ConvertPlannableUsecase::new(&mut eventstore, &mut SequentialIdGenerator::new())
+                .execute(source, PlannableKind::Task);
+
+        assert_eq!(result, Err(ConversionError::NotFound(source)));
+        assert_eq!(eventstore.todo_event_store.read(todo_id).unwrap().len(), 2);
+        assert_eq!(eventstore.task_event_store.get_all().unwrap(), vec![]);
+    }
+}
@@ -0,0 +1,137 @@
+use crate::change_todo::{append_changes, read_todo, ChangeTodoEvents};
+use crate::clock::Clock;
+use crate::id_generator::IdGenerator;
+use chrono::NaiveDateTime;
+use entities::errors::TodoError;
+use entities::todo::Todo;
+use entities::todo_events::{TodoEndDateChangedEvent, TodoEvent, TodoId, TodoTitleChangedEvent};
+
+pub struct EditTodoUsecase<'a> {
+    change_todo_events: &'a mut dyn ChangeTodoEvents,
+    clock: &'a dyn Clock,
+    id_generator: &'a mut dyn IdGenerator,
+}
+
+impl<'a> EditTodoUsecase<'a> {
+    // Only what is given and differs from the todo is changed, a new end date may not be past
+    pub fn execute(
+        self,
+        todo_id: TodoId,
+        title: Option<String>,
+        end_date: Option<NaiveDateTime>,
+    ) -> Result<Todo, TodoError> {
+        let todo = read_todo(self.change_todo_events, todo_id)?;
+        let mut changes = Vec::new();
+        if let Some(title) = title.filter(|title| *title != todo.title) {
+            changes.push(TodoEvent::TitleChanged(TodoTitleChangedEvent {
+                title,
+                todo_id,
+                event_id: self.id_generator.next_id(),
+                sequence: todo.version + changes.len() as i32,
+            }));
+        }
+        if end_date.is_some() && end_date != todo.end_date {
+            changes.push(TodoEvent::EndDateChanged(TodoEndDateChangedEvent {
+                todo_id,
+                event_id: self.id_generator.next_id(),
+                sequence: todo.version + changes.len() as i32,
+                end_date,
+            }));
+        }
+        let now = self.clock.now();
+        append_changes(self.change_todo_events, todo, changes, Some(now))
+    }
+    pub fn new(
+        change_todo_events: &'a mut dyn ChangeTodoEvents,
+        clock: &'a dyn Clock,
+        id_generator: &'a mut dyn IdGenerator,
+    ) -> Self {
+        Self {
+            change_todo_events,
+            clock,
+            id_generator,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EditTodoUsecase;
+    use crate::change_todo::fixture::TestEventStore;
+    use crate::clock::FixedClock;
+    use crate::id_generator::SequentialIdGenerator;
+    use chrono::{NaiveDate, NaiveDateTime};
+    use entities::errors::{TodoError, ValidationError, Violation};
+    use entities::plannable::PlannableKind;
+    use entities::todo_events::{TodoEndDateChangedEvent, TodoEvent, TodoTitleChangedEvent};
+    use event_store::plannable_event_store::EventStore;
+    use uuid::Uuid;
+
+    fn date(day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, 9, day)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn given_todo_when_editedtitleandenddate_then_changedeventsstored() {
+        //GIVEN
+        let todo_id = Uuid::new_v4();
+        let mut eventstore = TestEventStore::with_todo(todo_id);
+
+        //WHEN
+        let todo = EditTodoUsecase::new(
+            &mut eventstore,
+            &FixedClock(date(1)),
+            &mut SequentialIdGenerator::new(),
+        )
+        .execute(
+            todo_id,
+            Some(String::from("Read rust book")),
+            Some(date(29)),
+        )
+        .unwrap();
+
+        //THEN
+        assert_eq!(todo.title, "Read rust book");
+        assert_eq!(todo.end_date, Some(date(29)));
+        assert_eq!(
+            eventstore.real_event_store.read(todo_id).unwrap()[1..],
+            [
+                TodoEvent::TitleChanged(TodoTitleChangedEvent {
+                    title: String::from("Read rust book"),
+                    todo_id,
+                    event_id: Uuid::from_u128(1),
+                    sequence: 1,
+                }),
+                TodoEvent::EndDateChanged(TodoEndDateChangedEvent {
+                    todo_id,
+                    event_id: Uuid::from_u128(2),
+                    sequence: 2,
+                    end_date: Some(date(29)),
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn given_todo_when_editedwithblanktitle_then_invalidandnothingstored() {
+        let todo_id = Uuid::new_v4();
+        let mut eventstore = TestEventStore::with_todo(todo_id);
+        let result = EditTodoUsecase::new(
+            &mut eventstore,
+            &FixedClock(date(1)),
+            &mut SequentialIdGenerator::new(),
+        )
+        .execute(todo_id, Some(String::from(" ")), None);
+        assert_eq!(
+            result,
+            Err(TodoError::Invalid(ValidationError {
+                kind: PlannableKind::Todo,
+                violations: vec![Violation::Empty { field: "title" }],
+            }))
+        );
+        assert_eq!(eventstore.real_event_store.read(todo_id).unwrap().len(), 1);
+    }
+}
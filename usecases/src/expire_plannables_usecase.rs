@@ -0,0 +1,278 @@
+use crate::clock::Clock;
+use crate::id_generator::IdGenerator;
+use crate::view::{fold, ViewItem};
+use entities::chore::Chore;
+use entities::chore_events::{ChoreEvent, ChoreExpiredEvent};
+use entities::errors::EventStoreError;
+use entities::impediment::Impediment;
+use entities::impediment_events::{ImpedimentEvent, ImpedimentExpiredEvent};
+use entities::task::Task;
+use entities::task_events::{TaskEvent, TaskExpiredEvent};
+use uuid::Uuid;
+
+pub struct ExpirePlannablesUsecase<'a> {
+    expire_plannable_events: &'a mut dyn ExpirePlannableEvents,
+    clock: &'a dyn Clock,
+    id_generator: &'a mut dyn IdGenerator,
+}
+
+impl<'a> ExpirePlannablesUsecase<'a> {
+    // Records an Expired event for every task, chore and impediment that the README rules say has
+    // lapsed by now, see should_expire on each of them. Returns what was expired.
+    pub fn execute(self) -> Result<Vec<ViewItem>, EventStoreError> {
+        let now = self.clock.now();
+        let events = self.expire_plannable_events;
+        let mut expired = Vec::new();
+
+        let tasks = fold(
+            events.get_all_tasks()?,
+            TaskEvent::task_id,
+            Task::from_events,
+        );
+        for task in tasks.iter().filter(|task| task.should_expire(now)) {
+            let event = TaskEvent::Expired(TaskExpiredEvent {
+                task_id: task.task_id,
+                event_id: self.id_generator.next_id(),
+                sequence: task.version,
+            });
+            events.append_task(task.task_id, task.version, vec![event])?;
+            expired.extend(ViewItem::task(task));
+        }
+
+        let chores = fold(
+            events.get_all_chores()?,
+            ChoreEvent::chore_id,
+            Chore::from_events,
+        );
+        for chore in chores.iter().filter(|chore| chore.should_expire(now)) {
+            // Every occurrence of a recurring chore that lapsed since the last run expires on its own
+            let mut lapsed = chore.clone();
+            let mut chore_events = Vec::new();
+            while lapsed.should_expire(now) {
+                let event = ChoreEvent::Expired(ChoreExpiredEvent {
+                    chore_id: chore.chore_id,
+                    event_id: self.id_generator.next_id(),
+                    sequence: lapsed.version,
+                });
+                expired.extend(ViewItem::chore(&lapsed));
+                lapsed.apply(&event);
+                chore_events.push(event);
+            }
+            events.append_chore(chore.chore_id, chore.version, chore_events)?;
+        }
+
+        let impediments = fold(
+            events.get_all_impediments()?,
+            ImpedimentEvent::impediment_id,
+            Impediment::from_events,
+        );
+        for impediment in impediments
+            .iter()
+            .filter(|impediment| impediment.should_expire(now))
+        {
+            let event = ImpedimentEvent::Expired(ImpedimentExpiredEvent {
+                impediment_id: impediment.impediment_id,
+                event_id: self.id_generator.next_id(),
+                sequence: impediment.version,
+            });
+            events.append_impediment(impediment.impediment_id, impediment.version, vec![event])?;
+            expired.push(ViewItem::impediment(impediment));
+        }
+        Ok(expired)
+    }
+    pub fn new(
+        expire_plannable_events: &'a mut dyn ExpirePlannableEvents,
+        clock: &'a dyn Clock,
+        id_generator: &'a mut dyn IdGenerator,
+    ) -> Self {
+        Self {
+            expire_plannable_events,
+            clock,
+            id_generator,
+        }
+    }
+}
+
+pub trait ExpirePlannableEvents {
+    fn get_all_tasks(&mut self) -> Result<Vec<TaskEvent>, EventStoreError>;
+    fn get_all_chores(&mut self) -> Result<Vec<ChoreEvent>, EventStoreError>;
+    fn get_all_impediments(&mut self) -> Result<Vec<ImpedimentEvent>, EventStoreError>;
+
+    // Appends to a stream when it is still at expected_version, returns the new version
+    fn append_task(
+        &mut self,
+        task_id: Uuid,
+        expected_version: i32,
+        task_events: Vec<TaskEvent>,
+    ) -> Result<i32, EventStoreError>;
+    fn append_chore(
+        &mut self,
+        chore_id: Uuid,
+        expected_version: i32,
+        chore_events: Vec<ChoreEvent>,
+    ) -> Result<i32, EventStoreError>;
+    fn append_impediment(
+        &mut self,
+        impediment_id: Uuid,
+        expected_version: i32,
+        impediment_events: Vec<ImpedimentEvent>,
+    ) -> Result<i32, EventStoreError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExpirePlannablesUsecase;
+    use crate::clock::FixedClock;
+    use crate::expire_plannables_usecase::ExpirePlannableEvents;
+    use crate::id_generator::SequentialIdGenerator;
+    use chrono::{Duration, NaiveDate, NaiveDateTime};
+    use entities::chore::{Chore, ChoreStatus};
+    use entities::chore_events::{ChoreCreatedEvent, ChoreEvent};
+    use entities::errors::EventStoreError;
+    use entities::impediment_events::ImpedimentEvent;
+    use entities::recurrence::Recurrence;
+    use entities::task_events::{TaskCreatedEvent, TaskEvent, TaskExpiredEvent};
+    use event_store::in_memory_event_store::InMemoryEventStore;
+    use event_store::plannable_event_store::EventStore;
+    use uuid::Uuid;
+
+    //Used for testing to call store functions
+    #[derive(Default)]
+    struct TestEventStore {
+        task_event_store: InMemoryEventStore<TaskEvent>,
+        chore_event_store: InMemoryEventStore<ChoreEvent>,
+        impediment_event_store: InMemoryEventStore<ImpedimentEvent>,
+    }
+
+    impl ExpirePlannableEvents for TestEventStore {
+        fn get_all_tasks(&mut self) -> Result<Vec<TaskEvent>, EventStoreError> {
+            self.task_event_store.get_all()
+        }
+        fn get_all_chores(&mut self) -> Result<Vec<ChoreEvent>, EventStoreError> {
+            self.chore_event_store.get_all()
+        }
+        fn get_all_impediments(&mut self) -> Result<Vec<ImpedimentEvent>, EventStoreError> {
+            self.impediment_event_store.get_all()
+        }
+        fn append_task(
+            &mut self,
+            task_id: Uuid,
+            expected_version: i32,
+            task_events: Vec<TaskEvent>,
+        ) -> Result<i32, EventStoreError> {
+            self.task_event_store
+                .append(task_id, expected_version, task_events)
+        }
+        fn append_chore(
+            &mut self,
+            chore_id: Uuid,
+            expected_version: i32,
+            chore_events: Vec<ChoreEvent>,
+        ) -> Result<i32, EventStoreError> {
+            self.chore_event_store
+                .append(chore_id, expected_version, chore_events)
+        }
+        fn append_impediment(
+            &mut self,
+            impediment_id: Uuid,
+            expected_version: i32,
+            impediment_events: Vec<ImpedimentEvent>,
+        ) -> Result<i32, EventStoreError> {
+            self.impediment_event_store
+                .append(impediment_id, expected_version, impediment_events)
+        }
+    }
+
+    fn date(day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, 9, day)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap()
+    }
+
+    fn chore(chore_id: Uuid, recurrence: Option<Recurrence>) -> ChoreEvent {
+        ChoreEvent::Created(ChoreCreatedEvent {
+            title: String::from("Wash the car"),
+            chore_id,
+            event_id: Uuid::new_v4(),
+            sequence: 0,
+            due_date: Some(date(20)),
+            recurrence,
+            converted_from: None,
+        })
+    }
+
+    #[test]
+    fn given_lapsedplannables_when_expired_then_expiredeventsstoredonce() {
+        //GIVEN a task that ended yesterday, one ending tomorrow and two chores due last week
+        let (lapsed_id, open_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let (one_of_id, recurring_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut eventstore = TestEventStore::default();
+        for (task_id, end_date) in [(lapsed_id, date(24)), (open_id, date(26))] {
+            eventstore
+                .task_event_store
+                .save(vec![TaskEvent::Created(TaskCreatedEvent {
+                    title: String::from("Complete your homework"),
+                    task_id,
+                    event_id: Uuid::new_v4(),
+                    sequence: 0,
+                    end_date: Some(end_date),
+                    converted_from: None,
+                })])
+                .unwrap();
+        }
+        eventstore
+            .chore_event_store
+            .save(vec![
+                chore(one_of_id, None),
+                chore(recurring_id, Some(Recurrence::Weeks(1))),
+            ])
+            .unwrap();
+        let clock = FixedClock(date(25));
+        let mut id_generator = SequentialIdGenerator::new();
+
+        //WHEN
+        let expired =
+            ExpirePlannablesUsecase::new(&mut eventstore, &clock, &mut id_generator).execute();
+
+        //THEN the recurring chore expires its lapsed occurrence and moves on
+        let expired: Vec<Uuid> = expired.unwrap().iter().map(|item| item.id).collect();
+        assert_eq!(expired, vec![lapsed_id, one_of_id, recurring_id]);
+        assert_eq!(
+            eventstore.task_event_store.read(lapsed_id).unwrap()[1],
+            TaskEvent::Expired(TaskExpiredEvent {
+                task_id: lapsed_id,
+                event_id: Uuid::from_u128(1),
+                sequence: 1,
+            })
+        );
+        assert_eq!(eventstore.task_event_store.read(open_id).unwrap().len(), 1);
+        let one_of =
+            Chore::from_events(&eventstore.chore_event_store.read(one_of_id).unwrap()).unwrap();
+        assert_eq!(one_of.status, ChoreStatus::Expired);
+        let recurring =
+            Chore::from_events(&eventstore.chore_event_store.read(recurring_id).unwrap()).unwrap();
+        assert_eq!(recurring.status, ChoreStatus::Open);
+        assert_eq!(recurring.due_date, Some(date(27)));
+
+        //A recurring chore left alone for weeks expires every occurrence it missed
+        let clock = FixedClock(date(25) + Duration::weeks(2));
+        let expired =
+            ExpirePlannablesUsecase::new(&mut eventstore, &clock, &mut id_generator).execute();
+        let expired: Vec<NaiveDateTime> = expired
+            .unwrap()
+            .iter()
+            .filter(|item| item.id == recurring_id)
+            .map(|item| item.at)
+            .collect();
+        assert_eq!(expired, vec![date(27), date(27) + Duration::weeks(1)]);
+        let recurring =
+            Chore::from_events(&eventstore.chore_event_store.read(recurring_id).unwrap()).unwrap();
+        assert_eq!(recurring.due_date, Some(date(27) + Duration::weeks(2)));
+
+        //Running again finds nothing left to expire
+        let expired =
+            ExpirePlannablesUsecase::new(&mut eventstore, &clock, &mut id_generator).execute();
+        assert_eq!(expired, Ok(vec![]));
+    }
+}
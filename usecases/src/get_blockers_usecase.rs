@@ -0,0 +1,128 @@
+use crate::view::fold;
+use chrono::NaiveDateTime;
+use entities::errors::EventStoreError;
+use entities::impediment::Impediment;
+use entities::impediment_events::ImpedimentEvent;
+use entities::plannable::PlannableRef;
+
+pub struct GetBlockersUsecase<'a> {
+    get_impediment_events: &'a mut dyn GetImpedimentEvents,
+}
+
+impl<'a> GetBlockersUsecase<'a> {
+    // Returns the impediments that block the plannable at now, empty when it is not blocked
+    pub fn execute(
+        self,
+        plannable: PlannableRef,
+        now: NaiveDateTime,
+    ) -> Result<Vec<Impediment>, EventStoreError> {
+        let events = self.get_impediment_events.get_all()?;
+
+        Ok(fold(
+            events,
+            ImpedimentEvent::impediment_id,
+            Impediment::from_events,
+        )
+        .into_iter()
+        .filter(|impediment| impediment.is_blocking(&plannable, now))
+        .collect())
+    }
+    pub fn new(get_impediment_events: &'a mut dyn GetImpedimentEvents) -> Self {
+        Self {
+            get_impediment_events,
+        }
+    }
+}
+
+pub trait GetImpedimentEvents {
+    fn get_all(&mut self) -> Result<Vec<ImpedimentEvent>, EventStoreError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GetBlockersUsecase;
+    use crate::get_blockers_usecase::GetImpedimentEvents;
+    use chrono::{NaiveDate, NaiveDateTime};
+    use entities::errors::EventStoreError;
+    use entities::impediment_events::{
+        ImpedimentEvent, ImpedimentRaisedEvent, ImpedimentResolvedEvent,
+    };
+    use entities::plannable::PlannableRef;
+    use event_store::in_memory_event_store::InMemoryEventStore;
+    use event_store::plannable_event_store::EventStore;
+    use uuid::Uuid;
+    //Used for testing to call store functions
+    struct TestEventStore {
+        real_event_store: InMemoryEventStore<ImpedimentEvent>,
+    }
+
+    impl GetImpedimentEvents for TestEventStore {
+        fn get_all(&mut self) -> Result<Vec<ImpedimentEvent>, EventStoreError> {
+            self.real_event_store.get_all()
+        }
+    }
+
+    fn date(day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, 9, day)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap()
+    }
+
+    fn raised(
+        impediment_id: Uuid,
+        expires_at: NaiveDateTime,
+        blocks: Vec<PlannableRef>,
+    ) -> ImpedimentEvent {
+        ImpedimentEvent::Raised(ImpedimentRaisedEvent {
+            event_id: Uuid::new_v4(),
+            impediment_id,
+            sequence: 0,
+            title: String::from("Bank card stopped working"),
+            expires_at,
+            blocks,
+        })
+    }
+
+    #[test]
+    fn given_impediments_when_execute_then_onlyactiveblockersoftheplannable() {
+        let mut eventstore = TestEventStore {
+            real_event_store: InMemoryEventStore::new(),
+        };
+        let story = PlannableRef::Story(Uuid::new_v4());
+        let task = PlannableRef::Task(Uuid::new_v4());
+        let (blocking, expired, resolved, other) = (
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        );
+        eventstore
+            .real_event_store
+            .save(vec![
+                raised(blocking, date(12), vec![task, story]),
+                raised(expired, date(9), vec![story]),
+                raised(resolved, date(12), vec![story]),
+                raised(other, date(12), vec![task]),
+                ImpedimentEvent::Resolved(ImpedimentResolvedEvent {
+                    event_id: Uuid::new_v4(),
+                    impediment_id: resolved,
+                    sequence: 1,
+                }),
+            ])
+            .unwrap();
+        let result = GetBlockersUsecase::new(&mut eventstore)
+            .execute(story, date(10))
+            .unwrap();
+        let blockers: Vec<_> = result
+            .iter()
+            .map(|impediment| impediment.impediment_id)
+            .collect();
+        assert_eq!(blockers, vec![blocking]);
+
+        let result = GetBlockersUsecase::new(&mut eventstore)
+            .execute(PlannableRef::Todo(Uuid::new_v4()), date(10))
+            .unwrap();
+        assert!(result.is_empty());
+    }
+}
@@ -0,0 +1,134 @@
+use crate::view::fold;
+use entities::chore::Chore;
+use entities::chore_events::ChoreEvent;
+use entities::errors::EventStoreError;
+
+pub struct GetChoreUsecase<'a> {
+    get_chore_events: &'a mut dyn GetChoreEvents,
+}
+
+impl<'a> GetChoreUsecase<'a> {
+    // Folds the stored events into one Chore per plannable_id, converted chores are left out
+    pub fn execute(self) -> Result<Vec<Chore>, EventStoreError> {
+        let events = self.get_chore_events.get_all()?;
+
+        Ok(fold(events, ChoreEvent::chore_id, Chore::from_events)
+            .into_iter()
+            .filter(|chore| chore.converted_into.is_none())
+            .collect())
+    }
+    pub fn new(get_chore_events: &'a mut dyn GetChoreEvents) -> Self {
+        Self { get_chore_events }
+    }
+}
+
+pub trait GetChoreEvents {
+    fn get_all(&mut self) -> Result<Vec<ChoreEvent>, EventStoreError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GetChoreUsecase;
+    use crate::get_chore_usecase::GetChoreEvents;
+    use chrono::NaiveDate;
+    use entities::chore::{Chore, ChoreStatus};
+    use entities::chore_events::{ChoreCreatedEvent, ChoreDoneEvent, ChoreEvent};
+    use entities::errors::EventStoreError;
+    use entities::recurrence::Recurrence;
+    use event_store::in_memory_event_store::InMemoryEventStore;
+    use event_store::plannable_event_store::EventStore;
+    use uuid::Uuid;
+    //Used for testing to call store functions
+    struct TestEventStore {
+        real_event_store: InMemoryEventStore<ChoreEvent>,
+    }
+
+    impl TestEventStore {
+        fn new() -> Self {
+            Self {
+                real_event_store: InMemoryEventStore::new(),
+            }
+        }
+        fn save(&mut self, chore_events: Vec<ChoreEvent>) -> Result<(), EventStoreError> {
+            self.real_event_store.save(chore_events)
+        }
+    }
+
+    impl GetChoreEvents for TestEventStore {
+        fn get_all(&mut self) -> Result<Vec<ChoreEvent>, EventStoreError> {
+            self.real_event_store.get_all()
+        }
+    }
+
+    #[test]
+    fn given_multiplechorestreams_when_execute_then_onefoldedchoreperid() {
+        let mut eventstore = TestEventStore::new();
+        let car_id = Uuid::new_v4();
+        let mailbox_id = Uuid::new_v4();
+        let due_date = NaiveDate::from_ymd_opt(2023, 9, 29)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap();
+        eventstore
+            .save(vec![
+                ChoreEvent::Created(ChoreCreatedEvent {
+                    event_id: Uuid::new_v4(),
+                    chore_id: car_id,
+                    sequence: 0,
+                    title: String::from("Wash the car"),
+                    due_date: Some(due_date),
+                    recurrence: Some(Recurrence::Weeks(1)),
+                    converted_from: None,
+                }),
+                ChoreEvent::Created(ChoreCreatedEvent {
+                    event_id: Uuid::new_v4(),
+                    chore_id: mailbox_id,
+                    sequence: 0,
+                    title: String::from("Cleanup your mailbox"),
+                    due_date: None,
+                    recurrence: None,
+                    converted_from: None,
+                }),
+                ChoreEvent::Done(ChoreDoneEvent {
+                    event_id: Uuid::new_v4(),
+                    chore_id: car_id,
+                    sequence: 1,
+                }),
+            ])
+            .unwrap();
+        let result = GetChoreUsecase::new(&mut eventstore).execute().unwrap();
+        assert_eq!(
+            result,
+            vec![
+                Chore {
+                    chore_id: car_id,
+                    title: String::from("Wash the car"),
+                    due_date: NaiveDate::from_ymd_opt(2023, 10, 6)
+                        .unwrap()
+                        .and_hms_opt(9, 0, 0),
+                    recurrence: Some(Recurrence::Weeks(1)),
+                    schedule_start: Some(due_date),
+                    occurrence: 1,
+                    status: ChoreStatus::Open,
+                    times_done: 1,
+                    converted_from: None,
+                    converted_into: None,
+                    version: 2,
+                },
+                Chore {
+                    chore_id: mailbox_id,
+                    title: String::from("Cleanup your mailbox"),
+                    due_date: None,
+                    recurrence: None,
+                    schedule_start: None,
+                    occurrence: 0,
+                    status: ChoreStatus::Open,
+                    times_done: 0,
+                    converted_from: None,
+                    converted_into: None,
+                    version: 1,
+                }
+            ]
+        );
+    }
+}
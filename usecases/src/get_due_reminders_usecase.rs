@@ -0,0 +1,124 @@
+use crate::view::fold;
+use chrono::NaiveDateTime;
+use entities::errors::EventStoreError;
+use entities::reminder::Reminder;
+use entities::reminder_events::ReminderEvent;
+
+pub struct GetDueRemindersUsecase<'a> {
+    get_reminder_events: &'a mut dyn GetReminderEvents,
+}
+
+impl<'a> GetDueRemindersUsecase<'a> {
+    // Returns the reminders that should notify at now, the longest overdue first
+    pub fn execute(self, now: NaiveDateTime) -> Result<Vec<Reminder>, EventStoreError> {
+        let events = self.get_reminder_events.get_all()?;
+
+        let mut due: Vec<Reminder> =
+            fold(events, ReminderEvent::reminder_id, Reminder::from_events)
+                .into_iter()
+                .filter(|reminder| reminder.is_due(now))
+                .collect();
+        due.sort_by_key(|reminder| reminder.notify_at());
+        Ok(due)
+    }
+    pub fn new(get_reminder_events: &'a mut dyn GetReminderEvents) -> Self {
+        Self {
+            get_reminder_events,
+        }
+    }
+}
+
+pub trait GetReminderEvents {
+    fn get_all(&mut self) -> Result<Vec<ReminderEvent>, EventStoreError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GetDueRemindersUsecase;
+    use crate::get_due_reminders_usecase::GetReminderEvents;
+    use chrono::{NaiveDate, NaiveDateTime};
+    use entities::errors::EventStoreError;
+    use entities::reminder_events::{
+        ReminderAcknowledgedEvent, ReminderCreatedEvent, ReminderEvent, ReminderSnoozedEvent,
+    };
+    use event_store::in_memory_event_store::InMemoryEventStore;
+    use event_store::plannable_event_store::EventStore;
+    use uuid::Uuid;
+    //Used for testing to call store functions
+    struct TestEventStore {
+        real_event_store: InMemoryEventStore<ReminderEvent>,
+    }
+
+    impl TestEventStore {
+        fn new() -> Self {
+            Self {
+                real_event_store: InMemoryEventStore::new(),
+            }
+        }
+        fn save(&mut self, reminder_events: Vec<ReminderEvent>) -> Result<(), EventStoreError> {
+            self.real_event_store.save(reminder_events)
+        }
+    }
+
+    impl GetReminderEvents for TestEventStore {
+        fn get_all(&mut self) -> Result<Vec<ReminderEvent>, EventStoreError> {
+            self.real_event_store.get_all()
+        }
+    }
+
+    fn date(day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, 9, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    fn created(reminder_id: Uuid, title: &str, trigger_at: NaiveDateTime) -> ReminderEvent {
+        ReminderEvent::Created(ReminderCreatedEvent {
+            event_id: Uuid::new_v4(),
+            reminder_id,
+            sequence: 0,
+            title: String::from(title),
+            trigger_at,
+            recurrence: None,
+        })
+    }
+
+    #[test]
+    fn given_reminders_when_execute_then_onlyduereminderslongestoverduefirst() {
+        let mut eventstore = TestEventStore::new();
+        let (late, early, acknowledged, snoozed, future) = (
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        );
+        eventstore
+            .save(vec![
+                created(late, "Call mom", date(10, 9)),
+                created(early, "Water the plants", date(9, 9)),
+                created(acknowledged, "Pay rent", date(1, 9)),
+                created(snoozed, "Book flights", date(10, 8)),
+                created(future, "Dentist", date(11, 9)),
+                ReminderEvent::Acknowledged(ReminderAcknowledgedEvent {
+                    event_id: Uuid::new_v4(),
+                    reminder_id: acknowledged,
+                    sequence: 1,
+                    acknowledged_at: date(1, 10),
+                }),
+                ReminderEvent::Snoozed(ReminderSnoozedEvent {
+                    event_id: Uuid::new_v4(),
+                    reminder_id: snoozed,
+                    sequence: 1,
+                    until: date(10, 12),
+                }),
+            ])
+            .unwrap();
+        let result = GetDueRemindersUsecase::new(&mut eventstore)
+            .execute(date(10, 10))
+            .unwrap();
+        let due: Vec<_> = result.iter().map(|reminder| reminder.reminder_id).collect();
+        assert_eq!(due, vec![early, late]);
+    }
+}
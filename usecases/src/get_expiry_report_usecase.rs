@@ -0,0 +1,200 @@
+use crate::clock::Clock;
+use crate::view::{GetViewEvents, Plannables, ViewItem};
+use chrono::{Duration, NaiveDateTime};
+use entities::errors::EventStoreError;
+
+pub struct GetExpiryReportUsecase<'a> {
+    get_view_events: &'a mut dyn GetViewEvents,
+    clock: &'a dyn Clock,
+}
+
+// The expiring kinds of the README, tasks, chores and impediments, around now
+#[derive(Debug, PartialEq, Clone)]
+pub struct ExpiryReport {
+    // Past their end date, the longest overdue first, with how long ago that was
+    pub overdue: Vec<(ViewItem, Duration)>,
+    // Expiring within the horizon, the soonest first, with how long until then
+    pub expiring: Vec<(ViewItem, Duration)>,
+}
+
+impl<'a> GetExpiryReportUsecase<'a> {
+    pub fn execute(self, horizon: Duration) -> Result<ExpiryReport, EventStoreError> {
+        let now = self.clock.now();
+        // A horizon past the last representable date takes in everything from now on
+        let until = now
+            .checked_add_signed(horizon)
+            .unwrap_or(NaiveDateTime::MAX);
+        let plannables = Plannables::read(self.get_view_events)?;
+
+        let tasks = plannables.tasks.iter().filter_map(ViewItem::task);
+        let chores = plannables.chores.iter().filter_map(ViewItem::chore);
+        let impediments = plannables
+            .impediments
+            .iter()
+            .filter(|impediment| !impediment.resolved && !impediment.expired)
+            .map(ViewItem::impediment);
+        let (mut overdue, mut expiring): (Vec<ViewItem>, Vec<ViewItem>) = tasks
+            .chain(chores)
+            .chain(impediments)
+            .filter(|item| item.at < until)
+            .partition(|item| item.at < now);
+        overdue.sort_by_key(|item| item.at);
+        expiring.sort_by_key(|item| item.at);
+
+        Ok(ExpiryReport {
+            overdue: overdue
+                .into_iter()
+                .map(|item| {
+                    let by = now - item.at;
+                    (item, by)
+                })
+                .collect(),
+            expiring: expiring
+                .into_iter()
+                .map(|item| {
+                    let within = item.at - now;
+                    (item, within)
+                })
+                .collect(),
+        })
+    }
+    pub fn new(get_view_events: &'a mut dyn GetViewEvents, clock: &'a dyn Clock) -> Self {
+        Self {
+            get_view_events,
+            clock,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GetExpiryReportUsecase;
+    use crate::clock::FixedClock;
+    use crate::view::fixture::TestEventStore;
+    use chrono::{Duration, NaiveDate, NaiveDateTime};
+    use entities::chore_events::{ChoreCreatedEvent, ChoreEvent};
+    use entities::impediment_events::{
+        ImpedimentEvent, ImpedimentRaisedEvent, ImpedimentResolvedEvent,
+    };
+    use entities::task_events::{TaskCreatedEvent, TaskEvent};
+    use event_store::plannable_event_store::EventStore;
+    use uuid::Uuid;
+
+    fn date(day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, 9, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    fn impediment(title: &str, impediment_id: Uuid, expires_at: NaiveDateTime) -> ImpedimentEvent {
+        ImpedimentEvent::Raised(ImpedimentRaisedEvent {
+            title: String::from(title),
+            impediment_id,
+            event_id: Uuid::new_v4(),
+            sequence: 0,
+            expires_at,
+            blocks: Vec::new(),
+        })
+    }
+
+    #[test]
+    fn given_expiringkinds_when_report_then_overdueandexpiringwithinhorizon() {
+        //GIVEN it is 2023-09-25 at 12:00
+        let mut eventstore = TestEventStore::default();
+        eventstore
+            .task_event_store
+            .save(vec![TaskEvent::Created(TaskCreatedEvent {
+                title: String::from("Complete your homework"),
+                task_id: Uuid::new_v4(),
+                event_id: Uuid::new_v4(),
+                sequence: 0,
+                end_date: Some(date(25, 9)),
+                converted_from: None,
+            })])
+            .unwrap();
+        eventstore
+            .chore_event_store
+            .save(vec![
+                ChoreEvent::Created(ChoreCreatedEvent {
+                    title: String::from("Cleanup your mailbox"),
+                    chore_id: Uuid::new_v4(),
+                    event_id: Uuid::new_v4(),
+                    sequence: 0,
+                    due_date: Some(date(23, 12)),
+                    recurrence: None,
+                    converted_from: None,
+                }),
+                ChoreEvent::Created(ChoreCreatedEvent {
+                    title: String::from("Wash the car"),
+                    chore_id: Uuid::new_v4(),
+                    event_id: Uuid::new_v4(),
+                    sequence: 0,
+                    due_date: Some(date(30, 12)),
+                    recurrence: None,
+                    converted_from: None,
+                }),
+            ])
+            .unwrap();
+        let resolved_id = Uuid::new_v4();
+        eventstore
+            .impediment_event_store
+            .save(vec![
+                impediment("Bank card stopped working", Uuid::new_v4(), date(26, 0)),
+                impediment("Car broke down", resolved_id, date(24, 0)),
+                ImpedimentEvent::Resolved(ImpedimentResolvedEvent {
+                    impediment_id: resolved_id,
+                    event_id: Uuid::new_v4(),
+                    sequence: 1,
+                }),
+            ])
+            .unwrap();
+
+        //WHEN looking a day ahead
+        let report = GetExpiryReportUsecase::new(&mut eventstore, &FixedClock(date(25, 12)))
+            .execute(Duration::days(1))
+            .unwrap();
+
+        //THEN the resolved impediment and the chore due in 5 days are left out
+        let overdue: Vec<(&str, Duration)> = report
+            .overdue
+            .iter()
+            .map(|(item, by)| (item.title.as_str(), *by))
+            .collect();
+        assert_eq!(
+            overdue,
+            vec![
+                ("Cleanup your mailbox", Duration::days(2)),
+                ("Complete your homework", Duration::hours(3)),
+            ]
+        );
+        let expiring: Vec<(&str, Duration)> = report
+            .expiring
+            .iter()
+            .map(|(item, within)| (item.title.as_str(), *within))
+            .collect();
+        assert_eq!(
+            expiring,
+            vec![("Bank card stopped working", Duration::hours(12))]
+        );
+    }
+
+    #[test]
+    fn given_horizonpastthelastdate_when_report_then_everythingahead() {
+        let mut eventstore = TestEventStore::default();
+        eventstore
+            .impediment_event_store
+            .save(vec![impediment(
+                "Bank card stopped working",
+                Uuid::new_v4(),
+                NaiveDateTime::MAX - Duration::hours(12),
+            )])
+            .unwrap();
+        let clock = FixedClock(NaiveDateTime::MAX - Duration::days(1));
+        let report = GetExpiryReportUsecase::new(&mut eventstore, &clock)
+            .execute(Duration::days(2))
+            .unwrap();
+        assert_eq!(report.expiring.len(), 1);
+        assert!(report.overdue.is_empty());
+    }
+}
@@ -0,0 +1,94 @@
+use crate::view::fold;
+use entities::errors::EventStoreError;
+use entities::issue::Issue;
+use entities::issue_events::IssueEvent;
+use std::cmp::Reverse;
+
+pub struct GetOpenIssuesUsecase<'a> {
+    get_issue_events: &'a mut dyn GetIssueEvents,
+}
+
+impl<'a> GetOpenIssuesUsecase<'a> {
+    // Returns the unresolved issues, the most severe first and otherwise in the order raised
+    pub fn execute(self) -> Result<Vec<Issue>, EventStoreError> {
+        let events = self.get_issue_events.get_all()?;
+
+        let mut open: Vec<Issue> = fold(events, IssueEvent::issue_id, Issue::from_events)
+            .into_iter()
+            .filter(|issue| !issue.resolved)
+            .collect();
+        open.sort_by_key(|issue| Reverse(issue.severity));
+        Ok(open)
+    }
+    pub fn new(get_issue_events: &'a mut dyn GetIssueEvents) -> Self {
+        Self { get_issue_events }
+    }
+}
+
+pub trait GetIssueEvents {
+    fn get_all(&mut self) -> Result<Vec<IssueEvent>, EventStoreError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GetOpenIssuesUsecase;
+    use crate::get_open_issues_usecase::GetIssueEvents;
+    use entities::errors::EventStoreError;
+    use entities::issue_events::{IssueEvent, IssueRaisedEvent, IssueResolvedEvent, Severity};
+    use event_store::in_memory_event_store::InMemoryEventStore;
+    use event_store::plannable_event_store::EventStore;
+    use uuid::Uuid;
+    //Used for testing to call store functions
+    struct TestEventStore {
+        real_event_store: InMemoryEventStore<IssueEvent>,
+    }
+
+    impl GetIssueEvents for TestEventStore {
+        fn get_all(&mut self) -> Result<Vec<IssueEvent>, EventStoreError> {
+            self.real_event_store.get_all()
+        }
+    }
+
+    fn raised(issue_id: Uuid, title: &str, severity: Severity) -> IssueEvent {
+        IssueEvent::Raised(IssueRaisedEvent {
+            event_id: Uuid::new_v4(),
+            issue_id,
+            sequence: 0,
+            title: String::from(title),
+            severity,
+        })
+    }
+
+    #[test]
+    fn given_issues_when_execute_then_openissuesmostseverefirst() {
+        let mut eventstore = TestEventStore {
+            real_event_store: InMemoryEventStore::new(),
+        };
+        let (dishwasher, leak, resolved, question) = (
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        );
+        eventstore
+            .real_event_store
+            .save(vec![
+                raised(dishwasher, "Dishwasher not performing", Severity::Medium),
+                raised(leak, "Roof is leaking", Severity::Critical),
+                raised(resolved, "Wifi drops", Severity::High),
+                raised(question, "Which paint for the hall", Severity::Medium),
+                IssueEvent::Resolved(IssueResolvedEvent {
+                    event_id: Uuid::new_v4(),
+                    issue_id: resolved,
+                    sequence: 1,
+                    resolution: String::from("Restarted the router"),
+                }),
+            ])
+            .unwrap();
+        let result = GetOpenIssuesUsecase::new(&mut eventstore)
+            .execute()
+            .unwrap();
+        let open: Vec<_> = result.iter().map(|issue| issue.issue_id).collect();
+        assert_eq!(open, vec![leak, dishwasher, question]);
+    }
+}
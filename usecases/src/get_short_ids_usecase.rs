@@ -0,0 +1,61 @@
+use crate::short_ids::{FindPlannableIds, ShortIds};
+use entities::errors::EventStoreError;
+use entities::plannable::PlannableKind;
+
+pub struct GetShortIdsUsecase<'a> {
+    find_plannable_ids: &'a mut dyn FindPlannableIds,
+}
+
+impl<'a> GetShortIdsUsecase<'a> {
+    // Prefixes are unique over every kind, so a short id is never ambiguous whatever it is used for
+    pub fn execute(self) -> Result<ShortIds, EventStoreError> {
+        let mut ids = Vec::new();
+        for kind in PlannableKind::ALL {
+            ids.extend(self.find_plannable_ids.find_ids(kind, "")?);
+        }
+        Ok(ShortIds::new(ids))
+    }
+    pub fn new(find_plannable_ids: &'a mut dyn FindPlannableIds) -> Self {
+        Self { find_plannable_ids }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GetShortIdsUsecase;
+    use crate::short_ids::FindPlannableIds;
+    use entities::errors::EventStoreError;
+    use entities::plannable::PlannableKind;
+    use uuid::Uuid;
+
+    //Used for testing to call store functions
+    struct TestEventStore {
+        todo_id: Uuid,
+        chore_id: Uuid,
+    }
+
+    impl FindPlannableIds for TestEventStore {
+        fn find_ids(
+            &mut self,
+            kind: PlannableKind,
+            id_prefix: &str,
+        ) -> Result<Vec<Uuid>, EventStoreError> {
+            assert_eq!(id_prefix, "");
+            match kind {
+                PlannableKind::Todo => Ok(vec![self.todo_id]),
+                PlannableKind::Chore => Ok(vec![self.chore_id]),
+                _ => Ok(Vec::new()),
+            }
+        }
+    }
+
+    #[test]
+    fn given_idsofdifferentkinds_when_shortened_then_uniqueoverallkinds() {
+        let todo_id = Uuid::parse_str("5e1f0000-0000-4000-8000-000000000001").unwrap();
+        let chore_id = Uuid::parse_str("5e1f7000-0000-4000-8000-000000000001").unwrap();
+        let mut eventstore = TestEventStore { todo_id, chore_id };
+        let short_ids = GetShortIdsUsecase::new(&mut eventstore).execute().unwrap();
+        assert_eq!(short_ids.short(todo_id), "5e1f0");
+        assert_eq!(short_ids.short(chore_id), "5e1f7");
+    }
+}
@@ -1,12 +1,21 @@
-use entities::todo_events::TodoCreatedEvent;
+use crate::view::fold;
+use entities::errors::EventStoreError;
+use entities::todo::Todo;
+use entities::todo_events::TodoEvent;
 
 pub struct GetTodoUsecase<'a> {
     get_todo_events: &'a mut dyn GetTodoEvents,
 }
 
 impl<'a> GetTodoUsecase<'a> {
-    pub fn execute(self) -> Result<Vec<TodoCreatedEvent>, std::io::Error> {
-        self.get_todo_events.get_all()
+    // Folds the stored events into one Todo per plannable_id, removed and converted todos are left out
+    pub fn execute(self) -> Result<Vec<Todo>, EventStoreError> {
+        let events = self.get_todo_events.get_all()?;
+
+        Ok(fold(events, TodoEvent::todo_id, Todo::from_events)
+            .into_iter()
+            .filter(|todo| !todo.removed && todo.converted_into.is_none())
+            .collect())
     }
     pub fn new(get_todo_events: &'a mut dyn GetTodoEvents) -> Self {
         Self { get_todo_events }
@@ -14,7 +23,7 @@ impl<'a> GetTodoUsecase<'a> {
 }
 
 pub trait GetTodoEvents {
-    fn get_all(&mut self) -> Result<Vec<TodoCreatedEvent>, std::io::Error>;
+    fn get_all(&mut self) -> Result<Vec<TodoEvent>, EventStoreError>;
 }
 
 #[cfg(test)]
@@ -22,55 +31,129 @@ mod tests {
     use super::GetTodoUsecase;
     use crate::get_todo_usecase::GetTodoEvents;
     use chrono::NaiveDate;
-    use entities::todo_events::TodoCreatedEvent;
-    use event_store::plannable_todo_event_store::TodoEventStore;
+    use entities::errors::EventStoreError;
+    use entities::todo::Todo;
+    use entities::todo_events::{
+        TodoCompletedEvent, TodoCreatedEvent, TodoEvent, TodoRemovedEvent, TodoTitleChangedEvent,
+    };
+    use event_store::in_memory_event_store::InMemoryEventStore;
+    use event_store::plannable_event_store::EventStore;
     use uuid::Uuid;
     //Used for testing to call store functions
     struct TestEventStore {
-        real_event_store: TodoEventStore,
+        real_event_store: InMemoryEventStore<TodoEvent>,
     }
 
     impl TestEventStore {
-        fn new(name: String) -> Self {
-            let database_url = format!("/tmp/{}.db", name);
-            let eventstore = TodoEventStore::clean(&database_url).unwrap();
+        fn new() -> Self {
             Self {
-                real_event_store: eventstore,
+                real_event_store: InMemoryEventStore::new(),
             }
         }
-        fn save(&mut self, todo_events: Vec<TodoCreatedEvent>) -> Result<(), std::io::Error> {
+        fn save(&mut self, todo_events: Vec<TodoEvent>) -> Result<(), EventStoreError> {
             self.real_event_store.save(todo_events)
         }
     }
 
     impl GetTodoEvents for TestEventStore {
-        fn get_all(&mut self) -> Result<Vec<TodoCreatedEvent>, std::io::Error> {
+        fn get_all(&mut self) -> Result<Vec<TodoEvent>, EventStoreError> {
             self.real_event_store.get_all()
         }
     }
 
     #[test]
     fn given_todoevent_when_stored_then_ok() {
-        let mut eventstore = TestEventStore::new(String::from("simple_plan_get_todo_usecase"));
+        let mut eventstore = TestEventStore::new();
         let todo_id = Uuid::new_v4();
-        let plannables = vec![TodoCreatedEvent {
+        let end_date = Some(
+            NaiveDate::from_ymd_opt(2023, 9, 29)
+                .unwrap()
+                .and_hms_opt(9, 10, 11)
+                .unwrap(),
+        );
+        let plannables = vec![TodoEvent::Created(TodoCreatedEvent {
             event_id: Uuid::new_v4(),
             todo_id,
             sequence: 0,
             title: String::from("Read rust book"),
-            end_date: Some(
-                NaiveDate::from_ymd_opt(2023, 9, 29)
-                    .unwrap()
-                    .and_hms_opt(9, 10, 11)
-                    .unwrap(),
-            ),
-        }];
-        let result = eventstore.save(plannables.clone());
+            end_date,
+            converted_from: None,
+        })];
+        let result = eventstore.save(plannables);
         assert!(result.is_ok());
         let usecase = GetTodoUsecase {
             get_todo_events: &mut eventstore,
         };
         let result = usecase.execute().unwrap();
-        assert_eq!(result, plannables);
+        assert_eq!(
+            result,
+            vec![Todo {
+                todo_id,
+                title: String::from("Read rust book"),
+                end_date,
+                completed: false,
+                removed: false,
+                converted_from: None,
+                converted_into: None,
+                version: 1,
+            }]
+        );
+    }
+
+    #[test]
+    fn given_multipletodostreams_when_execute_then_onefoldedtodoperid() {
+        let mut eventstore = TestEventStore::new();
+        let read_id = Uuid::new_v4();
+        let removed_id = Uuid::new_v4();
+        eventstore
+            .save(vec![
+                TodoEvent::Created(TodoCreatedEvent {
+                    event_id: Uuid::new_v4(),
+                    todo_id: read_id,
+                    sequence: 0,
+                    title: String::from("Buy rust book"),
+                    end_date: None,
+                    converted_from: None,
+                }),
+                TodoEvent::Created(TodoCreatedEvent {
+                    event_id: Uuid::new_v4(),
+                    todo_id: removed_id,
+                    sequence: 0,
+                    title: String::from("Sell rust book"),
+                    end_date: None,
+                    converted_from: None,
+                }),
+                TodoEvent::TitleChanged(TodoTitleChangedEvent {
+                    event_id: Uuid::new_v4(),
+                    todo_id: read_id,
+                    sequence: 1,
+                    title: String::from("Read rust book"),
+                }),
+                TodoEvent::Removed(TodoRemovedEvent {
+                    event_id: Uuid::new_v4(),
+                    todo_id: removed_id,
+                    sequence: 1,
+                }),
+                TodoEvent::Completed(TodoCompletedEvent {
+                    event_id: Uuid::new_v4(),
+                    todo_id: read_id,
+                    sequence: 2,
+                }),
+            ])
+            .unwrap();
+        let result = GetTodoUsecase::new(&mut eventstore).execute().unwrap();
+        assert_eq!(
+            result,
+            vec![Todo {
+                todo_id: read_id,
+                title: String::from("Read rust book"),
+                end_date: None,
+                completed: true,
+                removed: false,
+                converted_from: None,
+                converted_into: None,
+                version: 3,
+            }]
+        );
     }
 }
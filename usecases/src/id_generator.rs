@@ -0,0 +1,33 @@
+use uuid::Uuid;
+
+// Hands out the ids of new plannables and events, so tests can know them up front
+pub trait IdGenerator {
+    fn next_id(&mut self) -> Uuid;
+}
+
+pub struct RandomIdGenerator;
+
+impl IdGenerator for RandomIdGenerator {
+    fn next_id(&mut self) -> Uuid {
+        Uuid::new_v4()
+    }
+}
+
+// Counts up from 1, the nth id is Uuid::from_u128(n)
+#[derive(Default)]
+pub struct SequentialIdGenerator {
+    last: u128,
+}
+
+impl SequentialIdGenerator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl IdGenerator for SequentialIdGenerator {
+    fn next_id(&mut self) -> Uuid {
+        self.last += 1;
+        Uuid::from_u128(self.last)
+    }
+}
@@ -1,2 +1,35 @@
+pub mod acknowledge_reminder_usecase;
+pub mod add_agenda_item_usecase;
+pub mod add_chore_usecase;
+pub mod add_reminder_usecase;
+pub mod add_story_usecase;
 pub mod add_todo_usecase;
+pub mod change_todo;
+pub mod clock;
+pub mod complete_todo_usecase;
+pub mod convert_plannable_usecase;
+pub mod edit_todo_usecase;
+pub mod expire_plannables_usecase;
+pub mod get_blockers_usecase;
+pub mod get_chore_usecase;
+pub mod get_due_reminders_usecase;
+pub mod get_expiry_report_usecase;
+pub mod get_open_issues_usecase;
+pub mod get_short_ids_usecase;
 pub mod get_todo_usecase;
+pub mod id_generator;
+pub mod next_hours_view_usecase;
+pub mod raise_impediment_usecase;
+pub mod raise_issue_usecase;
+pub mod remove_todo_usecase;
+pub mod reopen_todo_usecase;
+pub mod reschedule_agenda_item_usecase;
+pub mod reschedule_chore_usecase;
+pub mod resolve_id_usecase;
+pub mod short_ids;
+pub mod snooze_reminder_usecase;
+pub mod today_view_usecase;
+pub mod transition_story_usecase;
+pub mod validation;
+pub mod view;
+pub mod week_view_usecase;
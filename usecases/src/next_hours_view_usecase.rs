@@ -0,0 +1,204 @@
+use crate::clock::Clock;
+use crate::view::{GetViewEvents, Plannables, ViewItem};
+use chrono::{Duration, NaiveDateTime};
+use entities::errors::ViewError;
+
+pub struct NextHoursViewUsecase<'a> {
+    get_view_events: &'a mut dyn GetViewEvents,
+    clock: &'a dyn Clock,
+}
+
+// One line of a timeline, a plannable or the free time between two agenda items
+#[derive(Debug, PartialEq, Clone)]
+pub enum TimelineEntry {
+    Item(ViewItem),
+    Gap {
+        from: NaiveDateTime,
+        to: NaiveDateTime,
+    },
+}
+
+impl TimelineEntry {
+    fn at(&self) -> NaiveDateTime {
+        match self {
+            TimelineEntry::Item(item) => item.at,
+            TimelineEntry::Gap { from, .. } => *from,
+        }
+    }
+}
+
+impl<'a> NextHoursViewUsecase<'a> {
+    // Returns every plannable whose end date, due date, agenda occurrence, notification or expiry
+    // falls in [now, now + window), in chronological order with the free gaps between agenda
+    // items in between
+    pub fn execute(self, window: Duration) -> Result<Vec<TimelineEntry>, ViewError> {
+        let from = self.clock.now();
+        let to = from
+            .checked_add_signed(window)
+            .ok_or(ViewError::WindowOutOfRange)?;
+        let in_window = |item: &ViewItem| from <= item.at && item.at < to;
+        let plannables = Plannables::read(self.get_view_events)?;
+
+        let todos = plannables.todos.iter().filter_map(ViewItem::todo);
+        let tasks = plannables.tasks.iter().filter_map(ViewItem::task);
+        let chores = plannables.chores.iter().filter_map(ViewItem::chore);
+        let reminders = plannables.reminders.iter().filter_map(ViewItem::reminder);
+        let impediments = plannables
+            .impediments
+            .iter()
+            .filter(|impediment| !impediment.resolved)
+            .map(ViewItem::impediment);
+        let mut occurrences: Vec<ViewItem> = plannables
+            .agenda_items
+            .iter()
+            .flat_map(|agenda_item| {
+                agenda_item
+                    .occurrences(from, to)
+                    .into_iter()
+                    .map(|occurrence| ViewItem::occurrence(agenda_item, occurrence))
+            })
+            .collect();
+        occurrences.sort_by_key(|item| item.at);
+
+        let mut timeline: Vec<TimelineEntry> = todos
+            .chain(tasks)
+            .chain(chores)
+            .chain(reminders)
+            .chain(impediments)
+            .filter(in_window)
+            .map(TimelineEntry::Item)
+            .collect();
+        timeline.extend(gaps(&occurrences));
+        timeline.extend(occurrences.into_iter().map(TimelineEntry::Item));
+        // An item starting when a gap starts comes first, the gap follows it
+        timeline.sort_by_key(|entry| (entry.at(), matches!(entry, TimelineEntry::Gap { .. })));
+        Ok(timeline)
+    }
+    pub fn new(get_view_events: &'a mut dyn GetViewEvents, clock: &'a dyn Clock) -> Self {
+        Self {
+            get_view_events,
+            clock,
+        }
+    }
+}
+
+// The free time between agenda occurrences ordered by start. An occurrence without an end only
+// takes its start.
+fn gaps(occurrences: &[ViewItem]) -> Vec<TimelineEntry> {
+    let mut gaps = Vec::new();
+    let mut busy_until: Option<NaiveDateTime> = None;
+    for occurrence in occurrences {
+        if let Some(from) = busy_until.filter(|from| *from < occurrence.at) {
+            gaps.push(TimelineEntry::Gap {
+                from,
+                to: occurrence.at,
+            });
+        }
+        let end = occurrence.until.unwrap_or(occurrence.at);
+        busy_until = Some(busy_until.map_or(end, |busy_until| busy_until.max(end)));
+    }
+    gaps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NextHoursViewUsecase, TimelineEntry};
+    use crate::clock::FixedClock;
+    use crate::view::fixture::TestEventStore;
+    use chrono::{Duration, NaiveDate, NaiveDateTime};
+    use entities::agenda_item_events::{AgendaItemCreatedEvent, AgendaItemEnd, AgendaItemEvent};
+    use entities::errors::ViewError;
+    use entities::reminder_events::{ReminderCreatedEvent, ReminderEvent};
+    use entities::todo_events::{TodoCreatedEvent, TodoEvent};
+    use event_store::plannable_event_store::EventStore;
+    use uuid::Uuid;
+
+    fn time(hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, 9, 25)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    fn agenda_item(title: &str, start: NaiveDateTime, minutes: i64) -> AgendaItemEvent {
+        AgendaItemEvent::Created(AgendaItemCreatedEvent {
+            title: String::from(title),
+            agenda_item_id: Uuid::new_v4(),
+            event_id: Uuid::new_v4(),
+            sequence: 0,
+            start,
+            end: Some(AgendaItemEnd::After(Duration::minutes(minutes))),
+            location: None,
+            recurrence: None,
+        })
+    }
+
+    #[test]
+    fn given_agendaandtodos_when_nextthreehours_then_chronologicalwithgaps() {
+        //GIVEN it is 09:00 with two meetings and a todo inside the next 3 hours
+        let mut eventstore = TestEventStore::default();
+        eventstore
+            .agenda_item_event_store
+            .save(vec![
+                agenda_item("Standup", time(9, 30), 15),
+                agenda_item("Dentist appointment", time(11, 0), 60),
+                agenda_item("Lunch", time(12, 30), 60),
+            ])
+            .unwrap();
+        eventstore
+            .todo_event_store
+            .save(vec![TodoEvent::Created(TodoCreatedEvent {
+                title: String::from("Read rust book"),
+                todo_id: Uuid::new_v4(),
+                event_id: Uuid::new_v4(),
+                sequence: 0,
+                end_date: Some(time(10, 0)),
+                converted_from: None,
+            })])
+            .unwrap();
+        eventstore
+            .reminder_event_store
+            .save(vec![ReminderEvent::Created(ReminderCreatedEvent {
+                title: String::from("Call grandma"),
+                reminder_id: Uuid::new_v4(),
+                event_id: Uuid::new_v4(),
+                sequence: 0,
+                trigger_at: time(12, 0),
+                recurrence: None,
+            })])
+            .unwrap();
+
+        //WHEN
+        let timeline = NextHoursViewUsecase::new(&mut eventstore, &FixedClock(time(9, 0)))
+            .execute(Duration::hours(3))
+            .unwrap();
+
+        //THEN the reminder at 12:00 and lunch fall outside [09:00, 12:00)
+        let timeline: Vec<String> = timeline
+            .iter()
+            .map(|entry| match entry {
+                TimelineEntry::Item(item) => item.title.clone(),
+                TimelineEntry::Gap { from, to } => {
+                    format!("{} - {}", from.format("%H:%M"), to.format("%H:%M"))
+                }
+            })
+            .collect();
+        assert_eq!(
+            timeline,
+            vec![
+                "Standup",
+                "09:45 - 11:00",
+                "Read rust book",
+                "Dentist appointment"
+            ]
+        );
+    }
+
+    #[test]
+    fn given_windowpastthelastdate_when_nexthours_then_windowoutofrange() {
+        let mut eventstore = TestEventStore::default();
+        let clock = FixedClock(NaiveDateTime::MAX - Duration::hours(1));
+        let result = NextHoursViewUsecase::new(&mut eventstore, &clock).execute(Duration::hours(2));
+        assert_eq!(result, Err(ViewError::WindowOutOfRange));
+    }
+}
@@ -0,0 +1,169 @@
+use crate::clock::Clock;
+use crate::id_generator::IdGenerator;
+use crate::validation::validate_all;
+use chrono::NaiveDateTime;
+use entities::errors::{EventStoreError, PlannableError};
+use entities::impediment_events::{ImpedimentEvent, ImpedimentId, ImpedimentRaisedEvent};
+use entities::plannable::PlannableRef;
+use uuid::Uuid;
+pub struct RaiseImpedimentUsecase<'a> {
+    store_impediment_events: &'a mut dyn StoreImpedimentEvents,
+    clock: &'a dyn Clock,
+    id_generator: &'a mut dyn IdGenerator,
+    allow_past: bool,
+}
+
+impl<'a> RaiseImpedimentUsecase<'a> {
+    // Raises the impediment against the plannables it blocks and returns its id
+    pub fn execute(
+        self,
+        title: String,
+        expires_at: NaiveDateTime,
+        blocks: Vec<PlannableRef>,
+    ) -> Result<ImpedimentId, PlannableError> {
+        let impediment_id = self.id_generator.next_id();
+        let impediment = vec![ImpedimentEvent::Raised(ImpedimentRaisedEvent {
+            title,
+            impediment_id,
+            event_id: self.id_generator.next_id(),
+            sequence: 0,
+            expires_at,
+            blocks,
+        })];
+        let now = (!self.allow_past).then(|| self.clock.now());
+        validate_all(&impediment, now)?;
+        self.store_impediment_events
+            .append(impediment_id, 0, impediment)?;
+        Ok(impediment_id)
+    }
+    pub fn new(
+        store_impediment_events: &'a mut dyn StoreImpedimentEvents,
+        clock: &'a dyn Clock,
+        id_generator: &'a mut dyn IdGenerator,
+    ) -> Self {
+        Self {
+            store_impediment_events,
+            clock,
+            id_generator,
+            allow_past: false,
+        }
+    }
+
+    // Also accepts an impediment that is already over, e.g. when importing old impediments
+    pub fn allow_past(self) -> Self {
+        Self {
+            allow_past: true,
+            ..self
+        }
+    }
+}
+
+pub trait StoreImpedimentEvents {
+    // Appends to the impediment stream when it is still at expected_version, returns the new version
+    fn append(
+        &mut self,
+        impediment_id: Uuid,
+        expected_version: i32,
+        impediment_events: Vec<ImpedimentEvent>,
+    ) -> Result<i32, EventStoreError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RaiseImpedimentUsecase;
+    use crate::clock::FixedClock;
+    use crate::id_generator::SequentialIdGenerator;
+    use crate::raise_impediment_usecase::StoreImpedimentEvents;
+    use chrono::{NaiveDate, NaiveDateTime};
+    use entities::errors::{EventStoreError, PlannableError, ValidationError, Violation};
+    use entities::impediment::Impediment;
+    use entities::impediment_events::ImpedimentEvent;
+    use entities::plannable::{PlannableKind, PlannableRef};
+    use event_store::in_memory_event_store::InMemoryEventStore;
+    use event_store::plannable_event_store::EventStore;
+    use uuid::Uuid;
+
+    //Used for testing to call store functions
+    struct TestEventStore {
+        real_event_store: InMemoryEventStore<ImpedimentEvent>,
+    }
+
+    impl StoreImpedimentEvents for TestEventStore {
+        fn append(
+            &mut self,
+            impediment_id: Uuid,
+            expected_version: i32,
+            impediment_events: Vec<ImpedimentEvent>,
+        ) -> Result<i32, EventStoreError> {
+            self.real_event_store
+                .append(impediment_id, expected_version, impediment_events)
+        }
+    }
+
+    fn date(day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, 9, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn given_impediment_when_raised_then_activeuntilitexpires() {
+        let mut eventstore = TestEventStore {
+            real_event_store: InMemoryEventStore::new(),
+        };
+        let blocked = PlannableRef::Task(Uuid::new_v4());
+        let impediment_id = RaiseImpedimentUsecase::new(
+            &mut eventstore,
+            &FixedClock(date(25, 9)),
+            &mut SequentialIdGenerator::new(),
+        )
+        .execute(
+            String::from("Bank card stopped working"),
+            date(28, 0),
+            vec![blocked],
+        )
+        .unwrap();
+        let events = eventstore.real_event_store.read(impediment_id).unwrap();
+        let impediment = Impediment::from_events(&events).unwrap();
+        assert_eq!(impediment.blocks, vec![blocked]);
+        assert!(impediment.is_active(date(27, 9)));
+        assert!(!impediment.is_active(date(28, 9)));
+    }
+
+    #[test]
+    fn given_expiryinthepast_when_raised_then_rejectedunlesspastallowed() {
+        let mut eventstore = TestEventStore {
+            real_event_store: InMemoryEventStore::new(),
+        };
+        let clock = FixedClock(date(25, 9));
+        let mut id_generator = SequentialIdGenerator::new();
+        let result = RaiseImpedimentUsecase::new(&mut eventstore, &clock, &mut id_generator)
+            .execute(
+                String::from("Bank card stopped working"),
+                date(24, 0),
+                Vec::new(),
+            );
+        assert_eq!(
+            result,
+            Err(PlannableError::Invalid(ValidationError {
+                kind: PlannableKind::Impediment,
+                violations: vec![Violation::InThePast {
+                    field: "expiry date"
+                }],
+            }))
+        );
+        assert!(eventstore.real_event_store.get_all().unwrap().is_empty());
+
+        //An import may bring in impediments that are already over
+        let result = RaiseImpedimentUsecase::new(&mut eventstore, &clock, &mut id_generator)
+            .allow_past()
+            .execute(
+                String::from("Bank card stopped working"),
+                date(24, 0),
+                Vec::new(),
+            );
+        assert!(result.is_ok());
+        assert_eq!(eventstore.real_event_store.get_all().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::id_generator::IdGenerator;
+use crate::validation::validate_all;
+use entities::errors::{EventStoreError, PlannableError};
+use entities::issue_events::{IssueEvent, IssueId, IssueRaisedEvent, Severity};
+use uuid::Uuid;
+pub struct RaiseIssueUsecase<'a> {
+    store_issue_events: &'a mut dyn StoreIssueEvents,
+    id_generator: &'a mut dyn IdGenerator,
+}
+
+impl<'a> RaiseIssueUsecase<'a> {
+    // Raises the issue and returns its id
+    pub fn execute(self, title: String, severity: Severity) -> Result<IssueId, PlannableError> {
+        let issue_id = self.id_generator.next_id();
+        let issue = vec![IssueEvent::Raised(IssueRaisedEvent {
+            title,
+            issue_id,
+            event_id: self.id_generator.next_id(),
+            sequence: 0,
+            severity,
+        })];
+        validate_all(&issue, None)?;
+        self.store_issue_events.append(issue_id, 0, issue)?;
+        Ok(issue_id)
+    }
+    pub fn new(
+        store_issue_events: &'a mut dyn StoreIssueEvents,
+        id_generator: &'a mut dyn IdGenerator,
+    ) -> Self {
+        Self {
+            store_issue_events,
+            id_generator,
+        }
+    }
+}
+
+pub trait StoreIssueEvents {
+    // Appends to the issue stream when it is still at expected_version, returns the new version
+    fn append(
+        &mut self,
+        issue_id: Uuid,
+        expected_version: i32,
+        issue_events: Vec<IssueEvent>,
+    ) -> Result<i32, EventStoreError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RaiseIssueUsecase;
+    use crate::id_generator::SequentialIdGenerator;
+    use crate::raise_issue_usecase::StoreIssueEvents;
+    use entities::errors::{EventStoreError, PlannableError, ValidationError, Violation};
+    use entities::issue::Issue;
+    use entities::issue_events::{IssueEvent, Severity};
+    use entities::plannable::PlannableKind;
+    use event_store::in_memory_event_store::InMemoryEventStore;
+    use event_store::plannable_event_store::EventStore;
+    use uuid::Uuid;
+
+    //Used for testing to call store functions
+    struct TestEventStore {
+        real_event_store: InMemoryEventStore<IssueEvent>,
+    }
+
+    impl StoreIssueEvents for TestEventStore {
+        fn append(
+            &mut self,
+            issue_id: Uuid,
+            expected_version: i32,
+            issue_events: Vec<IssueEvent>,
+        ) -> Result<i32, EventStoreError> {
+            self.real_event_store
+                .append(issue_id, expected_version, issue_events)
+        }
+    }
+
+    #[test]
+    fn given_issue_when_raised_then_storedasopen() {
+        let mut eventstore = TestEventStore {
+            real_event_store: InMemoryEventStore::new(),
+        };
+        let issue_id = RaiseIssueUsecase::new(&mut eventstore, &mut SequentialIdGenerator::new())
+            .execute(String::from("Leaking faucet"), Severity::High)
+            .unwrap();
+        let events = eventstore.real_event_store.read(issue_id).unwrap();
+        let issue = Issue::from_events(&events).unwrap();
+        assert_eq!(issue.title, "Leaking faucet");
+        assert_eq!(issue.severity, Severity::High);
+        assert!(!issue.resolved);
+    }
+
+    #[test]
+    fn given_blanktitle_when_raised_then_invalid() {
+        let mut eventstore = TestEventStore {
+            real_event_store: InMemoryEventStore::new(),
+        };
+        let result = RaiseIssueUsecase::new(&mut eventstore, &mut SequentialIdGenerator::new())
+            .execute(String::from(" "), Severity::Low);
+        assert_eq!(
+            result,
+            Err(PlannableError::Invalid(ValidationError {
+                kind: PlannableKind::Issue,
+                violations: vec![Violation::Empty { field: "title" }],
+            }))
+        );
+        assert!(eventstore.real_event_store.get_all().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,63 @@
+use crate::change_todo::{append_changes, read_todo, ChangeTodoEvents};
+use crate::id_generator::IdGenerator;
+use entities::errors::TodoError;
+use entities::todo::Todo;
+use entities::todo_events::{TodoEvent, TodoId, TodoRemovedEvent};
+
+pub struct RemoveTodoUsecase<'a> {
+    change_todo_events: &'a mut dyn ChangeTodoEvents,
+    id_generator: &'a mut dyn IdGenerator,
+}
+
+impl<'a> RemoveTodoUsecase<'a> {
+    // The events stay in the store, the todo is only left out of every view from now on
+    pub fn execute(self, todo_id: TodoId) -> Result<Todo, TodoError> {
+        let todo = read_todo(self.change_todo_events, todo_id)?;
+        let removed = TodoEvent::Removed(TodoRemovedEvent {
+            todo_id,
+            event_id: self.id_generator.next_id(),
+            sequence: todo.version,
+        });
+        append_changes(self.change_todo_events, todo, vec![removed], None)
+    }
+    pub fn new(
+        change_todo_events: &'a mut dyn ChangeTodoEvents,
+        id_generator: &'a mut dyn IdGenerator,
+    ) -> Self {
+        Self {
+            change_todo_events,
+            id_generator,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RemoveTodoUsecase;
+    use crate::change_todo::fixture::TestEventStore;
+    use crate::id_generator::SequentialIdGenerator;
+    use entities::errors::TodoError;
+    use uuid::Uuid;
+
+    #[test]
+    fn given_removedtodo_when_removedagain_then_notfound() {
+        //GIVEN
+        let todo_id = Uuid::new_v4();
+        let mut eventstore = TestEventStore::with_todo(todo_id);
+        let mut id_generator = SequentialIdGenerator::new();
+
+        //WHEN
+        let todo = RemoveTodoUsecase::new(&mut eventstore, &mut id_generator)
+            .execute(todo_id)
+            .unwrap();
+        let again = RemoveTodoUsecase::new(&mut eventstore, &mut id_generator).execute(todo_id);
+
+        //THEN
+        assert!(todo.removed);
+        assert_eq!(again, Err(TodoError::NotFound(todo_id.to_string())));
+        assert_eq!(
+            RemoveTodoUsecase::new(&mut eventstore, &mut id_generator).execute(Uuid::nil()),
+            Err(TodoError::NotFound(Uuid::nil().to_string()))
+        );
+    }
+}
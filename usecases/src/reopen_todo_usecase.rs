@@ -0,0 +1,72 @@
+use crate::change_todo::{append_changes, read_todo, ChangeTodoEvents};
+use crate::id_generator::IdGenerator;
+use entities::errors::TodoError;
+use entities::todo::Todo;
+use entities::todo_events::{TodoEvent, TodoId, TodoReopenedEvent};
+
+pub struct ReopenTodoUsecase<'a> {
+    change_todo_events: &'a mut dyn ChangeTodoEvents,
+    id_generator: &'a mut dyn IdGenerator,
+}
+
+impl<'a> ReopenTodoUsecase<'a> {
+    pub fn execute(self, todo_id: TodoId) -> Result<Todo, TodoError> {
+        let todo = read_todo(self.change_todo_events, todo_id)?;
+        if !todo.completed {
+            return Err(TodoError::NotCompleted(todo_id.to_string()));
+        }
+        let reopened = TodoEvent::Reopened(TodoReopenedEvent {
+            todo_id,
+            event_id: self.id_generator.next_id(),
+            sequence: todo.version,
+        });
+        append_changes(self.change_todo_events, todo, vec![reopened], None)
+    }
+    pub fn new(
+        change_todo_events: &'a mut dyn ChangeTodoEvents,
+        id_generator: &'a mut dyn IdGenerator,
+    ) -> Self {
+        Self {
+            change_todo_events,
+            id_generator,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReopenTodoUsecase;
+    use crate::change_todo::fixture::TestEventStore;
+    use crate::id_generator::SequentialIdGenerator;
+    use entities::errors::TodoError;
+    use entities::todo_events::{TodoCompletedEvent, TodoEvent};
+    use event_store::plannable_event_store::EventStore;
+    use uuid::Uuid;
+
+    #[test]
+    fn given_completedtodo_when_reopened_then_openagain() {
+        //GIVEN
+        let todo_id = Uuid::new_v4();
+        let mut eventstore = TestEventStore::with_todo(todo_id);
+        eventstore
+            .real_event_store
+            .save(vec![TodoEvent::Completed(TodoCompletedEvent {
+                todo_id,
+                event_id: Uuid::new_v4(),
+                sequence: 1,
+            })])
+            .unwrap();
+        let mut id_generator = SequentialIdGenerator::new();
+
+        //WHEN
+        let todo = ReopenTodoUsecase::new(&mut eventstore, &mut id_generator)
+            .execute(todo_id)
+            .unwrap();
+        let again = ReopenTodoUsecase::new(&mut eventstore, &mut id_generator).execute(todo_id);
+
+        //THEN
+        assert!(!todo.completed);
+        assert_eq!(todo.version, 3);
+        assert_eq!(again, Err(TodoError::NotCompleted(todo_id.to_string())));
+    }
+}
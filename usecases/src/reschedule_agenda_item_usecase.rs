@@ -0,0 +1,222 @@
+use crate::clock::Clock;
+use crate::id_generator::IdGenerator;
+use crate::validation::validate_all;
+use chrono::NaiveDateTime;
+use entities::agenda_item::AgendaItem;
+use entities::agenda_item_events::{
+    AgendaItemEnd, AgendaItemEvent, AgendaItemId, AgendaItemRescheduledEvent,
+};
+use entities::errors::{AgendaItemError, EventStoreError};
+use uuid::Uuid;
+
+pub struct RescheduleAgendaItemUsecase<'a> {
+    change_agenda_item_events: &'a mut dyn ChangeAgendaItemEvents,
+    clock: &'a dyn Clock,
+    id_generator: &'a mut dyn IdGenerator,
+}
+
+impl<'a> RescheduleAgendaItemUsecase<'a> {
+    // Moves the agenda item to start and end, it may not be over already. A recurring agenda item
+    // repeats from the new start.
+    pub fn execute(
+        self,
+        agenda_item_id: AgendaItemId,
+        start: NaiveDateTime,
+        end: Option<AgendaItemEnd>,
+    ) -> Result<AgendaItem, AgendaItemError> {
+        let events = self.change_agenda_item_events.read(agenda_item_id)?;
+        let mut agenda_item = AgendaItem::from_events(&events)
+            .ok_or_else(|| AgendaItemError::NotFound(agenda_item_id.to_string()))?;
+        if agenda_item.cancelled {
+            return Err(AgendaItemError::Cancelled(agenda_item_id.to_string()));
+        }
+        let rescheduled = vec![AgendaItemEvent::Rescheduled(AgendaItemRescheduledEvent {
+            agenda_item_id,
+            event_id: self.id_generator.next_id(),
+            sequence: agenda_item.version,
+            start,
+            end,
+        })];
+        validate_all(&rescheduled, Some(self.clock.now()))?;
+        self.change_agenda_item_events.append(
+            agenda_item_id,
+            agenda_item.version,
+            rescheduled.clone(),
+        )?;
+        rescheduled
+            .iter()
+            .for_each(|event| agenda_item.apply(event));
+        Ok(agenda_item)
+    }
+    pub fn new(
+        change_agenda_item_events: &'a mut dyn ChangeAgendaItemEvents,
+        clock: &'a dyn Clock,
+        id_generator: &'a mut dyn IdGenerator,
+    ) -> Self {
+        Self {
+            change_agenda_item_events,
+            clock,
+            id_generator,
+        }
+    }
+}
+
+// Port of the usecases that change a single existing agenda item
+pub trait ChangeAgendaItemEvents {
+    fn read(&mut self, agenda_item_id: Uuid) -> Result<Vec<AgendaItemEvent>, EventStoreError>;
+
+    // Appends to the agenda item stream when it is still at expected_version, returns the new version
+    fn append(
+        &mut self,
+        agenda_item_id: Uuid,
+        expected_version: i32,
+        agenda_item_events: Vec<AgendaItemEvent>,
+    ) -> Result<i32, EventStoreError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RescheduleAgendaItemUsecase;
+    use crate::clock::FixedClock;
+    use crate::id_generator::SequentialIdGenerator;
+    use crate::reschedule_agenda_item_usecase::ChangeAgendaItemEvents;
+    use chrono::{Duration, NaiveDate, NaiveDateTime};
+    use entities::agenda_item_events::{
+        AgendaItemCancelledEvent, AgendaItemCreatedEvent, AgendaItemEnd, AgendaItemEvent,
+    };
+    use entities::errors::{AgendaItemError, EventStoreError, ValidationError, Violation};
+    use entities::plannable::PlannableKind;
+    use event_store::in_memory_event_store::InMemoryEventStore;
+    use event_store::plannable_event_store::EventStore;
+    use uuid::Uuid;
+
+    //Used for testing to call store functions
+    struct TestEventStore {
+        real_event_store: InMemoryEventStore<AgendaItemEvent>,
+    }
+
+    impl TestEventStore {
+        fn with_agenda_item(agenda_item_id: Uuid) -> Self {
+            let mut real_event_store = InMemoryEventStore::new();
+            real_event_store
+                .save(vec![AgendaItemEvent::Created(AgendaItemCreatedEvent {
+                    title: String::from("Dentist appointment"),
+                    agenda_item_id,
+                    event_id: Uuid::new_v4(),
+                    sequence: 0,
+                    start: date(26, 10),
+                    end: Some(AgendaItemEnd::After(Duration::hours(1))),
+                    location: None,
+                    recurrence: None,
+                })])
+                .unwrap();
+            Self { real_event_store }
+        }
+    }
+
+    impl ChangeAgendaItemEvents for TestEventStore {
+        fn read(&mut self, agenda_item_id: Uuid) -> Result<Vec<AgendaItemEvent>, EventStoreError> {
+            self.real_event_store.read(agenda_item_id)
+        }
+        fn append(
+            &mut self,
+            agenda_item_id: Uuid,
+            expected_version: i32,
+            agenda_item_events: Vec<AgendaItemEvent>,
+        ) -> Result<i32, EventStoreError> {
+            self.real_event_store
+                .append(agenda_item_id, expected_version, agenda_item_events)
+        }
+    }
+
+    fn date(day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, 9, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn given_agendaitem_when_rescheduled_then_movedtonewstart() {
+        let agenda_item_id = Uuid::new_v4();
+        let mut eventstore = TestEventStore::with_agenda_item(agenda_item_id);
+
+        let agenda_item = RescheduleAgendaItemUsecase::new(
+            &mut eventstore,
+            &FixedClock(date(25, 9)),
+            &mut SequentialIdGenerator::new(),
+        )
+        .execute(agenda_item_id, date(28, 14), None)
+        .unwrap();
+
+        assert_eq!(agenda_item.start, date(28, 14));
+        assert_eq!(agenda_item.end, None);
+        assert_eq!(
+            eventstore
+                .real_event_store
+                .read(agenda_item_id)
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn given_startinthepast_when_rescheduled_then_invalidandnothingstored() {
+        let agenda_item_id = Uuid::new_v4();
+        let mut eventstore = TestEventStore::with_agenda_item(agenda_item_id);
+
+        let result = RescheduleAgendaItemUsecase::new(
+            &mut eventstore,
+            &FixedClock(date(25, 9)),
+            &mut SequentialIdGenerator::new(),
+        )
+        .execute(agenda_item_id, date(24, 10), None);
+
+        assert_eq!(
+            result,
+            Err(AgendaItemError::Invalid(ValidationError {
+                kind: PlannableKind::AgendaItem,
+                violations: vec![Violation::InThePast { field: "start" }],
+            }))
+        );
+        assert_eq!(
+            eventstore
+                .real_event_store
+                .read(agenda_item_id)
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn given_cancelledagendaitem_when_rescheduled_then_cancelled() {
+        let agenda_item_id = Uuid::new_v4();
+        let mut eventstore = TestEventStore::with_agenda_item(agenda_item_id);
+        eventstore
+            .real_event_store
+            .append(
+                agenda_item_id,
+                1,
+                vec![AgendaItemEvent::Cancelled(AgendaItemCancelledEvent {
+                    agenda_item_id,
+                    event_id: Uuid::new_v4(),
+                    sequence: 1,
+                })],
+            )
+            .unwrap();
+
+        let result = RescheduleAgendaItemUsecase::new(
+            &mut eventstore,
+            &FixedClock(date(25, 9)),
+            &mut SequentialIdGenerator::new(),
+        )
+        .execute(agenda_item_id, date(28, 14), None);
+
+        assert_eq!(
+            result,
+            Err(AgendaItemError::Cancelled(agenda_item_id.to_string()))
+        );
+    }
+}
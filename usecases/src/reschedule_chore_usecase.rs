@@ -0,0 +1,196 @@
+use crate::clock::Clock;
+use crate::id_generator::IdGenerator;
+use crate::validation::validate_all;
+use chrono::NaiveDateTime;
+use entities::chore::{Chore, ChoreStatus};
+use entities::chore_events::{ChoreEvent, ChoreId, ChoreRescheduledEvent};
+use entities::errors::{ChoreError, EventStoreError};
+use uuid::Uuid;
+
+pub struct RescheduleChoreUsecase<'a> {
+    change_chore_events: &'a mut dyn ChangeChoreEvents,
+    clock: &'a dyn Clock,
+    id_generator: &'a mut dyn IdGenerator,
+}
+
+impl<'a> RescheduleChoreUsecase<'a> {
+    // Moves the current occurrence to due_date, which may not be past. A recurring chore counts
+    // its next occurrences from there.
+    pub fn execute(self, chore_id: ChoreId, due_date: NaiveDateTime) -> Result<Chore, ChoreError> {
+        let events = self.change_chore_events.read(chore_id)?;
+        let mut chore = Chore::from_events(&events)
+            .ok_or_else(|| ChoreError::NotFound(chore_id.to_string()))?;
+        if let Some(into) = chore.converted_into {
+            return Err(ChoreError::Converted {
+                chore_id: chore_id.to_string(),
+                into,
+            });
+        }
+        if chore.status != ChoreStatus::Open {
+            return Err(ChoreError::NotOpen(chore_id.to_string()));
+        }
+        let rescheduled = vec![ChoreEvent::Rescheduled(ChoreRescheduledEvent {
+            chore_id,
+            event_id: self.id_generator.next_id(),
+            sequence: chore.version,
+            due_date: Some(due_date),
+        })];
+        validate_all(&rescheduled, Some(self.clock.now()))?;
+        self.change_chore_events
+            .append(chore_id, chore.version, rescheduled.clone())?;
+        rescheduled.iter().for_each(|event| chore.apply(event));
+        Ok(chore)
+    }
+    pub fn new(
+        change_chore_events: &'a mut dyn ChangeChoreEvents,
+        clock: &'a dyn Clock,
+        id_generator: &'a mut dyn IdGenerator,
+    ) -> Self {
+        Self {
+            change_chore_events,
+            clock,
+            id_generator,
+        }
+    }
+}
+
+// Port of the usecases that change a single existing chore
+pub trait ChangeChoreEvents {
+    fn read(&mut self, chore_id: Uuid) -> Result<Vec<ChoreEvent>, EventStoreError>;
+
+    // Appends to the chore stream when it is still at expected_version, returns the new version
+    fn append(
+        &mut self,
+        chore_id: Uuid,
+        expected_version: i32,
+        chore_events: Vec<ChoreEvent>,
+    ) -> Result<i32, EventStoreError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RescheduleChoreUsecase;
+    use crate::clock::FixedClock;
+    use crate::id_generator::SequentialIdGenerator;
+    use crate::reschedule_chore_usecase::ChangeChoreEvents;
+    use chrono::{NaiveDate, NaiveDateTime};
+    use entities::chore_events::{ChoreCreatedEvent, ChoreDoneEvent, ChoreEvent};
+    use entities::errors::{ChoreError, EventStoreError, ValidationError, Violation};
+    use entities::plannable::PlannableKind;
+    use entities::recurrence::Recurrence;
+    use event_store::in_memory_event_store::InMemoryEventStore;
+    use event_store::plannable_event_store::EventStore;
+    use uuid::Uuid;
+
+    //Used for testing to call store functions
+    struct TestEventStore {
+        real_event_store: InMemoryEventStore<ChoreEvent>,
+    }
+
+    impl TestEventStore {
+        fn with_chore(chore_id: Uuid, recurrence: Option<Recurrence>) -> Self {
+            let mut real_event_store = InMemoryEventStore::new();
+            real_event_store
+                .save(vec![ChoreEvent::Created(ChoreCreatedEvent {
+                    title: String::from("Wash the car"),
+                    chore_id,
+                    event_id: Uuid::new_v4(),
+                    sequence: 0,
+                    due_date: Some(date(29)),
+                    recurrence,
+                    converted_from: None,
+                })])
+                .unwrap();
+            Self { real_event_store }
+        }
+    }
+
+    impl ChangeChoreEvents for TestEventStore {
+        fn read(&mut self, chore_id: Uuid) -> Result<Vec<ChoreEvent>, EventStoreError> {
+            self.real_event_store.read(chore_id)
+        }
+        fn append(
+            &mut self,
+            chore_id: Uuid,
+            expected_version: i32,
+            chore_events: Vec<ChoreEvent>,
+        ) -> Result<i32, EventStoreError> {
+            self.real_event_store
+                .append(chore_id, expected_version, chore_events)
+        }
+    }
+
+    fn date(day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, 9, day)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn given_recurringchore_when_rescheduled_then_countsfromnewduedate() {
+        let chore_id = Uuid::new_v4();
+        let mut eventstore = TestEventStore::with_chore(chore_id, Some(Recurrence::Weeks(2)));
+
+        let chore = RescheduleChoreUsecase::new(
+            &mut eventstore,
+            &FixedClock(date(25)),
+            &mut SequentialIdGenerator::new(),
+        )
+        .execute(chore_id, date(30))
+        .unwrap();
+
+        assert_eq!(chore.due_date, Some(date(30)));
+        assert_eq!(chore.schedule_start, Some(date(30)));
+        assert_eq!(eventstore.real_event_store.read(chore_id).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn given_duedateinthepast_when_rescheduled_then_invalidandnothingstored() {
+        let chore_id = Uuid::new_v4();
+        let mut eventstore = TestEventStore::with_chore(chore_id, None);
+
+        let result = RescheduleChoreUsecase::new(
+            &mut eventstore,
+            &FixedClock(date(25)),
+            &mut SequentialIdGenerator::new(),
+        )
+        .execute(chore_id, date(24));
+
+        assert_eq!(
+            result,
+            Err(ChoreError::Invalid(ValidationError {
+                kind: PlannableKind::Chore,
+                violations: vec![Violation::InThePast { field: "due date" }],
+            }))
+        );
+        assert_eq!(eventstore.real_event_store.read(chore_id).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn given_doneoneoffchore_when_rescheduled_then_notopen() {
+        let chore_id = Uuid::new_v4();
+        let mut eventstore = TestEventStore::with_chore(chore_id, None);
+        eventstore
+            .real_event_store
+            .append(
+                chore_id,
+                1,
+                vec![ChoreEvent::Done(ChoreDoneEvent {
+                    chore_id,
+                    event_id: Uuid::new_v4(),
+                    sequence: 1,
+                })],
+            )
+            .unwrap();
+
+        let result = RescheduleChoreUsecase::new(
+            &mut eventstore,
+            &FixedClock(date(25)),
+            &mut SequentialIdGenerator::new(),
+        )
+        .execute(chore_id, date(30));
+
+        assert_eq!(result, Err(ChoreError::NotOpen(chore_id.to_string())));
+    }
+}
@@ -0,0 +1,127 @@
+use crate::short_ids::FindPlannableIds;
+use entities::errors::IdError;
+use entities::plannable::PlannableKind;
+use uuid::Uuid;
+
+pub struct ResolveIdUsecase<'a> {
+    find_plannable_ids: &'a mut dyn FindPlannableIds,
+}
+
+impl<'a> ResolveIdUsecase<'a> {
+    // Finds the only id of the kind that starts with id_prefix, a full id resolves to itself as
+    // long as it exists. Upper case is accepted as ids are stored in lower case.
+    pub fn execute(self, kind: PlannableKind, id_prefix: &str) -> Result<Uuid, IdError> {
+        let prefix = id_prefix.trim().to_lowercase();
+        if prefix.is_empty()
+            || !prefix
+                .chars()
+                .all(|char| char.is_ascii_hexdigit() || char == '-')
+        {
+            return Err(IdError::Malformed(id_prefix.to_string()));
+        }
+        let mut candidates = self.find_plannable_ids.find_ids(kind, &prefix)?;
+        match candidates.len() {
+            0 => Err(IdError::NotFound { kind, prefix }),
+            1 => Ok(candidates.remove(0)),
+            _ => Err(IdError::Ambiguous {
+                kind,
+                prefix,
+                candidates,
+            }),
+        }
+    }
+    pub fn new(find_plannable_ids: &'a mut dyn FindPlannableIds) -> Self {
+        Self { find_plannable_ids }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResolveIdUsecase;
+    use crate::short_ids::FindPlannableIds;
+    use entities::errors::{EventStoreError, IdError};
+    use entities::plannable::PlannableKind;
+    use entities::todo_events::{TodoCreatedEvent, TodoEvent};
+    use event_store::in_memory_event_store::InMemoryEventStore;
+    use event_store::plannable_event_store::EventStore;
+    use uuid::Uuid;
+
+    //Used for testing to call store functions
+    struct TestEventStore {
+        todo_event_store: InMemoryEventStore<TodoEvent>,
+    }
+
+    impl FindPlannableIds for TestEventStore {
+        fn find_ids(
+            &mut self,
+            kind: PlannableKind,
+            id_prefix: &str,
+        ) -> Result<Vec<Uuid>, EventStoreError> {
+            match kind {
+                PlannableKind::Todo => self.todo_event_store.find_ids(id_prefix),
+                _ => Ok(Vec::new()),
+            }
+        }
+    }
+
+    fn with_todos(todo_ids: &[Uuid]) -> TestEventStore {
+        let mut todo_event_store = InMemoryEventStore::new();
+        for todo_id in todo_ids {
+            todo_event_store
+                .save(vec![TodoEvent::Created(TodoCreatedEvent {
+                    title: String::from("Read rust book"),
+                    todo_id: *todo_id,
+                    event_id: Uuid::new_v4(),
+                    sequence: 0,
+                    end_date: None,
+                    converted_from: None,
+                })])
+                .unwrap();
+        }
+        TestEventStore { todo_event_store }
+    }
+
+    #[test]
+    fn given_uniqueprefix_when_resolved_then_fullid() {
+        let todo_id = Uuid::parse_str("5e1f0000-0000-4000-8000-000000000001").unwrap();
+        let other_id = Uuid::parse_str("5e2f0000-0000-4000-8000-000000000001").unwrap();
+        let mut eventstore = with_todos(&[todo_id, other_id]);
+        let result = ResolveIdUsecase::new(&mut eventstore).execute(PlannableKind::Todo, "5E1");
+        assert_eq!(result, Ok(todo_id));
+        //The id of a todo is no task id
+        let result = ResolveIdUsecase::new(&mut eventstore).execute(PlannableKind::Task, "5e1");
+        assert_eq!(
+            result,
+            Err(IdError::NotFound {
+                kind: PlannableKind::Task,
+                prefix: String::from("5e1"),
+            })
+        );
+    }
+
+    #[test]
+    fn given_sharedprefix_when_resolved_then_ambiguouswithcandidates() {
+        let todo_id = Uuid::parse_str("5e1f0000-0000-4000-8000-000000000001").unwrap();
+        let other_id = Uuid::parse_str("5e2f0000-0000-4000-8000-000000000001").unwrap();
+        let mut eventstore = with_todos(&[other_id, todo_id]);
+        let result = ResolveIdUsecase::new(&mut eventstore).execute(PlannableKind::Todo, "5e");
+        assert_eq!(
+            result,
+            Err(IdError::Ambiguous {
+                kind: PlannableKind::Todo,
+                prefix: String::from("5e"),
+                candidates: vec![todo_id, other_id],
+            })
+        );
+    }
+
+    #[test]
+    fn given_nonhexprefix_when_resolved_then_malformed() {
+        let mut eventstore = with_todos(&[Uuid::new_v4()]);
+        for prefix in ["", "%", "todo"] {
+            let result =
+                ResolveIdUsecase::new(&mut eventstore).execute(PlannableKind::Todo, prefix);
+            assert_eq!(result, Err(IdError::Malformed(String::from(prefix))));
+        }
+    }
+}
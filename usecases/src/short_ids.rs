@@ -0,0 +1,97 @@
+use entities::errors::EventStoreError;
+use entities::plannable::PlannableKind;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+// Shorter prefixes would soon be ambiguous again once a few more plannables are added
+pub const MIN_SHORT_ID_LENGTH: usize = 4;
+
+// Port shared by the usecases that shorten ids and resolve them again
+pub trait FindPlannableIds {
+    // Distinct ids of the kind that start with id_prefix, in ascending order
+    fn find_ids(
+        &mut self,
+        kind: PlannableKind,
+        id_prefix: &str,
+    ) -> Result<Vec<Uuid>, EventStoreError>;
+}
+
+// The shortest prefix of every id that no other id starts with, git-style
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct ShortIds {
+    lengths: HashMap<Uuid, usize>,
+}
+
+impl ShortIds {
+    pub fn new(ids: impl IntoIterator<Item = Uuid>) -> Self {
+        let mut ids: Vec<String> = ids.into_iter().map(|id| id.to_string()).collect();
+        ids.sort();
+        ids.dedup();
+        // In sorted order the longest prefix an id shares is the one with either neighbour
+        let shared: Vec<usize> = ids
+            .windows(2)
+            .map(|pair| common_prefix_length(&pair[0], &pair[1]))
+            .collect();
+        let lengths = ids
+            .iter()
+            .enumerate()
+            .map(|(index, id)| {
+                let before = index.checked_sub(1).map_or(0, |before| shared[before]);
+                let after = shared.get(index).copied().unwrap_or(0);
+                let mut length = (before.max(after) + 1).max(MIN_SHORT_ID_LENGTH);
+                // A prefix ending in a dash reads like a typo
+                if id.as_bytes().get(length - 1) == Some(&b'-') {
+                    length += 1;
+                }
+                (Uuid::parse_str(id).unwrap(), length.min(id.len()))
+            })
+            .collect();
+        Self { lengths }
+    }
+
+    // The full id when it was not among the ids the prefixes were made for
+    pub fn short(&self, id: Uuid) -> String {
+        let mut id_text = id.to_string();
+        if let Some(length) = self.lengths.get(&id) {
+            id_text.truncate(*length);
+        }
+        id_text
+    }
+}
+
+fn common_prefix_length(first: &str, second: &str) -> usize {
+    first
+        .bytes()
+        .zip(second.bytes())
+        .take_while(|(first, second)| first == second)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShortIds;
+    use uuid::Uuid;
+
+    fn id(id: &str) -> Uuid {
+        Uuid::parse_str(id).unwrap()
+    }
+
+    #[test]
+    fn given_ids_when_shortened_then_shortestuniqueprefix() {
+        let shared = id("5e1f0000-0000-4000-8000-000000000001");
+        let sibling = id("5e1f0000-0000-4000-8000-000000000002");
+        let dashed = id("5e2f0000-0000-4000-8000-000000000001");
+        let dashed_sibling = id("5e2f0000-1000-4000-8000-000000000001");
+        let alone = id("a0000000-0000-4000-8000-000000000001");
+        let short_ids = ShortIds::new([shared, sibling, dashed, dashed_sibling, alone]);
+
+        assert_eq!(short_ids.short(shared), shared.to_string());
+        assert_eq!(short_ids.short(sibling), sibling.to_string());
+        assert_eq!(short_ids.short(dashed), "5e2f0000-0");
+        assert_eq!(short_ids.short(dashed_sibling), "5e2f0000-1");
+        assert_eq!(short_ids.short(alone), "a000");
+
+        let unknown = Uuid::new_v4();
+        assert_eq!(short_ids.short(unknown), unknown.to_string());
+    }
+}
@@ -0,0 +1,159 @@
+use crate::acknowledge_reminder_usecase::ChangeReminderEvents;
+use crate::clock::Clock;
+use crate::id_generator::IdGenerator;
+use crate::validation::validate_all;
+use chrono::NaiveDateTime;
+use entities::errors::ReminderError;
+use entities::reminder::Reminder;
+use entities::reminder_events::{ReminderEvent, ReminderId, ReminderSnoozedEvent};
+
+pub struct SnoozeReminderUsecase<'a> {
+    change_reminder_events: &'a mut dyn ChangeReminderEvents,
+    clock: &'a dyn Clock,
+    id_generator: &'a mut dyn IdGenerator,
+}
+
+impl<'a> SnoozeReminderUsecase<'a> {
+    // Puts off the current notification until the given time, which may not be past
+    pub fn execute(
+        self,
+        reminder_id: ReminderId,
+        until: NaiveDateTime,
+    ) -> Result<Reminder, ReminderError> {
+        let events = self.change_reminder_events.read(reminder_id)?;
+        let mut reminder = Reminder::from_events(&events)
+            .ok_or_else(|| ReminderError::NotFound(reminder_id.to_string()))?;
+        if reminder.acknowledged {
+            return Err(ReminderError::Acknowledged(reminder_id.to_string()));
+        }
+        let snoozed = vec![ReminderEvent::Snoozed(ReminderSnoozedEvent {
+            reminder_id,
+            event_id: self.id_generator.next_id(),
+            sequence: reminder.version,
+            until,
+        })];
+        validate_all(&snoozed, Some(self.clock.now()))?;
+        self.change_reminder_events
+            .append(reminder_id, reminder.version, snoozed.clone())?;
+        snoozed.iter().for_each(|event| reminder.apply(event));
+        Ok(reminder)
+    }
+    pub fn new(
+        change_reminder_events: &'a mut dyn ChangeReminderEvents,
+        clock: &'a dyn Clock,
+        id_generator: &'a mut dyn IdGenerator,
+    ) -> Self {
+        Self {
+            change_reminder_events,
+            clock,
+            id_generator,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SnoozeReminderUsecase;
+    use crate::acknowledge_reminder_usecase::ChangeReminderEvents;
+    use crate::clock::FixedClock;
+    use crate::id_generator::SequentialIdGenerator;
+    use chrono::{NaiveDate, NaiveDateTime};
+    use entities::errors::{EventStoreError, ReminderError, ValidationError, Violation};
+    use entities::plannable::PlannableKind;
+    use entities::reminder_events::{ReminderCreatedEvent, ReminderEvent};
+    use event_store::in_memory_event_store::InMemoryEventStore;
+    use event_store::plannable_event_store::EventStore;
+    use uuid::Uuid;
+
+    //Used for testing to call store functions
+    struct TestEventStore {
+        real_event_store: InMemoryEventStore<ReminderEvent>,
+    }
+
+    impl TestEventStore {
+        fn with_reminder(reminder_id: Uuid) -> Self {
+            let mut real_event_store = InMemoryEventStore::new();
+            real_event_store
+                .save(vec![ReminderEvent::Created(ReminderCreatedEvent {
+                    title: String::from("Book flights"),
+                    reminder_id,
+                    event_id: Uuid::new_v4(),
+                    sequence: 0,
+                    trigger_at: date(10, 8),
+                    recurrence: None,
+                })])
+                .unwrap();
+            Self { real_event_store }
+        }
+    }
+
+    impl ChangeReminderEvents for TestEventStore {
+        fn read(&mut self, reminder_id: Uuid) -> Result<Vec<ReminderEvent>, EventStoreError> {
+            self.real_event_store.read(reminder_id)
+        }
+        fn append(
+            &mut self,
+            reminder_id: Uuid,
+            expected_version: i32,
+            reminder_events: Vec<ReminderEvent>,
+        ) -> Result<i32, EventStoreError> {
+            self.real_event_store
+                .append(reminder_id, expected_version, reminder_events)
+        }
+    }
+
+    fn date(day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, 9, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn given_duereminder_when_snoozed_then_notdueuntilthen() {
+        let reminder_id = Uuid::new_v4();
+        let mut eventstore = TestEventStore::with_reminder(reminder_id);
+
+        let reminder = SnoozeReminderUsecase::new(
+            &mut eventstore,
+            &FixedClock(date(10, 9)),
+            &mut SequentialIdGenerator::new(),
+        )
+        .execute(reminder_id, date(10, 12))
+        .unwrap();
+
+        assert!(!reminder.is_due(date(10, 11)));
+        assert!(reminder.is_due(date(10, 12)));
+        assert_eq!(
+            eventstore.real_event_store.read(reminder_id).unwrap().len(),
+            2
+        );
+    }
+
+    #[test]
+    fn given_snoozeinthepast_when_snoozed_then_invalidandnothingstored() {
+        let reminder_id = Uuid::new_v4();
+        let mut eventstore = TestEventStore::with_reminder(reminder_id);
+
+        let result = SnoozeReminderUsecase::new(
+            &mut eventstore,
+            &FixedClock(date(10, 9)),
+            &mut SequentialIdGenerator::new(),
+        )
+        .execute(reminder_id, date(10, 8));
+
+        assert_eq!(
+            result,
+            Err(ReminderError::Invalid(ValidationError {
+                kind: PlannableKind::Reminder,
+                violations: vec![Violation::InThePast {
+                    field: "snooze date"
+                }],
+            }))
+        );
+        assert_eq!(
+            eventstore.real_event_store.read(reminder_id).unwrap().len(),
+            1
+        );
+    }
+}
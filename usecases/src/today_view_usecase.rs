@@ -0,0 +1,187 @@
+use crate::clock::Clock;
+use crate::view::{GetViewEvents, Plannables, ViewItem};
+use chrono::Duration;
+use entities::errors::EventStoreError;
+
+pub struct TodayViewUsecase<'a> {
+    get_view_events: &'a mut dyn GetViewEvents,
+    clock: &'a dyn Clock,
+}
+
+impl<'a> TodayViewUsecase<'a> {
+    // Returns what needs attention today: one-of tasks due today, chores due today or overdue,
+    // today's agenda occurrences, reminders that notify today or did before and active
+    // impediments. Ordered by urgency, then kind, then time.
+    pub fn execute(self) -> Result<Vec<ViewItem>, EventStoreError> {
+        let now = self.clock.now();
+        let start = now.date().and_hms_opt(0, 0, 0).unwrap_or(now);
+        let end = start + Duration::days(1);
+        let plannables = Plannables::read(self.get_view_events)?;
+
+        let tasks = plannables
+            .tasks
+            .iter()
+            .filter_map(ViewItem::task)
+            .filter(|item| start <= item.at && item.at < end);
+        let chores = plannables
+            .chores
+            .iter()
+            .filter_map(ViewItem::chore)
+            .filter(|item| item.at < end);
+        let occurrences = plannables.agenda_items.iter().flat_map(|agenda_item| {
+            agenda_item
+                .occurrences(start, end)
+                .into_iter()
+                .map(|occurrence| ViewItem::occurrence(agenda_item, occurrence))
+        });
+        let reminders = plannables
+            .reminders
+            .iter()
+            .filter_map(ViewItem::reminder)
+            .filter(|item| item.at < end);
+        let impediments = plannables
+            .impediments
+            .iter()
+            .filter(|impediment| impediment.is_active(now))
+            .map(ViewItem::impediment);
+
+        let mut today: Vec<ViewItem> = tasks
+            .chain(chores)
+            .chain(occurrences)
+            .chain(reminders)
+            .chain(impediments)
+            .collect();
+        today.sort_by_key(|item| (item.kind.urgency(), item.kind, item.at));
+        Ok(today)
+    }
+    pub fn new(get_view_events: &'a mut dyn GetViewEvents, clock: &'a dyn Clock) -> Self {
+        Self {
+            get_view_events,
+            clock,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TodayViewUsecase;
+    use crate::clock::FixedClock;
+    use crate::view::fixture::TestEventStore;
+    use chrono::{Duration, NaiveDate, NaiveDateTime};
+    use entities::agenda_item_events::{AgendaItemCreatedEvent, AgendaItemEnd, AgendaItemEvent};
+    use entities::chore_events::{ChoreCreatedEvent, ChoreEvent};
+    use entities::impediment_events::{ImpedimentEvent, ImpedimentRaisedEvent};
+    use entities::plannable::PlannableKind;
+    use entities::reminder_events::{ReminderCreatedEvent, ReminderEvent};
+    use entities::task_events::{TaskCreatedEvent, TaskEvent};
+    use event_store::plannable_event_store::EventStore;
+    use uuid::Uuid;
+
+    fn date(day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, 9, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    fn task(title: &str, end_date: NaiveDateTime) -> TaskEvent {
+        TaskEvent::Created(TaskCreatedEvent {
+            title: String::from(title),
+            task_id: Uuid::new_v4(),
+            event_id: Uuid::new_v4(),
+            sequence: 0,
+            end_date: Some(end_date),
+            converted_from: None,
+        })
+    }
+
+    fn chore(title: &str, due_date: NaiveDateTime) -> ChoreEvent {
+        ChoreEvent::Created(ChoreCreatedEvent {
+            title: String::from(title),
+            chore_id: Uuid::new_v4(),
+            event_id: Uuid::new_v4(),
+            sequence: 0,
+            due_date: Some(due_date),
+            recurrence: None,
+            converted_from: None,
+        })
+    }
+
+    #[test]
+    fn given_plannablesofallkinds_when_today_then_onlytodaysitemsbyurgency() {
+        //GIVEN it is 2023-09-25 at 09:00
+        let mut eventstore = TestEventStore::default();
+        eventstore
+            .task_event_store
+            .save(vec![
+                task("Complete your homework", date(25, 17)),
+                task("Pack for the trip", date(26, 8)),
+            ])
+            .unwrap();
+        eventstore
+            .chore_event_store
+            .save(vec![
+                chore("Wash the car", date(25, 12)),
+                chore("Cleanup your mailbox", date(20, 9)),
+                chore("Mow the lawn", date(27, 9)),
+            ])
+            .unwrap();
+        eventstore
+            .agenda_item_event_store
+            .save(vec![AgendaItemEvent::Created(AgendaItemCreatedEvent {
+                title: String::from("Dentist appointment"),
+                agenda_item_id: Uuid::new_v4(),
+                event_id: Uuid::new_v4(),
+                sequence: 0,
+                start: date(25, 10),
+                end: Some(AgendaItemEnd::After(Duration::hours(1))),
+                location: None,
+                recurrence: None,
+            })])
+            .unwrap();
+        eventstore
+            .reminder_event_store
+            .save(vec![ReminderEvent::Created(ReminderCreatedEvent {
+                title: String::from("Call grandma"),
+                reminder_id: Uuid::new_v4(),
+                event_id: Uuid::new_v4(),
+                sequence: 0,
+                trigger_at: date(25, 19),
+                recurrence: None,
+            })])
+            .unwrap();
+        eventstore
+            .impediment_event_store
+            .save(vec![ImpedimentEvent::Raised(ImpedimentRaisedEvent {
+                title: String::from("Bank card stopped working"),
+                impediment_id: Uuid::new_v4(),
+                event_id: Uuid::new_v4(),
+                sequence: 0,
+                expires_at: date(28, 0),
+                blocks: Vec::new(),
+            })])
+            .unwrap();
+
+        //WHEN
+        let today = TodayViewUsecase::new(&mut eventstore, &FixedClock(date(25, 9)))
+            .execute()
+            .unwrap();
+
+        //THEN tomorrow's task and the chore due later are left out
+        let today: Vec<(PlannableKind, &str)> = today
+            .iter()
+            .map(|item| (item.kind, item.title.as_str()))
+            .collect();
+        assert_eq!(
+            today,
+            vec![
+                (PlannableKind::Task, "Complete your homework"),
+                (PlannableKind::Impediment, "Bank card stopped working"),
+                (PlannableKind::Chore, "Cleanup your mailbox"),
+                (PlannableKind::Chore, "Wash the car"),
+                (PlannableKind::AgendaItem, "Dentist appointment"),
+                (PlannableKind::Reminder, "Call grandma"),
+            ]
+        );
+    }
+}
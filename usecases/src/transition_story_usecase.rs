@@ -0,0 +1,158 @@
+use crate::id_generator::IdGenerator;
+use crate::validation::Validate;
+use entities::errors::{EventStoreError, StoryError};
+use entities::story::{Story, StoryTransition};
+use entities::story_events::{StoryEvent, StoryId};
+use uuid::Uuid;
+
+pub struct TransitionStoryUsecase<'a> {
+    transition_story_events: &'a mut dyn TransitionStoryEvents,
+    id_generator: &'a mut dyn IdGenerator,
+}
+
+impl<'a> TransitionStoryUsecase<'a> {
+    // Moves the story to the next status of its workflow and returns it as it is afterwards
+    pub fn execute(
+        self,
+        story_id: StoryId,
+        transition: StoryTransition,
+    ) -> Result<Story, StoryError> {
+        let events = self.transition_story_events.read(story_id)?;
+        let mut story = Story::from_events(&events)
+            .ok_or_else(|| StoryError::NotFound(story_id.to_string()))?;
+        let event = story.transition(transition, self.id_generator.next_id())?;
+        event.validate(None)?;
+        self.transition_story_events
+            .append(story_id, story.version, vec![event.clone()])?;
+        story.apply(&event);
+        Ok(story)
+    }
+    pub fn new(
+        transition_story_events: &'a mut dyn TransitionStoryEvents,
+        id_generator: &'a mut dyn IdGenerator,
+    ) -> Self {
+        Self {
+            transition_story_events,
+            id_generator,
+        }
+    }
+}
+
+pub trait TransitionStoryEvents {
+    fn read(&mut self, story_id: Uuid) -> Result<Vec<StoryEvent>, EventStoreError>;
+
+    // Appends to the story stream when it is still at expected_version, returns the new version
+    fn append(
+        &mut self,
+        story_id: Uuid,
+        expected_version: i32,
+        story_events: Vec<StoryEvent>,
+    ) -> Result<i32, EventStoreError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TransitionStoryUsecase;
+    use crate::id_generator::SequentialIdGenerator;
+    use crate::transition_story_usecase::TransitionStoryEvents;
+    use entities::errors::{EventStoreError, StoryError};
+    use entities::story::{StoryStatus, StoryTransition};
+    use entities::story_events::{StoryCreatedEvent, StoryEvent, StoryRefinedEvent};
+    use event_store::in_memory_event_store::InMemoryEventStore;
+    use event_store::plannable_event_store::EventStore;
+    use uuid::Uuid;
+
+    //Used for testing to call store functions
+    struct TestEventStore {
+        real_event_store: InMemoryEventStore<StoryEvent>,
+    }
+
+    impl TestEventStore {
+        fn with_draft(story_id: Uuid) -> Self {
+            let mut real_event_store = InMemoryEventStore::new();
+            real_event_store
+                .save(vec![StoryEvent::Created(StoryCreatedEvent {
+                    event_id: Uuid::new_v4(),
+                    story_id,
+                    sequence: 0,
+                    title: String::from("Refactor code to use clean architecture"),
+                    description: String::from("Split the app into layers"),
+                })])
+                .unwrap();
+            Self { real_event_store }
+        }
+    }
+
+    impl TransitionStoryEvents for TestEventStore {
+        fn read(&mut self, story_id: Uuid) -> Result<Vec<StoryEvent>, EventStoreError> {
+            self.real_event_store.read(story_id)
+        }
+
+        fn append(
+            &mut self,
+            story_id: Uuid,
+            expected_version: i32,
+            story_events: Vec<StoryEvent>,
+        ) -> Result<i32, EventStoreError> {
+            self.real_event_store
+                .append(story_id, expected_version, story_events)
+        }
+    }
+
+    #[test]
+    fn given_draft_when_refined_then_refinedeventstored() {
+        let story_id = Uuid::new_v4();
+        let mut eventstore = TestEventStore::with_draft(story_id);
+        let story = TransitionStoryUsecase::new(&mut eventstore, &mut SequentialIdGenerator::new())
+            .execute(
+                story_id,
+                StoryTransition::Refine {
+                    description: String::from("Split the app into entities and usecases"),
+                    acceptance_notes: String::from("No usecase depends on diesel"),
+                    estimate: Some(5),
+                },
+            )
+            .unwrap();
+        assert_eq!(story.status, StoryStatus::Refined);
+        assert_eq!(story.version, 2);
+        assert_eq!(
+            eventstore.real_event_store.read(story_id).unwrap()[1],
+            StoryEvent::Refined(StoryRefinedEvent {
+                story_id,
+                event_id: Uuid::from_u128(1),
+                sequence: 1,
+                description: String::from("Split the app into entities and usecases"),
+                acceptance_notes: String::from("No usecase depends on diesel"),
+                estimate: Some(5),
+            })
+        );
+    }
+
+    #[test]
+    fn given_draft_when_finished_then_illegaltransitionandnothingstored() {
+        let story_id = Uuid::new_v4();
+        let mut eventstore = TestEventStore::with_draft(story_id);
+        let result =
+            TransitionStoryUsecase::new(&mut eventstore, &mut SequentialIdGenerator::new())
+                .execute(story_id, StoryTransition::Finish);
+        assert_eq!(
+            result,
+            Err(StoryError::IllegalTransition {
+                story_id: story_id.to_string(),
+                from: StoryStatus::Draft,
+                to: StoryStatus::Done,
+            })
+        );
+        assert_eq!(eventstore.real_event_store.read(story_id).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn given_unknownstory_when_transitioned_then_notfound() {
+        let mut eventstore = TestEventStore::with_draft(Uuid::new_v4());
+        let story_id = Uuid::new_v4();
+        let result =
+            TransitionStoryUsecase::new(&mut eventstore, &mut SequentialIdGenerator::new())
+                .execute(story_id, StoryTransition::Start);
+        assert_eq!(result, Err(StoryError::NotFound(story_id.to_string())));
+    }
+}
@@ -0,0 +1,437 @@
+use chrono::NaiveDateTime;
+use entities::agenda_item_events::{AgendaItemEnd, AgendaItemEvent};
+use entities::chore_events::ChoreEvent;
+use entities::errors::{ValidationError, Violation};
+use entities::impediment_events::ImpedimentEvent;
+use entities::issue_events::IssueEvent;
+use entities::plannable::PlannableKind;
+use entities::recurrence::Recurrence;
+use entities::reminder_events::ReminderEvent;
+use entities::story_events::StoryEvent;
+use entities::task_events::TaskEvent;
+use entities::todo_events::TodoEvent;
+
+// Checks an event against the rules of its kind from the README comparison table before it is
+// stored. Rules that need the state of the stream are left to the aggregates. When now is given,
+// dates that leave the plannable entirely in the past are rejected as well, the README leaves the
+// past out of scope.
+pub trait Validate {
+    fn validate(&self, now: Option<NaiveDateTime>) -> Result<(), ValidationError>;
+}
+
+// Collects the violations of one item so they can be reported together
+struct Rules {
+    kind: PlannableKind,
+    now: Option<NaiveDateTime>,
+    violations: Vec<Violation>,
+}
+
+impl Rules {
+    fn new(kind: PlannableKind, now: Option<NaiveDateTime>) -> Self {
+        Self {
+            kind,
+            now,
+            violations: Vec::new(),
+        }
+    }
+
+    fn not_past(mut self, field: &'static str, date: Option<NaiveDateTime>) -> Self {
+        if date.is_some_and(|date| self.now.is_some_and(|now| date < now)) {
+            self.violations.push(Violation::InThePast { field });
+        }
+        self
+    }
+
+    fn not_empty(mut self, field: &'static str, text: &str) -> Self {
+        if text.trim().is_empty() {
+            self.violations.push(Violation::Empty { field });
+        }
+        self
+    }
+
+    fn date_required(mut self, field: &'static str, date: Option<NaiveDateTime>) -> Self {
+        if date.is_none() {
+            self.violations.push(Violation::MissingDate { field });
+        }
+        self
+    }
+
+    fn recurrence(mut self, recurrence: Option<Recurrence>, start: Option<NaiveDateTime>) -> Self {
+        let count = match recurrence {
+            Some(Recurrence::Days(count))
+            | Some(Recurrence::Weeks(count))
+            | Some(Recurrence::Months(count))
+            | Some(Recurrence::Years(count)) => count,
+            None => return self,
+        };
+        if count == 0 {
+            self.violations.push(Violation::ZeroRecurrence);
+        }
+        if start.is_none() {
+            self.violations.push(Violation::RecurrenceWithoutDate);
+        }
+        self
+    }
+
+    fn after(
+        mut self,
+        field: &'static str,
+        date: Option<NaiveDateTime>,
+        other: &'static str,
+        other_date: NaiveDateTime,
+    ) -> Self {
+        if date.is_some_and(|date| date <= other_date) {
+            self.violations.push(Violation::NotAfter { field, other });
+        }
+        self
+    }
+
+    fn end(self, start: NaiveDateTime, end: Option<AgendaItemEnd>) -> Self {
+        let end = end.map(|end| match end {
+            AgendaItemEnd::At(end) => end,
+            AgendaItemEnd::After(duration) => start + duration,
+        });
+        self.after("end", end, "start", start)
+    }
+
+    // A one-of agenda item is over at its end, or at its start when it has none
+    fn single_not_past(self, start: NaiveDateTime, end: Option<AgendaItemEnd>) -> Self {
+        match end {
+            Some(AgendaItemEnd::At(end)) => self.not_past("end", Some(end)),
+            Some(AgendaItemEnd::After(duration)) => self.not_past("end", Some(start + duration)),
+            None => self.not_past("start", Some(start)),
+        }
+    }
+
+    fn check(self) -> Result<(), ValidationError> {
+        if self.violations.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationError {
+                kind: self.kind,
+                violations: self.violations,
+            })
+        }
+    }
+}
+
+// Todos have no fixed timeline, an end date is allowed but never required
+impl Validate for TodoEvent {
+    fn validate(&self, now: Option<NaiveDateTime>) -> Result<(), ValidationError> {
+        let rules = Rules::new(PlannableKind::Todo, now);
+        match self {
+            TodoEvent::Created(created) => rules
+                .not_empty("title", &created.title)
+                .not_past("end date", created.end_date),
+            TodoEvent::TitleChanged(changed) => rules.not_empty("title", &changed.title),
+            TodoEvent::EndDateChanged(changed) => rules.not_past("end date", changed.end_date),
+            _ => rules,
+        }
+        .check()
+    }
+}
+
+// One-of tasks MUST have a fixed timeline and expire at their end date
+impl Validate for TaskEvent {
+    fn validate(&self, now: Option<NaiveDateTime>) -> Result<(), ValidationError> {
+        let rules = Rules::new(PlannableKind::Task, now);
+        match self {
+            TaskEvent::Created(created) => rules
+                .not_empty("title", &created.title)
+                .date_required("end date", created.end_date)
+                .not_past("end date", created.end_date),
+            TaskEvent::Converted(_) | TaskEvent::Expired(_) => rules,
+        }
+        .check()
+    }
+}
+
+// Chores have no fixed timeline but MAY recur, counting from their due date. A recurring chore keeps
+// coming back, so only a one-off due date can be in the past.
+impl Validate for ChoreEvent {
+    fn validate(&self, now: Option<NaiveDateTime>) -> Result<(), ValidationError> {
+        let rules = Rules::new(PlannableKind::Chore, now);
+        match self {
+            ChoreEvent::Created(created) => {
+                let rules = rules
+                    .not_empty("title", &created.title)
+                    .recurrence(created.recurrence, created.due_date);
+                match created.recurrence {
+                    Some(_) => rules,
+                    None => rules.not_past("due date", created.due_date),
+                }
+            }
+            ChoreEvent::Rescheduled(rescheduled) => {
+                rules.not_past("due date", rescheduled.due_date)
+            }
+            _ => rules,
+        }
+        .check()
+    }
+}
+
+// Agenda items MUST have a date, MAY recur and end after they start
+impl Validate for AgendaItemEvent {
+    fn validate(&self, now: Option<NaiveDateTime>) -> Result<(), ValidationError> {
+        let rules = Rules::new(PlannableKind::AgendaItem, now);
+        match self {
+            AgendaItemEvent::Created(created) => {
+                let recurrence = created.recurrence.as_ref();
+                let rules = match recurrence {
+                    Some(recurrence) => rules.not_past("until date", recurrence.until),
+                    None => rules.single_not_past(created.start, created.end),
+                };
+                rules
+                    .not_empty("title", &created.title)
+                    .end(created.start, created.end)
+                    .recurrence(
+                        recurrence.map(|recurrence| recurrence.recurrence),
+                        Some(created.start),
+                    )
+                    .after(
+                        "until date",
+                        recurrence.and_then(|recurrence| recurrence.until),
+                        "start",
+                        created.start,
+                    )
+            }
+            AgendaItemEvent::Rescheduled(rescheduled) => rules
+                .end(rescheduled.start, rescheduled.end)
+                .single_not_past(rescheduled.start, rescheduled.end),
+            _ => rules,
+        }
+        .check()
+    }
+}
+
+// Reminders MUST have a trigger date and MAY recur from it
+impl Validate for ReminderEvent {
+    fn validate(&self, now: Option<NaiveDateTime>) -> Result<(), ValidationError> {
+        let rules = Rules::new(PlannableKind::Reminder, now);
+        match self {
+            ReminderEvent::Created(created) => {
+                let rules = rules
+                    .not_empty("title", &created.title)
+                    .recurrence(created.recurrence, Some(created.trigger_at));
+                match created.recurrence {
+                    Some(_) => rules,
+                    None => rules.not_past("trigger date", Some(created.trigger_at)),
+                }
+            }
+            ReminderEvent::Snoozed(snoozed) => rules.not_past("snooze date", Some(snoozed.until)),
+            ReminderEvent::Acknowledged(_) => rules,
+        }
+        .check()
+    }
+}
+
+impl Validate for StoryEvent {
+    fn validate(&self, now: Option<NaiveDateTime>) -> Result<(), ValidationError> {
+        let rules = Rules::new(PlannableKind::Story, now);
+        match self {
+            StoryEvent::Created(created) => rules.not_empty("title", &created.title),
+            StoryEvent::Refined(refined) => rules.not_empty("description", &refined.description),
+            _ => rules,
+        }
+        .check()
+    }
+}
+
+impl Validate for IssueEvent {
+    fn validate(&self, now: Option<NaiveDateTime>) -> Result<(), ValidationError> {
+        let rules = Rules::new(PlannableKind::Issue, now);
+        match self {
+            IssueEvent::Raised(raised) => rules.not_empty("title", &raised.title),
+            IssueEvent::Commented(commented) => rules.not_empty("comment", &commented.comment),
+            IssueEvent::Resolved(resolved) => rules.not_empty("resolution", &resolved.resolution),
+            IssueEvent::Reopened(_) => rules,
+        }
+        .check()
+    }
+}
+
+// Impediments MUST expire, the expiry date is part of every raised event
+impl Validate for ImpedimentEvent {
+    fn validate(&self, now: Option<NaiveDateTime>) -> Result<(), ValidationError> {
+        let rules = Rules::new(PlannableKind::Impediment, now);
+        match self {
+            ImpedimentEvent::Raised(raised) => rules
+                .not_empty("title", &raised.title)
+                .not_past("expiry date", Some(raised.expires_at)),
+            ImpedimentEvent::Resolved(_) | ImpedimentEvent::Expired(_) => rules,
+        }
+        .check()
+    }
+}
+
+// Validates every event, stopping at the first one that breaks a rule
+pub fn validate_all<E: Validate>(
+    events: &[E],
+    now: Option<NaiveDateTime>,
+) -> Result<(), ValidationError> {
+    events.iter().try_for_each(|event| event.validate(now))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Validate;
+    use chrono::{Duration, NaiveDate, NaiveDateTime};
+    use entities::agenda_item_events::{
+        AgendaItemCreatedEvent, AgendaItemEnd, AgendaItemEvent, AgendaRecurrence,
+    };
+    use entities::chore_events::{ChoreCreatedEvent, ChoreEvent};
+    use entities::errors::{ValidationError, Violation};
+    use entities::plannable::PlannableKind;
+    use entities::recurrence::Recurrence;
+    use entities::task_events::{TaskCreatedEvent, TaskEvent};
+    use uuid::Uuid;
+
+    fn date(day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, 9, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn given_taskwithoutenddate_when_validate_then_missingdate() {
+        let task = TaskEvent::Created(TaskCreatedEvent {
+            title: String::from("Complete your homework"),
+            task_id: Uuid::new_v4(),
+            event_id: Uuid::new_v4(),
+            sequence: 0,
+            end_date: None,
+            converted_from: None,
+        });
+        assert_eq!(
+            task.validate(None),
+            Err(ValidationError {
+                kind: PlannableKind::Task,
+                violations: vec![Violation::MissingDate { field: "end date" }],
+            })
+        );
+    }
+
+    #[test]
+    fn given_chorewithblanktitleandzerorecurrence_when_validate_then_allviolations() {
+        let chore = ChoreEvent::Created(ChoreCreatedEvent {
+            title: String::from("  "),
+            chore_id: Uuid::new_v4(),
+            event_id: Uuid::new_v4(),
+            sequence: 0,
+            due_date: None,
+            recurrence: Some(Recurrence::Weeks(0)),
+            converted_from: None,
+        });
+        assert_eq!(
+            chore.validate(None),
+            Err(ValidationError {
+                kind: PlannableKind::Chore,
+                violations: vec![
+                    Violation::Empty { field: "title" },
+                    Violation::ZeroRecurrence,
+                    Violation::RecurrenceWithoutDate,
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn given_recurringchorefromthepast_when_validate_then_onlyoneoffinthepast() {
+        let created = ChoreCreatedEvent {
+            title: String::from("Water the plants"),
+            chore_id: Uuid::new_v4(),
+            event_id: Uuid::new_v4(),
+            sequence: 0,
+            due_date: Some(date(4, 9)),
+            recurrence: Some(Recurrence::Weeks(1)),
+            converted_from: None,
+        };
+        assert_eq!(
+            ChoreEvent::Created(created.clone()).validate(Some(date(30, 0))),
+            Ok(())
+        );
+
+        //A chore done once has nothing left to come
+        let once = ChoreEvent::Created(ChoreCreatedEvent {
+            recurrence: None,
+            ..created
+        });
+        assert_eq!(
+            once.validate(Some(date(30, 0)))
+                .map_err(|error| error.violations),
+            Err(vec![Violation::InThePast { field: "due date" }])
+        );
+    }
+
+    #[test]
+    fn given_agendaitem_when_endsbeforestart_then_notafter() {
+        let created = AgendaItemCreatedEvent {
+            title: String::from("Yoga class"),
+            agenda_item_id: Uuid::new_v4(),
+            event_id: Uuid::new_v4(),
+            sequence: 0,
+            start: date(4, 18),
+            end: Some(AgendaItemEnd::After(Duration::hours(1))),
+            location: None,
+            recurrence: Some(AgendaRecurrence {
+                recurrence: Recurrence::Weeks(1),
+                until: Some(date(30, 0)),
+                excluded_dates: Vec::new(),
+            }),
+        };
+        assert_eq!(
+            AgendaItemEvent::Created(created.clone()).validate(None),
+            Ok(())
+        );
+
+        let ends_before_start = AgendaItemEvent::Created(AgendaItemCreatedEvent {
+            end: Some(AgendaItemEnd::At(date(4, 17))),
+            ..created
+        });
+        assert_eq!(
+            ends_before_start.validate(None).unwrap_err().violations,
+            vec![Violation::NotAfter {
+                field: "end",
+                other: "start"
+            }]
+        );
+    }
+
+    #[test]
+    fn given_agendaitem_when_validatedafterwards_then_onlyoneofinthepast() {
+        let created = AgendaItemCreatedEvent {
+            title: String::from("Dentist appointment"),
+            agenda_item_id: Uuid::new_v4(),
+            event_id: Uuid::new_v4(),
+            sequence: 0,
+            start: date(4, 10),
+            end: Some(AgendaItemEnd::After(Duration::hours(1))),
+            location: None,
+            recurrence: None,
+        };
+        assert_eq!(
+            AgendaItemEvent::Created(created.clone())
+                .validate(Some(date(4, 10)))
+                .map_err(|error| error.violations),
+            Ok(())
+        );
+        assert_eq!(
+            AgendaItemEvent::Created(created.clone())
+                .validate(Some(date(4, 12)))
+                .map_err(|error| error.violations),
+            Err(vec![Violation::InThePast { field: "end" }])
+        );
+
+        //A weekly item without an until date always has occurrences to come
+        let weekly = AgendaItemEvent::Created(AgendaItemCreatedEvent {
+            recurrence: Some(AgendaRecurrence {
+                recurrence: Recurrence::Weeks(1),
+                until: None,
+                excluded_dates: Vec::new(),
+            }),
+            ..created
+        });
+        assert_eq!(weekly.validate(Some(date(30, 0))), Ok(()));
+    }
+}
@@ -0,0 +1,229 @@
+use chrono::NaiveDateTime;
+use entities::agenda_item::{AgendaItem, Occurrence};
+use entities::agenda_item_events::AgendaItemEvent;
+use entities::chore::{Chore, ChoreStatus};
+use entities::chore_events::ChoreEvent;
+use entities::errors::EventStoreError;
+use entities::impediment::Impediment;
+use entities::impediment_events::ImpedimentEvent;
+use entities::plannable::PlannableKind;
+use entities::reminder::Reminder;
+use entities::reminder_events::ReminderEvent;
+use entities::task::Task;
+use entities::task_events::TaskEvent;
+use entities::todo::Todo;
+use entities::todo_events::TodoEvent;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+// One line of a view, whatever the kind of plannable behind it
+#[derive(Debug, PartialEq, Clone)]
+pub struct ViewItem {
+    pub kind: PlannableKind,
+    pub id: Uuid,
+    pub title: String,
+    // The time that matters for the kind: the end date of a todo or task, the due date of a chore, the
+    // start of an agenda occurrence, when a reminder notifies or when an impediment expires
+    pub at: NaiveDateTime,
+    // The end of an agenda occurrence
+    pub until: Option<NaiveDateTime>,
+}
+
+impl ViewItem {
+    // None for a todo without an end date or one that is completed, removed or converted
+    pub fn todo(todo: &Todo) -> Option<Self> {
+        if todo.completed || todo.removed || todo.converted_into.is_some() {
+            return None;
+        }
+        Some(Self {
+            kind: PlannableKind::Todo,
+            id: todo.todo_id,
+            title: todo.title.clone(),
+            at: todo.end_date?,
+            until: None,
+        })
+    }
+
+    // None for a task without an end date or one that was converted or expired
+    pub fn task(task: &Task) -> Option<Self> {
+        if task.expired || task.converted_into.is_some() {
+            return None;
+        }
+        Some(Self {
+            kind: PlannableKind::Task,
+            id: task.task_id,
+            title: task.title.clone(),
+            at: task.end_date?,
+            until: None,
+        })
+    }
+
+    // None for a chore without a due date or one that is no longer open
+    pub fn chore(chore: &Chore) -> Option<Self> {
+        if chore.status != ChoreStatus::Open || chore.converted_into.is_some() {
+            return None;
+        }
+        Some(Self {
+            kind: PlannableKind::Chore,
+            id: chore.chore_id,
+            title: chore.title.clone(),
+            at: chore.due_date?,
+            until: None,
+        })
+    }
+
+    pub fn occurrence(agenda_item: &AgendaItem, occurrence: Occurrence) -> Self {
+        Self {
+            kind: PlannableKind::AgendaItem,
+            id: agenda_item.agenda_item_id,
+            title: agenda_item.title.clone(),
+            at: occurrence.start,
+            until: occurrence.end,
+        }
+    }
+
+    // None once the reminder is acknowledged
+    pub fn reminder(reminder: &Reminder) -> Option<Self> {
+        Some(Self {
+            kind: PlannableKind::Reminder,
+            id: reminder.reminder_id,
+            title: reminder.title.clone(),
+            at: reminder.notify_at()?,
+            until: None,
+        })
+    }
+
+    pub fn impediment(impediment: &Impediment) -> Self {
+        Self {
+            kind: PlannableKind::Impediment,
+            id: impediment.impediment_id,
+            title: impediment.title.clone(),
+            at: impediment.expires_at,
+            until: None,
+        }
+    }
+}
+
+// Reads every plannable a view can show
+pub trait GetViewEvents {
+    fn get_all_todos(&mut self) -> Result<Vec<TodoEvent>, EventStoreError>;
+    fn get_all_tasks(&mut self) -> Result<Vec<TaskEvent>, EventStoreError>;
+    fn get_all_chores(&mut self) -> Result<Vec<ChoreEvent>, EventStoreError>;
+    fn get_all_agenda_items(&mut self) -> Result<Vec<AgendaItemEvent>, EventStoreError>;
+    fn get_all_reminders(&mut self) -> Result<Vec<ReminderEvent>, EventStoreError>;
+    fn get_all_impediments(&mut self) -> Result<Vec<ImpedimentEvent>, EventStoreError>;
+}
+
+// The stored events folded into one aggregate per plannable, in the order they were first stored
+pub(crate) struct Plannables {
+    pub(crate) todos: Vec<Todo>,
+    pub(crate) tasks: Vec<Task>,
+    pub(crate) chores: Vec<Chore>,
+    pub(crate) agenda_items: Vec<AgendaItem>,
+    pub(crate) reminders: Vec<Reminder>,
+    pub(crate) impediments: Vec<Impediment>,
+}
+
+impl Plannables {
+    pub(crate) fn read(get_view_events: &mut dyn GetViewEvents) -> Result<Self, EventStoreError> {
+        Ok(Self {
+            todos: fold(
+                get_view_events.get_all_todos()?,
+                TodoEvent::todo_id,
+                Todo::from_events,
+            ),
+            tasks: fold(
+                get_view_events.get_all_tasks()?,
+                TaskEvent::task_id,
+                Task::from_events,
+            ),
+            chores: fold(
+                get_view_events.get_all_chores()?,
+                ChoreEvent::chore_id,
+                Chore::from_events,
+            ),
+            agenda_items: fold(
+                get_view_events.get_all_agenda_items()?,
+                AgendaItemEvent::agenda_item_id,
+                AgendaItem::from_events,
+            ),
+            reminders: fold(
+                get_view_events.get_all_reminders()?,
+                ReminderEvent::reminder_id,
+                Reminder::from_events,
+            ),
+            impediments: fold(
+                get_view_events.get_all_impediments()?,
+                ImpedimentEvent::impediment_id,
+                Impediment::from_events,
+            ),
+        })
+    }
+}
+
+// Groups the events by plannable in first-seen order and folds every stream
+pub(crate) fn fold<E, A>(
+    events: Vec<E>,
+    plannable_id: impl Fn(&E) -> Uuid,
+    from_events: impl Fn(&[E]) -> Option<A>,
+) -> Vec<A> {
+    let mut streams: Vec<Vec<E>> = Vec::new();
+    let mut positions: HashMap<Uuid, usize> = HashMap::new();
+    for event in events {
+        let position = *positions.entry(plannable_id(&event)).or_insert_with(|| {
+            streams.push(Vec::new());
+            streams.len() - 1
+        });
+        streams[position].push(event);
+    }
+    streams
+        .iter()
+        .filter_map(|stream| from_events(stream))
+        .collect()
+}
+
+#[cfg(test)]
+pub(crate) mod fixture {
+    use super::GetViewEvents;
+    use entities::agenda_item_events::AgendaItemEvent;
+    use entities::chore_events::ChoreEvent;
+    use entities::errors::EventStoreError;
+    use entities::impediment_events::ImpedimentEvent;
+    use entities::reminder_events::ReminderEvent;
+    use entities::task_events::TaskEvent;
+    use entities::todo_events::TodoEvent;
+    use event_store::in_memory_event_store::InMemoryEventStore;
+    use event_store::plannable_event_store::EventStore;
+
+    //Used by the tests of the view usecases to call store functions
+    #[derive(Default)]
+    pub(crate) struct TestEventStore {
+        pub(crate) todo_event_store: InMemoryEventStore<TodoEvent>,
+        pub(crate) task_event_store: InMemoryEventStore<TaskEvent>,
+        pub(crate) chore_event_store: InMemoryEventStore<ChoreEvent>,
+        pub(crate) agenda_item_event_store: InMemoryEventStore<AgendaItemEvent>,
+        pub(crate) reminder_event_store: InMemoryEventStore<ReminderEvent>,
+        pub(crate) impediment_event_store: InMemoryEventStore<ImpedimentEvent>,
+    }
+
+    impl GetViewEvents for TestEventStore {
+        fn get_all_todos(&mut self) -> Result<Vec<TodoEvent>, EventStoreError> {
+            self.todo_event_store.get_all()
+        }
+        fn get_all_tasks(&mut self) -> Result<Vec<TaskEvent>, EventStoreError> {
+            self.task_event_store.get_all()
+        }
+        fn get_all_chores(&mut self) -> Result<Vec<ChoreEvent>, EventStoreError> {
+            self.chore_event_store.get_all()
+        }
+        fn get_all_agenda_items(&mut self) -> Result<Vec<AgendaItemEvent>, EventStoreError> {
+            self.agenda_item_event_store.get_all()
+        }
+        fn get_all_reminders(&mut self) -> Result<Vec<ReminderEvent>, EventStoreError> {
+            self.reminder_event_store.get_all()
+        }
+        fn get_all_impediments(&mut self) -> Result<Vec<ImpedimentEvent>, EventStoreError> {
+            self.impediment_event_store.get_all()
+        }
+    }
+}
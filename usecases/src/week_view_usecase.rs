@@ -0,0 +1,219 @@
+use crate::view::{GetViewEvents, Plannables, ViewItem};
+use chrono::{Duration, IsoWeek, NaiveDate, NaiveDateTime, Weekday};
+use entities::errors::EventStoreError;
+use entities::recurrence::Recurrence;
+
+pub struct WeekViewUsecase<'a> {
+    get_view_events: &'a mut dyn GetViewEvents,
+}
+
+// The plannables of one day, ordered by time
+#[derive(Debug, PartialEq, Clone)]
+pub struct WeekDay {
+    pub date: NaiveDate,
+    pub items: Vec<ViewItem>,
+}
+
+impl<'a> WeekViewUsecase<'a> {
+    // Returns the seven days of the ISO week from Monday to Sunday. Recurring chores, agenda items
+    // and reminders are expanded into an item per occurrence that starts in the week, todos and
+    // tasks show up on their end date.
+    pub fn execute(self, week: IsoWeek) -> Result<Vec<WeekDay>, EventStoreError> {
+        let monday = NaiveDate::from_isoywd_opt(week.year(), week.week(), Weekday::Mon)
+            .expect("an IsoWeek always has a Monday");
+        let from = monday.and_hms_opt(0, 0, 0).unwrap_or_default();
+        let to = from + Duration::weeks(1);
+        let plannables = Plannables::read(self.get_view_events)?;
+
+        let todos = plannables.todos.iter().filter_map(ViewItem::todo);
+        let tasks = plannables.tasks.iter().filter_map(ViewItem::task);
+        let chores = plannables.chores.iter().flat_map(|chore| {
+            let item = ViewItem::chore(chore);
+            expand(item, chore.schedule_start, chore.recurrence, to)
+        });
+        let reminders = plannables.reminders.iter().flat_map(|reminder| {
+            let item = ViewItem::reminder(reminder);
+            expand(
+                item,
+                Some(reminder.first_trigger_at),
+                reminder.recurrence,
+                to,
+            )
+        });
+        let occurrences = plannables.agenda_items.iter().flat_map(|agenda_item| {
+            agenda_item
+                .occurrences(from, to)
+                .into_iter()
+                .map(|occurrence| ViewItem::occurrence(agenda_item, occurrence))
+        });
+
+        let mut items: Vec<ViewItem> = todos
+            .chain(tasks)
+            .chain(chores)
+            .chain(reminders)
+            .chain(occurrences)
+            .filter(|item| from <= item.at && item.at < to)
+            .collect();
+        items.sort_by_key(|item| item.at);
+
+        Ok(monday
+            .iter_days()
+            .take(7)
+            .map(|date| WeekDay {
+                date,
+                items: items
+                    .iter()
+                    .filter(|item| item.at.date() == date)
+                    .cloned()
+                    .collect(),
+            })
+            .collect())
+    }
+    pub fn new(get_view_events: &'a mut dyn GetViewEvents) -> Self {
+        Self { get_view_events }
+    }
+}
+
+// The item as it is next, followed by the later occurrences of its recurrence counted from start,
+// up to the end of the week
+fn expand(
+    item: Option<ViewItem>,
+    start: Option<NaiveDateTime>,
+    recurrence: Option<Recurrence>,
+    to: NaiveDateTime,
+) -> Vec<ViewItem> {
+    let Some(item) = item else {
+        return Vec::new();
+    };
+    let (Some(start), Some(recurrence)) = (start, recurrence) else {
+        return vec![item];
+    };
+    let later = (1..)
+        .map_while(|n| recurrence.nth_after(start, n))
+        .take_while(|at| *at < to)
+        .filter(|at| *at > item.at)
+        .map(|at| ViewItem { at, ..item.clone() })
+        .collect::<Vec<ViewItem>>();
+    std::iter::once(item).chain(later).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WeekViewUsecase;
+    use crate::view::fixture::TestEventStore;
+    use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime};
+    use entities::agenda_item_events::{
+        AgendaItemCreatedEvent, AgendaItemEnd, AgendaItemEvent, AgendaRecurrence,
+    };
+    use entities::chore_events::{ChoreCreatedEvent, ChoreEvent};
+    use entities::recurrence::Recurrence;
+    use entities::reminder_events::{ReminderCreatedEvent, ReminderEvent};
+    use event_store::plannable_event_store::EventStore;
+    use uuid::Uuid;
+
+    fn date(day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, 9, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn given_recurringplannables_when_weekview_then_occurrencesperday() {
+        //GIVEN a chore every 2 days, a weekly class and a daily reminder starting mid week
+        let mut eventstore = TestEventStore::default();
+        eventstore
+            .chore_event_store
+            .save(vec![ChoreEvent::Created(ChoreCreatedEvent {
+                title: String::from("Water the plants"),
+                chore_id: Uuid::new_v4(),
+                event_id: Uuid::new_v4(),
+                sequence: 0,
+                due_date: Some(date(23, 8)),
+                recurrence: Some(Recurrence::Days(2)),
+                converted_from: None,
+            })])
+            .unwrap();
+        eventstore
+            .agenda_item_event_store
+            .save(vec![AgendaItemEvent::Created(AgendaItemCreatedEvent {
+                title: String::from("Yoga class"),
+                agenda_item_id: Uuid::new_v4(),
+                event_id: Uuid::new_v4(),
+                sequence: 0,
+                start: date(4, 18),
+                end: Some(AgendaItemEnd::After(Duration::hours(1))),
+                location: None,
+                recurrence: Some(AgendaRecurrence {
+                    recurrence: Recurrence::Weeks(1),
+                    until: None,
+                    excluded_dates: Vec::new(),
+                }),
+            })])
+            .unwrap();
+        eventstore
+            .reminder_event_store
+            .save(vec![ReminderEvent::Created(ReminderCreatedEvent {
+                title: String::from("Take vitamins"),
+                reminder_id: Uuid::new_v4(),
+                event_id: Uuid::new_v4(),
+                sequence: 0,
+                trigger_at: date(29, 7),
+                recurrence: Some(Recurrence::Days(1)),
+            })])
+            .unwrap();
+
+        //WHEN viewing ISO week 39 of 2023, Monday 2023-09-25 to Sunday 2023-10-01
+        let week = WeekViewUsecase::new(&mut eventstore)
+            .execute(NaiveDate::from_ymd_opt(2023, 9, 27).unwrap().iso_week())
+            .unwrap();
+
+        //THEN
+        let week: Vec<(u32, Vec<&str>)> = week
+            .iter()
+            .map(|day| {
+                let titles = day.items.iter().map(|item| item.title.as_str()).collect();
+                (day.date.day(), titles)
+            })
+            .collect();
+        assert_eq!(
+            week,
+            vec![
+                (25, vec!["Water the plants", "Yoga class"]),
+                (26, vec![]),
+                (27, vec!["Water the plants"]),
+                (28, vec![]),
+                (29, vec!["Take vitamins", "Water the plants"]),
+                (30, vec!["Take vitamins"]),
+                (1, vec!["Take vitamins", "Water the plants"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn given_storedzerorecurrence_when_weekview_then_shownonce() {
+        //GIVEN a chore row written before zero counts were rejected
+        let mut eventstore = TestEventStore::default();
+        eventstore
+            .chore_event_store
+            .save(vec![ChoreEvent::Created(ChoreCreatedEvent {
+                title: String::from("Water the plants"),
+                chore_id: Uuid::new_v4(),
+                event_id: Uuid::new_v4(),
+                sequence: 0,
+                due_date: Some(date(25, 8)),
+                recurrence: Some(Recurrence::Days(0)),
+                converted_from: None,
+            })])
+            .unwrap();
+
+        //WHEN
+        let week = WeekViewUsecase::new(&mut eventstore)
+            .execute(NaiveDate::from_ymd_opt(2023, 9, 25).unwrap().iso_week())
+            .unwrap();
+
+        //THEN the expansion stops instead of repeating the due date forever
+        let items: usize = week.iter().map(|day| day.items.len()).sum();
+        assert_eq!(items, 1);
+    }
+}